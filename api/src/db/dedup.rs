@@ -0,0 +1,194 @@
+//! Fuzzy detection of the same job posted on several sources
+//!
+//! Job boards copy postings from each other and companies post on several
+//! at once, so the same role arrives with different URLs, slightly different
+//! titles ("Sr. Backend Engineer (m/f/d)") and rewritten descriptions. Two
+//! postings are taken to be the same job when the company names match after
+//! normalization, the locations are compatible, and either the titles match
+//! or the titles are similar and the descriptions share most of their words.
+//! Postings with their own ids at the same source are always different jobs.
+
+use std::collections::{HashMap, HashSet};
+
+use uuid::Uuid;
+
+use crate::models::Job;
+use crate::services::organisation::{normalize_organisation, same_organisation};
+
+/// Title words that say nothing about the role, e.g. gender markers in
+/// German postings ("m/w/d") and employment type
+const TITLE_NOISE: [&str; 12] = [
+    "m", "w", "f", "d", "x", "all", "genders", "remote", "hybrid", "onsite", "fulltime", "full",
+];
+
+/// Location words that don't name a place
+const LOCATION_NOISE: [&str; 8] = ["remote", "hybrid", "onsite", "on", "site", "only", "or", "and"];
+
+/// Words too common to tell descriptions apart
+const STOP_WORDS: [&str; 24] = [
+    "the", "and", "for", "with", "you", "your", "our", "are", "will", "who", "that", "this", "from",
+    "have", "has", "can", "all", "not", "but", "their", "they", "what", "work", "team",
+];
+
+/// Title similarity above which postings at the same company and place are
+/// the same job without comparing descriptions
+const SAME_TITLE: f64 = 0.99;
+/// Title similarity needed before descriptions are compared
+const SIMILAR_TITLE: f64 = 0.5;
+/// Description similarity needed when titles only partly match
+const SIMILAR_DESCRIPTION: f64 = 0.5;
+
+/// Whether two postings are for the same job
+pub fn is_duplicate(a: &Job, b: &Job) -> bool {
+    if a.source_url == b.source_url {
+        return true;
+    }
+    // A source lists each requisition once, e.g. a Greenhouse board with the
+    // same role in two offices
+    if a.source == b.source
+        && a.source_job_id.is_some()
+        && b.source_job_id.is_some()
+        && a.source_job_id != b.source_job_id
+    {
+        return false;
+    }
+
+    if !same_organisation(&a.company, &b.company) {
+        return false;
+    }
+    if !compatible_locations(&a.location, &b.location) {
+        return false;
+    }
+
+    let title = jaccard(&title_words(&a.title), &title_words(&b.title));
+    title >= SAME_TITLE
+        || (title >= SIMILAR_TITLE
+            && jaccard(&description_words(&a.description), &description_words(&b.description))
+                >= SIMILAR_DESCRIPTION)
+}
+
+/// Link each posting to the first earlier posting of the same job
+///
+/// `jobs` must be in the order they were stored, so that the oldest posting
+/// of a job stays canonical. Returns `(duplicate, canonical)` id pairs.
+pub fn find_duplicates(jobs: &[Job]) -> Vec<(Uuid, Uuid)> {
+    // Only postings of the same company can be duplicates
+    let mut canonical: HashMap<String, Vec<&Job>> = HashMap::new();
+    let mut duplicates = Vec::new();
+    for job in jobs {
+        let company = canonical.entry(normalize_organisation(&job.company)).or_default();
+        match company.iter().find(|c| is_duplicate(job, c)) {
+            Some(original) => duplicates.push((job.id, original.id)),
+            None => company.push(job),
+        }
+    }
+    duplicates
+}
+
+/// Locations are compatible when either names no place (e.g. just
+/// "Remote") or they share a place name
+fn compatible_locations(a: &str, b: &str) -> bool {
+    let places = |location: &str| -> HashSet<String> {
+        words(location)
+            .filter(|word| word.len() > 1 && !LOCATION_NOISE.contains(&word.as_str()))
+            .collect()
+    };
+    let (a, b) = (places(a), places(b));
+    a.is_empty() || b.is_empty() || !a.is_disjoint(&b)
+}
+
+fn title_words(title: &str) -> HashSet<String> {
+    words(title)
+        .filter(|word| !TITLE_NOISE.contains(&word.as_str()))
+        .map(|word| match word.as_str() {
+            "sr" => "senior".to_string(),
+            "jr" => "junior".to_string(),
+            "eng" | "engr" => "engineer".to_string(),
+            "dev" => "developer".to_string(),
+            "mgr" => "manager".to_string(),
+            _ => word,
+        })
+        .collect()
+}
+
+fn description_words(description: &str) -> HashSet<String> {
+    words(description)
+        .filter(|word| word.len() > 2 && !STOP_WORDS.contains(&word.as_str()))
+        .collect()
+}
+
+/// Lowercase alphanumeric words
+fn words(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+}
+
+/// Share of words in either set that are in both
+fn jaccard(a: &HashSet<String>, b: &HashSet<String>) -> f64 {
+    let union = a.union(b).count();
+    if union == 0 {
+        return 0.0;
+    }
+    a.intersection(b).count() as f64 / union as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::JobSource;
+    use crate::test_fixtures::job;
+
+    fn posting(title: &str, company: &str, location: &str, description: &str) -> Job {
+        Job { description: description.to_string(), ..job(title, company, location, JobSource::Remotive) }
+    }
+
+    const DESCRIPTION: &str = "Build payment APIs in Rust and PostgreSQL for merchants across Europe. \
+        You will own services end to end, from design reviews to on-call.";
+
+    #[test]
+    fn test_same_title_at_same_company() {
+        let a = posting("Senior Backend Engineer", "Acme Corp", "Berlin, Germany", DESCRIPTION);
+        let b = posting("Sr. Backend Engineer (m/w/d)", "ACME GmbH", "Berlin", "Apply via our careers page.");
+        assert!(is_duplicate(&a, &b));
+    }
+
+    #[test]
+    fn test_similar_title_needs_similar_description() {
+        let a = posting("Backend Engineer", "Acme", "Remote", DESCRIPTION);
+        let b = posting("Backend Engineer, Payments", "Acme", "Remote - Europe", &format!("{} Visa sponsorship available.", DESCRIPTION));
+        assert!(is_duplicate(&a, &b));
+
+        let c = posting("Backend Engineer, Payments", "Acme", "Remote", "Grow our mobile apps written in Swift and Kotlin.");
+        assert!(!is_duplicate(&a, &c));
+    }
+
+    #[test]
+    fn test_different_company_or_place_is_not_a_duplicate() {
+        let a = posting("Backend Engineer", "Acme", "Berlin", DESCRIPTION);
+        assert!(!is_duplicate(&a, &posting("Backend Engineer", "Globex", "Berlin", DESCRIPTION)));
+        assert!(!is_duplicate(&a, &posting("Backend Engineer", "Acme", "Munich", DESCRIPTION)));
+        assert!(is_duplicate(&a, &posting("Backend Engineer", "Acme", "Remote", DESCRIPTION)));
+    }
+
+    #[test]
+    fn test_postings_of_one_source_with_their_own_ids_are_different_jobs() {
+        let board = |id: &str, location: &str| Job {
+            source: JobSource::Greenhouse,
+            source_job_id: Some(id.to_string()),
+            ..posting("Backend Engineer", "Acme", location, DESCRIPTION)
+        };
+        assert!(!is_duplicate(&board("4001", "Berlin"), &board("4002", "Remote")));
+        assert!(is_duplicate(&board("4001", "Berlin"), &Job { source: JobSource::Lever, ..board("77", "Remote") }));
+    }
+
+    #[test]
+    fn test_find_duplicates_keeps_the_oldest_posting() {
+        let first = posting("Backend Engineer", "Acme", "Berlin", DESCRIPTION);
+        let other = posting("Data Engineer", "Acme", "Berlin", DESCRIPTION);
+        let second = posting("Backend Engineer", "ACME GmbH", "Remote", DESCRIPTION);
+        let third = posting("Backend Engineer (m/w/d)", "Acme Corp", "Berlin", DESCRIPTION);
+        let pairs = find_duplicates(&[first.clone(), other, second.clone(), third.clone()]);
+        assert_eq!(pairs, vec![(second.id, first.id), (third.id, first.id)]);
+    }
+}
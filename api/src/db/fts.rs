@@ -0,0 +1,338 @@
+//! Full-text search query translation
+//!
+//! Users type search-engine style queries such as
+//! `rust AND (tokio OR async) -"crypto"`. SQLite FTS5 has its own syntax
+//! (binary `NOT`, no `-` prefix, restricted barewords), so the user query is
+//! parsed into a small expression tree and rendered as a safe FTS5 MATCH
+//! expression. Every term is emitted as a quoted string, so punctuation in
+//! user input can never produce an FTS5 syntax error.
+//!
+//! Supported syntax:
+//! - `word` / `"exact phrase"` - terms and phrases
+//! - `word*` - prefix match
+//! - `a b` / `a AND b` - both terms (implicit AND)
+//! - `a OR b` - either term
+//! - `-a` / `NOT a` - exclude a term, phrase or group
+//! - `( ... )` - grouping
+
+use anyhow::{anyhow, Result};
+
+use crate::models::HighlightSpan;
+
+/// Marker placed before a matched term by `snippet()`
+pub const HIGHLIGHT_START: char = '\u{2}';
+/// Marker placed after a matched term by `snippet()`
+pub const HIGHLIGHT_END: char = '\u{3}';
+
+/// Parsed search expression
+#[derive(Debug, Clone, PartialEq)]
+enum Expr {
+    Term { text: String, prefix: bool },
+    Phrase(String),
+    And(Vec<Expr>),
+    Or(Vec<Expr>),
+    Not(Box<Expr>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Word(String),
+    Phrase(String),
+    And,
+    Or,
+    Not,
+    Minus,
+    LParen,
+    RParen,
+}
+
+/// Translate a user search query into an FTS5 MATCH expression
+///
+/// Returns `Ok(None)` if the query contains no searchable terms.
+pub fn to_fts5_query(input: &str) -> Result<Option<String>> {
+    let tokens = tokenize(input);
+    if tokens.is_empty() {
+        return Ok(None);
+    }
+
+    let mut parser = Parser { tokens, pos: 0 };
+    let expr = match parser.parse_or()? {
+        Some(expr) => expr,
+        None => return Ok(None),
+    };
+    if parser.pos < parser.tokens.len() {
+        return Err(anyhow!("Unbalanced parentheses in search query"));
+    }
+
+    render(&expr).map(Some)
+}
+
+/// Split a snippet produced with [`HIGHLIGHT_START`]/[`HIGHLIGHT_END`]
+/// markers into plain and highlighted spans
+pub fn parse_snippet(snippet: &str) -> Vec<HighlightSpan> {
+    let mut spans = Vec::new();
+    let mut current = String::new();
+    let mut highlighted = false;
+
+    for c in snippet.chars() {
+        if c == HIGHLIGHT_START || c == HIGHLIGHT_END {
+            if !current.is_empty() {
+                spans.push(HighlightSpan { text: std::mem::take(&mut current), highlighted });
+            }
+            highlighted = c == HIGHLIGHT_START;
+        } else {
+            current.push(c);
+        }
+    }
+    if !current.is_empty() {
+        spans.push(HighlightSpan { text: current, highlighted });
+    }
+
+    spans
+}
+
+fn tokenize(input: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        match c {
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            '(' => {
+                chars.next();
+                tokens.push(Token::LParen);
+            }
+            ')' => {
+                chars.next();
+                tokens.push(Token::RParen);
+            }
+            '-' => {
+                chars.next();
+                tokens.push(Token::Minus);
+            }
+            '"' => {
+                chars.next();
+                let mut phrase = String::new();
+                for c in chars.by_ref() {
+                    if c == '"' {
+                        break;
+                    }
+                    phrase.push(c);
+                }
+                if !phrase.trim().is_empty() {
+                    tokens.push(Token::Phrase(phrase.trim().to_string()));
+                }
+            }
+            _ => {
+                let mut word = String::new();
+                while let Some(&c) = chars.peek() {
+                    if c.is_whitespace() || c == '(' || c == ')' || c == '"' {
+                        break;
+                    }
+                    word.push(c);
+                    chars.next();
+                }
+                tokens.push(match word.as_str() {
+                    "AND" => Token::And,
+                    "OR" => Token::Or,
+                    "NOT" => Token::Not,
+                    _ => Token::Word(word),
+                });
+            }
+        }
+    }
+
+    tokens
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn parse_or(&mut self) -> Result<Option<Expr>> {
+        let mut branches = Vec::new();
+        if let Some(expr) = self.parse_and()? {
+            branches.push(expr);
+        }
+
+        while self.peek() == Some(&Token::Or) {
+            self.pos += 1;
+            if let Some(expr) = self.parse_and()? {
+                branches.push(expr);
+            }
+        }
+
+        Ok(match branches.len() {
+            0 => None,
+            1 => branches.pop(),
+            _ => Some(Expr::Or(branches)),
+        })
+    }
+
+    fn parse_and(&mut self) -> Result<Option<Expr>> {
+        let mut items = Vec::new();
+
+        loop {
+            match self.peek() {
+                None | Some(Token::Or) | Some(Token::RParen) => break,
+                Some(Token::And) => {
+                    self.pos += 1;
+                }
+                _ => {
+                    if let Some(expr) = self.parse_unary()? {
+                        items.push(expr);
+                    }
+                }
+            }
+        }
+
+        Ok(match items.len() {
+            0 => None,
+            1 => items.pop(),
+            _ => Some(Expr::And(items)),
+        })
+    }
+
+    fn parse_unary(&mut self) -> Result<Option<Expr>> {
+        match self.peek() {
+            Some(Token::Minus) | Some(Token::Not) => {
+                self.pos += 1;
+                Ok(self.parse_primary()?.map(|e| Expr::Not(Box::new(e))))
+            }
+            _ => self.parse_primary(),
+        }
+    }
+
+    fn parse_primary(&mut self) -> Result<Option<Expr>> {
+        let token = match self.peek() {
+            Some(token) => token.clone(),
+            None => return Ok(None),
+        };
+        self.pos += 1;
+
+        match token {
+            Token::LParen => {
+                let expr = self.parse_or()?;
+                if self.peek() != Some(&Token::RParen) {
+                    return Err(anyhow!("Unbalanced parentheses in search query"));
+                }
+                self.pos += 1;
+                Ok(expr)
+            }
+            Token::Phrase(phrase) => Ok(Some(Expr::Phrase(phrase))),
+            Token::Word(word) => {
+                let prefix = word.ends_with('*');
+                let text = word.trim_end_matches('*');
+                // Words made only of punctuation have nothing to index
+                if !text.chars().any(|c| c.is_alphanumeric()) {
+                    return Ok(None);
+                }
+                Ok(Some(Expr::Term { text: text.to_string(), prefix }))
+            }
+            Token::RParen => Err(anyhow!("Unbalanced parentheses in search query")),
+            // Stray operators (e.g. a trailing "AND") are ignored
+            Token::And | Token::Or | Token::Not | Token::Minus => Ok(None),
+        }
+    }
+}
+
+fn quote(text: &str) -> String {
+    format!("\"{}\"", text.replace('"', "\"\""))
+}
+
+fn render(expr: &Expr) -> Result<String> {
+    match expr {
+        Expr::Term { text, prefix } => Ok(if *prefix {
+            format!("{}*", quote(text))
+        } else {
+            quote(text)
+        }),
+        Expr::Phrase(phrase) => Ok(quote(phrase)),
+        Expr::Or(branches) => {
+            let rendered = branches.iter().map(render).collect::<Result<Vec<_>>>()?;
+            Ok(format!("({})", rendered.join(" OR ")))
+        }
+        Expr::And(items) => {
+            // FTS5's NOT is a binary operator, so exclusions are attached
+            // to the conjunction of the positive terms
+            let (negatives, positives): (Vec<&Expr>, Vec<&Expr>) =
+                items.iter().partition(|e| matches!(e, Expr::Not(_)));
+            if positives.is_empty() {
+                return Err(anyhow!("Search query needs at least one term that is not excluded"));
+            }
+
+            let positives = positives.into_iter().map(render).collect::<Result<Vec<_>>>()?;
+            let mut out = format!("({})", positives.join(" AND "));
+            for negative in negatives {
+                if let Expr::Not(inner) = negative {
+                    out = format!("({} NOT {})", out, render(inner)?);
+                }
+            }
+            Ok(out)
+        }
+        Expr::Not(_) => Err(anyhow!("Search query needs at least one term that is not excluded")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_simple_terms() {
+        assert_eq!(to_fts5_query("rust").unwrap().unwrap(), "\"rust\"");
+        assert_eq!(to_fts5_query("rust tokio").unwrap().unwrap(), "(\"rust\" AND \"tokio\")");
+    }
+
+    #[test]
+    fn test_boolean_query() {
+        let query = to_fts5_query("rust AND (tokio OR async) -\"crypto\"").unwrap().unwrap();
+        assert_eq!(query, "((\"rust\" AND (\"tokio\" OR \"async\")) NOT \"crypto\")");
+    }
+
+    #[test]
+    fn test_phrase_and_prefix() {
+        assert_eq!(
+            to_fts5_query("\"machine learning\" eng*").unwrap().unwrap(),
+            "(\"machine learning\" AND \"eng\"*)"
+        );
+    }
+
+    #[test]
+    fn test_punctuation_is_quoted() {
+        assert_eq!(to_fts5_query("c++ node.js").unwrap().unwrap(), "(\"c++\" AND \"node.js\")");
+        assert_eq!(to_fts5_query("say \"hi").unwrap().unwrap(), "(\"say\" AND \"hi\")");
+    }
+
+    #[test]
+    fn test_empty_and_invalid() {
+        assert_eq!(to_fts5_query("   ").unwrap(), None);
+        assert_eq!(to_fts5_query("&& ||").unwrap(), None);
+        assert!(to_fts5_query("-crypto").is_err());
+        assert!(to_fts5_query("(rust").is_err());
+        assert!(to_fts5_query("rust)").is_err());
+    }
+
+    #[test]
+    fn test_parse_snippet() {
+        let spans = parse_snippet("…build \u{2}Rust\u{3} services with \u{2}tokio\u{3}");
+        assert_eq!(spans.len(), 4);
+        assert_eq!(spans[0], HighlightSpan { text: "…build ".to_string(), highlighted: false });
+        assert_eq!(spans[1], HighlightSpan { text: "Rust".to_string(), highlighted: true });
+        assert_eq!(spans[3], HighlightSpan { text: "tokio".to_string(), highlighted: true });
+        assert!(parse_snippet("").is_empty());
+    }
+
+    #[test]
+    fn test_lowercase_operators_are_terms() {
+        assert_eq!(to_fts5_query("rock and roll").unwrap().unwrap(), "(\"rock\" AND \"and\" AND \"roll\")");
+    }
+}
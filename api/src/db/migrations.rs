@@ -0,0 +1,465 @@
+//! Versioned schema migrations
+//!
+//! Each migration is a numbered SQL script in `migrations/` that is embedded
+//! at compile time. Applied migrations are recorded in the `schema_migrations`
+//! table together with a checksum of their SQL, so that on startup only the
+//! pending migrations are run and edits to already-applied scripts are caught.
+//!
+//! To change the schema, add a new `NNNN_description.sql` file and append it
+//! to [`MIGRATIONS`]. Never edit a migration that has already been released.
+
+use anyhow::{anyhow, Result};
+use chrono::Utc;
+use sha2::{Digest, Sha256};
+use sqlx::{Row, SqlitePool};
+
+/// A single schema migration
+#[derive(Debug, Clone, Copy)]
+pub struct Migration {
+    pub version: i64,
+    pub name: &'static str,
+    pub sql: &'static str,
+}
+
+impl Migration {
+    /// SHA-256 checksum of the migration SQL, hex encoded
+    pub fn checksum(&self) -> String {
+        format!("{:x}", Sha256::digest(self.sql.as_bytes()))
+    }
+}
+
+/// All known migrations, in the order they must be applied
+pub const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        name: "initial_schema",
+        sql: include_str!("migrations/0001_initial_schema.sql"),
+    },
+    Migration {
+        version: 2,
+        name: "project_github_url",
+        sql: include_str!("migrations/0002_project_github_url.sql"),
+    },
+    Migration {
+        version: 3,
+        name: "jobs_fts",
+        sql: include_str!("migrations/0003_jobs_fts.sql"),
+    },
+    Migration {
+        version: 4,
+        name: "backfill_is_remote",
+        sql: include_str!("migrations/0004_backfill_is_remote.sql"),
+    },
+    Migration {
+        version: 5,
+        name: "backfill_status_history",
+        sql: include_str!("migrations/0005_backfill_status_history.sql"),
+    },
+    Migration {
+        version: 6,
+        name: "custom_skills",
+        sql: include_str!("migrations/0006_custom_skills.sql"),
+    },
+    Migration {
+        version: 7,
+        name: "job_duplicates",
+        sql: include_str!("migrations/0007_job_duplicates.sql"),
+    },
+    Migration {
+        version: 8,
+        name: "job_source_ids",
+        sql: include_str!("migrations/0008_job_source_ids.sql"),
+    },
+    Migration {
+        version: 9,
+        name: "fetch_runs",
+        sql: include_str!("migrations/0009_fetch_runs.sql"),
+    },
+    Migration {
+        version: 10,
+        name: "saved_searches",
+        sql: include_str!("migrations/0010_saved_searches.sql"),
+    },
+    Migration {
+        version: 11,
+        name: "company_boards",
+        sql: include_str!("migrations/0011_company_boards.sql"),
+    },
+    Migration {
+        version: 12,
+        name: "default_user",
+        sql: include_str!("migrations/0012_default_user.sql"),
+    },
+    Migration {
+        version: 13,
+        name: "data_migrations",
+        sql: include_str!("migrations/0013_data_migrations.sql"),
+    },
+    Migration {
+        version: 14,
+        name: "jobs_fts_map",
+        sql: include_str!("migrations/0014_jobs_fts_map.sql"),
+    },
+];
+
+/// Data migration linking the duplicate postings stored before duplicates
+/// were tracked
+pub const LINK_JOB_DUPLICATES: &str = "link_job_duplicates";
+
+/// A migration that has been recorded as applied
+#[derive(Debug, Clone)]
+pub struct AppliedMigration {
+    pub version: i64,
+    pub name: String,
+    pub checksum: String,
+    pub applied_at: String,
+}
+
+/// Bring the database schema up to date
+///
+/// Returns the versions of the migrations that were applied by this call.
+pub async fn run_migrations(pool: &SqlitePool) -> Result<Vec<i64>> {
+    apply_migrations(pool, MIGRATIONS).await
+}
+
+/// Get the highest applied migration version (0 for an empty database)
+pub async fn current_version(pool: &SqlitePool) -> Result<i64> {
+    ensure_migrations_table(pool).await?;
+    let version: Option<i64> = sqlx::query_scalar("SELECT MAX(version) FROM schema_migrations")
+        .fetch_one(pool)
+        .await?;
+    Ok(version.unwrap_or(0))
+}
+
+async fn apply_migrations(pool: &SqlitePool, migrations: &[Migration]) -> Result<Vec<i64>> {
+    ensure_migrations_table(pool).await?;
+
+    let applied = applied_migrations(pool).await?;
+
+    // Verify that what has already been applied matches what this build knows about
+    for record in &applied {
+        match migrations.iter().find(|m| m.version == record.version) {
+            Some(migration) if migration.checksum() != record.checksum => {
+                return Err(anyhow!(
+                    "Migration {} ({}) has been modified after it was applied",
+                    record.version,
+                    record.name
+                ));
+            }
+            Some(_) => {}
+            None => {
+                return Err(anyhow!(
+                    "Database has migration {} ({}) which is unknown to this version of the application",
+                    record.version,
+                    record.name
+                ));
+            }
+        }
+    }
+
+    let mut newly_applied = Vec::new();
+
+    for migration in migrations {
+        if applied.iter().any(|a| a.version == migration.version) {
+            continue;
+        }
+
+        let mut tx = pool.begin().await?;
+
+        sqlx::query(migration.sql)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| anyhow!("Migration {} ({}) failed: {}", migration.version, migration.name, e))?;
+
+        sqlx::query(
+            "INSERT INTO schema_migrations (version, name, checksum, applied_at) VALUES (?, ?, ?, ?)"
+        )
+        .bind(migration.version)
+        .bind(migration.name)
+        .bind(migration.checksum())
+        .bind(Utc::now().to_rfc3339())
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        tracing::info!("Applied migration {} ({})", migration.version, migration.name);
+        newly_applied.push(migration.version);
+    }
+
+    Ok(newly_applied)
+}
+
+/// Whether the named data migration has succeeded before
+pub async fn data_migration_applied(pool: &SqlitePool, name: &str) -> Result<bool> {
+    let applied: Option<String> = sqlx::query_scalar("SELECT name FROM data_migrations WHERE name = ?")
+        .bind(name)
+        .fetch_optional(pool)
+        .await?;
+    Ok(applied.is_some())
+}
+
+/// Record that the named data migration has succeeded
+pub async fn record_data_migration(pool: &SqlitePool, name: &str) -> Result<()> {
+    sqlx::query("INSERT OR IGNORE INTO data_migrations (name, applied_at) VALUES (?, ?)")
+        .bind(name)
+        .bind(Utc::now().to_rfc3339())
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+/// List the migrations recorded in `schema_migrations`, oldest first
+pub async fn applied_migrations(pool: &SqlitePool) -> Result<Vec<AppliedMigration>> {
+    ensure_migrations_table(pool).await?;
+
+    let rows = sqlx::query("SELECT * FROM schema_migrations ORDER BY version")
+        .fetch_all(pool)
+        .await?;
+
+    Ok(rows
+        .iter()
+        .map(|row| AppliedMigration {
+            version: row.get("version"),
+            name: row.get("name"),
+            checksum: row.get("checksum"),
+            applied_at: row.get("applied_at"),
+        })
+        .collect())
+}
+
+async fn ensure_migrations_table(pool: &SqlitePool) -> Result<()> {
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS schema_migrations (
+            version INTEGER PRIMARY KEY,
+            name TEXT NOT NULL,
+            checksum TEXT NOT NULL,
+            applied_at TEXT NOT NULL
+        )
+        "#
+    )
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn memory_pool() -> SqlitePool {
+        SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap()
+    }
+
+    async fn column_names(pool: &SqlitePool, table: &str) -> Vec<String> {
+        sqlx::query(&format!("PRAGMA table_info({})", table))
+            .fetch_all(pool)
+            .await
+            .unwrap()
+            .iter()
+            .map(|row| row.get("name"))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_fresh_database_applies_all_migrations() {
+        let pool = memory_pool().await;
+
+        let applied = run_migrations(&pool).await.unwrap();
+        assert_eq!(applied.len(), MIGRATIONS.len());
+        assert_eq!(current_version(&pool).await.unwrap(), MIGRATIONS.last().unwrap().version);
+        assert!(column_names(&pool, "projects").await.contains(&"github_url".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_rerun_is_noop() {
+        let pool = memory_pool().await;
+
+        run_migrations(&pool).await.unwrap();
+        let applied = run_migrations(&pool).await.unwrap();
+        assert!(applied.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_legacy_database_is_upgraded() {
+        let pool = memory_pool().await;
+
+        // Databases created before versioned migrations only ran the initial script
+        sqlx::query(MIGRATIONS[0].sql).execute(&pool).await.unwrap();
+        sqlx::query("INSERT INTO users (id, email, name) VALUES ('u1', 'a@example.com', 'A')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query(
+            "INSERT INTO jobs (id, title, company, source, source_url) \
+             VALUES ('j1', 'Rust Engineer', 'Acme', 'Remotive', 'https://example.com/j1')"
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            "INSERT INTO jobs (id, title, company, source, source_url) VALUES \
+             ('j2', 'Go Developer', 'Globex', 'HN Who''s Hiring', 'https://news.ycombinator.com/item?id=4242'), \
+             ('j3', 'Backend Engineer', 'Initech', 'Remotive', 'https://remotive.com/remote-jobs/dev/backend-engineer-1234567'), \
+             ('j4', 'Data Engineer', 'Umbrella', 'Arbeitnow', 'https://www.arbeitnow.com/jobs/companies/umbrella/data-engineer-berlin-77')"
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            "INSERT INTO job_applications (id, user_id, job_id, status, created_at, updated_at) \
+             VALUES ('a1', 'u1', 'j1', 'applied', '2024-01-01T00:00:00+00:00', '2024-01-02T00:00:00+00:00')"
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        run_migrations(&pool).await.unwrap();
+
+        // Existing users are kept, next to the default user
+        let users: Vec<String> = sqlx::query_scalar("SELECT id FROM users ORDER BY id")
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+        assert_eq!(users, vec![crate::models::DEFAULT_USER_ID.to_string(), "u1".to_string()]);
+        assert!(column_names(&pool, "projects").await.contains(&"github_url".to_string()));
+
+        // Existing postings are backfilled into the full-text index
+        let indexed: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM jobs_fts WHERE jobs_fts MATCH 'rust'")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(indexed, 1);
+        let mapped: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM jobs_fts_map JOIN jobs_fts ON jobs_fts.rowid = jobs_fts_map.fts_rowid \
+             WHERE jobs_fts_map.job_id = jobs_fts.job_id"
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(mapped, 4);
+
+        // Existing applications start their status history with the current status
+        let history: Vec<(String, String)> =
+            sqlx::query_as("SELECT id, status FROM application_status_history WHERE application_id = 'a1'")
+                .fetch_all(&pool)
+                .await
+                .unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].1, "applied");
+        assert!(uuid::Uuid::parse_str(&history[0].0).is_ok());
+
+        // Sources are stored by identifier, with the posting's id at the source
+        let sources: Vec<(String, Option<String>)> =
+            sqlx::query_as("SELECT source, source_job_id FROM jobs ORDER BY id")
+                .fetch_all(&pool)
+                .await
+                .unwrap();
+        assert_eq!(
+            sources,
+            vec![
+                ("remotive".to_string(), None),
+                ("hn_who_is_hiring".to_string(), Some("4242".to_string())),
+                ("remotive".to_string(), Some("1234567".to_string())),
+                ("arbeitnow".to_string(), Some("data-engineer-berlin-77".to_string())),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unchanged_upsert_leaves_fts_alone() {
+        let pool = memory_pool().await;
+        run_migrations(&pool).await.unwrap();
+        sqlx::query(
+            "INSERT INTO jobs (id, title, company, description, requirements, source, source_url) \
+             VALUES ('j1', 'Rust Engineer', 'Acme', 'Build things', '[]', 'remotive', 'https://example.com/1')"
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        // Rewrites the searchable text as upsert_by_source does; changes made
+        // by triggers count towards total_changes()
+        let rewrite = |title: &'static str| {
+            let pool = pool.clone();
+            async move {
+                let before: i64 = sqlx::query_scalar("SELECT total_changes()").fetch_one(&pool).await.unwrap();
+                sqlx::query(
+                    "UPDATE jobs SET title = ?, company = 'Acme', description = 'Build things', \
+                     requirements = '[]' WHERE id = 'j1'"
+                )
+                .bind(title)
+                .execute(&pool)
+                .await
+                .unwrap();
+                let after: i64 = sqlx::query_scalar("SELECT total_changes()").fetch_one(&pool).await.unwrap();
+                after - before
+            }
+        };
+        // Only the jobs row changes; re-indexing also writes to jobs_fts
+        assert_eq!(rewrite("Rust Engineer").await, 1);
+        assert!(rewrite("Go Engineer").await > 1);
+
+        let matches = |term: &'static str| {
+            let pool = pool.clone();
+            async move {
+                sqlx::query_scalar::<_, String>("SELECT job_id FROM jobs_fts WHERE jobs_fts MATCH ?")
+                    .bind(term)
+                    .fetch_all(&pool)
+                    .await
+                    .unwrap()
+            }
+        };
+        assert!(matches("rust").await.is_empty());
+        assert_eq!(matches("go").await, vec!["j1".to_string()]);
+
+        sqlx::query("DELETE FROM jobs WHERE id = 'j1'").execute(&pool).await.unwrap();
+        assert!(matches("go").await.is_empty());
+        let mapped: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM jobs_fts_map").fetch_one(&pool).await.unwrap();
+        assert_eq!(mapped, 0);
+    }
+
+    #[tokio::test]
+    async fn test_modified_migration_is_rejected() {
+        let pool = memory_pool().await;
+
+        run_migrations(&pool).await.unwrap();
+
+        let mut modified = MIGRATIONS.to_vec();
+        modified[1].sql = "ALTER TABLE projects ADD COLUMN something_else TEXT;";
+
+        let err = apply_migrations(&pool, &modified).await.unwrap_err();
+        assert!(err.to_string().contains("modified"));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_applied_migration_is_rejected() {
+        let pool = memory_pool().await;
+
+        run_migrations(&pool).await.unwrap();
+
+        let err = apply_migrations(&pool, &MIGRATIONS[..1]).await.unwrap_err();
+        assert!(err.to_string().contains("unknown"));
+    }
+
+    #[tokio::test]
+    async fn test_failed_migration_is_not_recorded() {
+        let pool = memory_pool().await;
+
+        let mut broken = MIGRATIONS.to_vec();
+        broken.push(Migration {
+            version: 999,
+            name: "broken",
+            sql: "ALTER TABLE no_such_table ADD COLUMN x TEXT;",
+        });
+
+        assert!(apply_migrations(&pool, &broken).await.is_err());
+        assert_eq!(current_version(&pool).await.unwrap(), MIGRATIONS.last().unwrap().version);
+    }
+}
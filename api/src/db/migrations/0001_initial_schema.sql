@@ -1,5 +1,8 @@
 -- Employment Barage Database Schema
--- SQLite migrations (idempotent)
+-- Migration 0001: initial schema
+--
+-- Uses IF NOT EXISTS so databases created before versioned migrations
+-- existed can adopt this migration without losing data.
 
 -- Users table
 CREATE TABLE IF NOT EXISTS users (
@@ -28,9 +31,6 @@ CREATE TABLE IF NOT EXISTS profiles (
     UNIQUE(user_id)
 );
 
--- Add name and email columns if they don't exist (for existing databases)
--- SQLite doesn't support IF NOT EXISTS for ALTER TABLE, so we use a migration-safe approach
-
 -- Work experience
 CREATE TABLE IF NOT EXISTS experiences (
     id TEXT PRIMARY KEY,
@@ -0,0 +1,3 @@
+-- Migration 0002: store a project's source repository separately from its homepage
+
+ALTER TABLE projects ADD COLUMN github_url TEXT;
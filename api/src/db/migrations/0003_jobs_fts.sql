@@ -0,0 +1,34 @@
+-- Migration 0003: full-text index over job postings
+--
+-- jobs_fts holds its own copy of the searchable text (jobs has a TEXT
+-- primary key, so an external-content table keyed by rowid would not stay
+-- stable). Triggers keep it in sync with jobs.
+
+CREATE VIRTUAL TABLE IF NOT EXISTS jobs_fts USING fts5(
+    job_id UNINDEXED,
+    title,
+    company,
+    description,
+    requirements,
+    tokenize = 'porter unicode61'
+);
+
+CREATE TRIGGER IF NOT EXISTS jobs_fts_insert AFTER INSERT ON jobs BEGIN
+    INSERT INTO jobs_fts (job_id, title, company, description, requirements)
+    VALUES (new.id, new.title, new.company, new.description, new.requirements);
+END;
+
+CREATE TRIGGER IF NOT EXISTS jobs_fts_delete AFTER DELETE ON jobs BEGIN
+    DELETE FROM jobs_fts WHERE job_id = old.id;
+END;
+
+CREATE TRIGGER IF NOT EXISTS jobs_fts_update
+AFTER UPDATE OF title, company, description, requirements ON jobs BEGIN
+    DELETE FROM jobs_fts WHERE job_id = old.id;
+    INSERT INTO jobs_fts (job_id, title, company, description, requirements)
+    VALUES (new.id, new.title, new.company, new.description, new.requirements);
+END;
+
+-- Index postings that were stored before this migration
+INSERT INTO jobs_fts (job_id, title, company, description, requirements)
+SELECT id, title, company, description, requirements FROM jobs;
@@ -0,0 +1,5 @@
+-- Migration 0004: populate jobs.is_remote for postings stored before it was written
+--
+-- Job sources report remote work as part of the location text.
+
+UPDATE jobs SET is_remote = 1 WHERE location LIKE '%remote%';
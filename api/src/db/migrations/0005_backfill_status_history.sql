@@ -0,0 +1,17 @@
+-- Migration 0005: start a status history for applications created before it was recorded
+--
+-- Each existing application gets a single entry with its current status.
+-- Ids are random v4-format UUIDs.
+
+INSERT INTO application_status_history (id, application_id, status, notes, created_at)
+SELECT
+    lower(
+        substr(h, 1, 8) || '-' || substr(h, 9, 4) || '-4' || substr(h, 14, 3) || '-8' ||
+        substr(h, 18, 3) || '-' || substr(h, 21, 12)
+    ),
+    id,
+    status,
+    NULL,
+    updated_at
+FROM (SELECT hex(randomblob(16)) AS h, id, status, updated_at FROM job_applications)
+WHERE id NOT IN (SELECT application_id FROM application_status_history);
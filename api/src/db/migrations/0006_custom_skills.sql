@@ -0,0 +1,11 @@
+-- Migration 0006: skills the user adds to the built-in skill taxonomy
+--
+-- Aliases are stored as a JSON array of strings.
+
+CREATE TABLE IF NOT EXISTS custom_skills (
+    name TEXT PRIMARY KEY COLLATE NOCASE,
+    category TEXT NOT NULL,
+    aliases TEXT NOT NULL DEFAULT '[]',
+    case_sensitive INTEGER NOT NULL DEFAULT 0,
+    created_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
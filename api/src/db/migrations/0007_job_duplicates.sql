@@ -0,0 +1,11 @@
+-- Migration 0007: link postings of the same job from different sources
+--
+-- A posting with duplicate_of set is another listing of that canonical job.
+-- Searches return only canonical jobs, with their duplicates as extra sources.
+-- Duplicates are detected by fuzzy matching in Rust (see db/dedup.rs), so
+-- Database links the postings stored before this migration after the schema
+-- migrations have run.
+
+ALTER TABLE jobs ADD COLUMN duplicate_of TEXT REFERENCES jobs(id) ON DELETE SET NULL;
+
+CREATE INDEX IF NOT EXISTS idx_jobs_duplicate_of ON jobs(duplicate_of);
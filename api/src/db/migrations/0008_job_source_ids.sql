@@ -0,0 +1,30 @@
+-- Migration 0008: store job sources by their stable identifier and backfill
+-- each posting's id at its source
+--
+-- Sources used to be stored by display name ("HN Who's Hiring").
+
+UPDATE jobs SET source = CASE source
+    WHEN 'LinkedIn' THEN 'linkedin'
+    WHEN 'Indeed' THEN 'indeed'
+    WHEN 'Glassdoor' THEN 'glassdoor'
+    WHEN 'AngelList' THEN 'angellist'
+    WHEN 'Remotive' THEN 'remotive'
+    WHEN 'HN Who''s Hiring' THEN 'hn_who_is_hiring'
+    WHEN 'Arbeitnow' THEN 'arbeitnow'
+    ELSE source
+END;
+
+-- HN comment URLs end in "item?id=<id>"
+UPDATE jobs SET source_job_id = substr(source_url, instr(source_url, 'item?id=') + 8)
+WHERE source = 'hn_who_is_hiring' AND source_job_id IS NULL AND instr(source_url, 'item?id=') > 0;
+
+-- Remotive URLs end in "-<id>"
+UPDATE jobs SET source_job_id = substr(source_url, length(rtrim(source_url, '0123456789')) + 1)
+WHERE source = 'remotive' AND source_job_id IS NULL
+    AND source_url GLOB '*[0-9]' AND rtrim(source_url, '0123456789') LIKE '%-';
+
+-- Arbeitnow URLs end in the posting's slug
+UPDATE jobs SET source_job_id = substr(source_url, length(rtrim(source_url, replace(source_url, '/', ''))) + 1)
+WHERE source = 'arbeitnow' AND source_job_id IS NULL AND source_url NOT LIKE '%/';
+
+CREATE INDEX IF NOT EXISTS idx_jobs_source_job_id ON jobs(source, source_job_id);
@@ -0,0 +1,17 @@
+-- Migration 0009: history of scheduled job source fetches
+--
+-- One row per fetch of one source. `finished_at` stays NULL while the fetch
+-- is running (or if the server stopped during it).
+
+CREATE TABLE IF NOT EXISTS fetch_runs (
+    id TEXT PRIMARY KEY,
+    source TEXT NOT NULL,
+    started_at TEXT NOT NULL,
+    finished_at TEXT,
+    fetched INTEGER NOT NULL DEFAULT 0,
+    saved INTEGER NOT NULL DEFAULT 0,
+    errors INTEGER NOT NULL DEFAULT 0,
+    error TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_fetch_runs_source_started ON fetch_runs(source, started_at);
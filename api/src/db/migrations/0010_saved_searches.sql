@@ -0,0 +1,24 @@
+-- Migration 0010: saved job searches and the jobs they have matched
+--
+-- The query is a JSON-serialized `JobSearchQuery`. Jobs matching when the
+-- search is saved are recorded as seen; jobs that start matching after a
+-- later fetch are recorded unseen until the user has looked at them.
+
+CREATE TABLE IF NOT EXISTS saved_searches (
+    id TEXT PRIMARY KEY,
+    user_id TEXT NOT NULL,
+    name TEXT NOT NULL,
+    query TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    last_checked_at TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_saved_searches_user ON saved_searches(user_id);
+
+CREATE TABLE IF NOT EXISTS saved_search_matches (
+    search_id TEXT NOT NULL REFERENCES saved_searches(id) ON DELETE CASCADE,
+    job_id TEXT NOT NULL REFERENCES jobs(id) ON DELETE CASCADE,
+    found_at TEXT NOT NULL,
+    seen INTEGER NOT NULL DEFAULT 0,
+    PRIMARY KEY (search_id, job_id)
+);
@@ -0,0 +1,15 @@
+-- Migration 0011: company job boards on applicant tracking systems
+--
+-- Each row is a company board the user follows, e.g. source 'greenhouse'
+-- with token 'stripe' for boards.greenhouse.io/stripe. ATS postings carry
+-- a department, which is stored with the job.
+
+CREATE TABLE IF NOT EXISTS company_boards (
+    source TEXT NOT NULL,
+    token TEXT NOT NULL,
+    company TEXT,
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    PRIMARY KEY (source, token)
+);
+
+ALTER TABLE jobs ADD COLUMN department TEXT;
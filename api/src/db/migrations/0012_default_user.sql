@@ -0,0 +1,14 @@
+-- Migration 0012: the default user
+--
+-- Until there is authentication every profile, resume and application
+-- belongs to DEFAULT_USER_ID (see models/user.rs), and their user_id
+-- columns reference users(id), so that user has to exist.
+
+INSERT OR IGNORE INTO users (id, email, name, created_at, updated_at)
+VALUES (
+    '00000000-0000-0000-0000-000000000001',
+    'default@localhost',
+    'Default User',
+    strftime('%Y-%m-%dT%H:%M:%SZ', 'now'),
+    strftime('%Y-%m-%dT%H:%M:%SZ', 'now')
+);
@@ -0,0 +1,10 @@
+-- Migration 0013: record data migrations that run in Rust
+--
+-- Some fixes to the stored data need Rust, e.g. the fuzzy matching that links
+-- duplicate job postings. Database runs each once after the schema migrations
+-- and records it here by name only after it has succeeded.
+
+CREATE TABLE IF NOT EXISTS data_migrations (
+    name TEXT PRIMARY KEY,
+    applied_at TEXT NOT NULL
+);
@@ -0,0 +1,44 @@
+-- Migration 0014: find a job's full-text row without scanning the index
+--
+-- job_id is UNINDEXED in jobs_fts, so deleting a job's row by job_id read
+-- the whole index. jobs_fts_map keeps each job's jobs_fts rowid instead,
+-- and a job is only re-indexed when its searchable text has changed, so
+-- refetching unchanged postings leaves the index alone.
+
+CREATE TABLE IF NOT EXISTS jobs_fts_map (
+    job_id TEXT PRIMARY KEY,
+    fts_rowid INTEGER NOT NULL
+);
+
+INSERT OR REPLACE INTO jobs_fts_map (job_id, fts_rowid)
+SELECT job_id, rowid FROM jobs_fts;
+
+DROP TRIGGER IF EXISTS jobs_fts_insert;
+DROP TRIGGER IF EXISTS jobs_fts_delete;
+DROP TRIGGER IF EXISTS jobs_fts_update;
+
+CREATE TRIGGER jobs_fts_insert AFTER INSERT ON jobs BEGIN
+    INSERT INTO jobs_fts (job_id, title, company, description, requirements)
+    VALUES (new.id, new.title, new.company, new.description, new.requirements);
+    INSERT OR REPLACE INTO jobs_fts_map (job_id, fts_rowid)
+    VALUES (new.id, last_insert_rowid());
+END;
+
+CREATE TRIGGER jobs_fts_delete AFTER DELETE ON jobs BEGIN
+    DELETE FROM jobs_fts
+    WHERE rowid = (SELECT fts_rowid FROM jobs_fts_map WHERE job_id = old.id);
+    DELETE FROM jobs_fts_map WHERE job_id = old.id;
+END;
+
+CREATE TRIGGER jobs_fts_update
+AFTER UPDATE OF title, company, description, requirements ON jobs
+WHEN old.title IS NOT new.title
+    OR old.company IS NOT new.company
+    OR old.description IS NOT new.description
+    OR old.requirements IS NOT new.requirements
+BEGIN
+    UPDATE jobs_fts
+    SET title = new.title, company = new.company,
+        description = new.description, requirements = new.requirements
+    WHERE rowid = (SELECT fts_rowid FROM jobs_fts_map WHERE job_id = old.id);
+END;
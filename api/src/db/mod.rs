@@ -6,6 +6,15 @@
 #[cfg(not(target_arch = "wasm32"))]
 pub mod sqlite;
 
+#[cfg(not(target_arch = "wasm32"))]
+pub mod migrations;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub mod fts;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub mod dedup;
+
 #[cfg(not(target_arch = "wasm32"))]
 pub mod repository;
 
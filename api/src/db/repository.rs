@@ -6,6 +6,8 @@
 use crate::models::*;
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 /// Repository for user operations
@@ -24,6 +26,8 @@ pub trait ProfileRepository: Send + Sync {
     async fn create(&self, profile: &Profile) -> Result<Profile>;
     async fn get_by_id(&self, id: &Uuid) -> Result<Option<Profile>>;
     async fn get_by_user_id(&self, user_id: &Uuid) -> Result<Option<Profile>>;
+    /// The most recently updated profile, if any
+    async fn get_latest(&self) -> Result<Option<Profile>>;
     async fn update(&self, profile: &Profile) -> Result<Profile>;
     async fn delete(&self, id: &Uuid) -> Result<()>;
 }
@@ -53,35 +57,159 @@ pub trait EducationRepository: Send + Sync {
 pub trait SkillRepository: Send + Sync {
     async fn create(&self, skill: &Skill) -> Result<Skill>;
     async fn get_by_profile_id(&self, profile_id: &Uuid) -> Result<Vec<Skill>>;
+    async fn update(&self, skill: &Skill) -> Result<Skill>;
     async fn delete(&self, id: &Uuid) -> Result<()>;
     async fn delete_by_profile_id(&self, profile_id: &Uuid) -> Result<()>;
 }
 
+/// Repository for skills the user adds to the skill taxonomy
+#[async_trait]
+pub trait CustomSkillRepository: Send + Sync {
+    /// Add a skill, replacing any custom skill with the same name
+    async fn upsert(&self, skill: &SkillDefinition) -> Result<SkillDefinition>;
+    async fn get_all(&self) -> Result<Vec<SkillDefinition>>;
+    async fn delete(&self, name: &str) -> Result<()>;
+}
+
+/// Repository for project operations
+#[async_trait]
+pub trait ProjectRepository: Send + Sync {
+    async fn create(&self, project: &Project) -> Result<Project>;
+    async fn get_by_id(&self, id: &Uuid) -> Result<Option<Project>>;
+    async fn get_by_profile_id(&self, profile_id: &Uuid) -> Result<Vec<Project>>;
+    async fn update(&self, project: &Project) -> Result<Project>;
+    async fn delete(&self, id: &Uuid) -> Result<()>;
+}
+
 /// Repository for job operations
 #[async_trait]
 pub trait JobRepository: Send + Sync {
     async fn create(&self, job: &Job) -> Result<Job>;
     async fn get_by_id(&self, id: &Uuid) -> Result<Option<Job>>;
-    async fn search(&self, query: &JobSearchQuery) -> Result<Vec<Job>>;
-    async fn get_saved(&self) -> Result<Vec<Job>>;
+    async fn search(&self, query: &JobSearchQuery) -> Result<Vec<JobSearchResult>>;
+    async fn get_saved(&self) -> Result<Vec<JobListing>>;
     async fn save(&self, id: &Uuid) -> Result<()>;
     async fn unsave(&self, id: &Uuid) -> Result<()>;
     async fn update_match_score(&self, id: &Uuid, score: f64) -> Result<()>;
+    /// Store a fetched job, updating the posting with the same URL if there is
+    /// one; a new posting of an already stored job is linked to it as a duplicate
     async fn upsert_by_source(&self, job: &Job) -> Result<Job>;
 }
 
 /// Job search query parameters
-#[derive(Debug, Clone, Default)]
+///
+/// Every field is an independent filter; unset fields match all jobs.
+/// Queries can be built field by field or with the chained setters:
+///
+/// ```ignore
+/// let query = JobSearchQuery::new()
+///     .keywords("rust AND tokio")
+///     .remote_only(true)
+///     .sort(JobSortOrder::Newest)
+///     .limit(20);
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct JobSearchQuery {
     pub keywords: Option<String>,
     pub location: Option<String>,
+    /// Minimum salary; jobs without salary information are excluded when set
     pub min_salary: Option<u32>,
+    /// ISO currency code of the advertised salary (e.g. "USD")
+    pub salary_currency: Option<String>,
+    pub salary_period: Option<SalaryPeriod>,
     pub sources: Vec<JobSource>,
     pub remote_only: bool,
+    pub posted_since: Option<DateTime<Utc>>,
+    /// Only return jobs from these companies (case-insensitive)
+    pub include_companies: Vec<String>,
+    /// Never return jobs from these companies (case-insensitive)
+    pub exclude_companies: Vec<String>,
+    /// Also return postings that duplicate another job; by default each job
+    /// is returned once, with its other postings as `other_sources`, and
+    /// matches when any of its postings does
+    pub include_duplicates: bool,
+    pub sort: JobSortOrder,
     pub limit: Option<u32>,
     pub offset: Option<u32>,
 }
 
+impl JobSearchQuery {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn keywords(mut self, keywords: impl Into<String>) -> Self {
+        self.keywords = Some(keywords.into());
+        self
+    }
+
+    pub fn location(mut self, location: impl Into<String>) -> Self {
+        self.location = Some(location.into());
+        self
+    }
+
+    pub fn min_salary(mut self, min_salary: u32) -> Self {
+        self.min_salary = Some(min_salary);
+        self
+    }
+
+    pub fn salary_currency(mut self, currency: impl Into<String>) -> Self {
+        self.salary_currency = Some(currency.into());
+        self
+    }
+
+    pub fn salary_period(mut self, period: SalaryPeriod) -> Self {
+        self.salary_period = Some(period);
+        self
+    }
+
+    pub fn source(mut self, source: JobSource) -> Self {
+        self.sources.push(source);
+        self
+    }
+
+    pub fn remote_only(mut self, remote_only: bool) -> Self {
+        self.remote_only = remote_only;
+        self
+    }
+
+    pub fn posted_since(mut self, since: DateTime<Utc>) -> Self {
+        self.posted_since = Some(since);
+        self
+    }
+
+    pub fn include_company(mut self, company: impl Into<String>) -> Self {
+        self.include_companies.push(company.into());
+        self
+    }
+
+    pub fn exclude_company(mut self, company: impl Into<String>) -> Self {
+        self.exclude_companies.push(company.into());
+        self
+    }
+
+    pub fn include_duplicates(mut self, include_duplicates: bool) -> Self {
+        self.include_duplicates = include_duplicates;
+        self
+    }
+
+    pub fn sort(mut self, sort: JobSortOrder) -> Self {
+        self.sort = sort;
+        self
+    }
+
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn offset(mut self, offset: u32) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+}
+
 /// Repository for job application operations
 #[async_trait]
 pub trait ApplicationRepository: Send + Sync {
@@ -90,7 +218,10 @@ pub trait ApplicationRepository: Send + Sync {
     async fn get_by_user_id(&self, user_id: &Uuid) -> Result<Vec<JobApplication>>;
     async fn get_by_user_and_job(&self, user_id: &Uuid, job_id: &Uuid) -> Result<Option<JobApplication>>;
     async fn update(&self, application: &JobApplication) -> Result<JobApplication>;
+    /// Change the status and append it to the application's status history
     async fn update_status(&self, id: &Uuid, status: ApplicationStatus, notes: Option<String>) -> Result<JobApplication>;
+    /// Status changes for an application, oldest first
+    async fn get_status_history(&self, application_id: &Uuid) -> Result<Vec<ApplicationStatusChange>>;
     async fn delete(&self, id: &Uuid) -> Result<()>;
 }
 
@@ -103,3 +234,42 @@ pub trait ResumeRepository: Send + Sync {
     async fn update(&self, resume: &Resume) -> Result<Resume>;
     async fn delete(&self, id: &Uuid) -> Result<()>;
 }
+
+/// Repository for the history of scheduled job source fetches
+#[async_trait]
+pub trait FetchRunRepository: Send + Sync {
+    async fn create(&self, run: &FetchRun) -> Result<FetchRun>;
+    /// Record how a run ended
+    async fn finish(&self, run: &FetchRun) -> Result<FetchRun>;
+    /// Most recent runs of all sources, newest first
+    async fn get_recent(&self, limit: u32) -> Result<Vec<FetchRun>>;
+    async fn get_latest_by_source(&self, source: &str) -> Result<Option<FetchRun>>;
+}
+
+/// Repository for saved job searches and the jobs they have matched
+#[async_trait]
+pub trait SavedSearchRepository: Send + Sync {
+    async fn create(&self, search: &SavedSearch, query: &JobSearchQuery) -> Result<SavedSearch>;
+    /// A user's saved searches with their counts of new matches
+    async fn get_by_user_id(&self, user_id: &Uuid) -> Result<Vec<SavedSearch>>;
+    /// Every saved search together with its query
+    async fn get_all(&self) -> Result<Vec<(SavedSearch, JobSearchQuery)>>;
+    /// Record jobs the search matched, as new unless `seen`
+    ///
+    /// Jobs matched before are left as they are. Returns how many jobs
+    /// the search had not matched before.
+    async fn record_matches(&self, search_id: &Uuid, job_ids: &[Uuid], seen: bool) -> Result<u32>;
+    /// Matched jobs the user has not looked at yet, newest first
+    async fn get_new_matches(&self, search_id: &Uuid) -> Result<Vec<JobListing>>;
+    async fn mark_seen(&self, search_id: &Uuid) -> Result<()>;
+    async fn delete(&self, id: &Uuid) -> Result<()>;
+}
+
+/// Repository for the company job boards the user follows
+#[async_trait]
+pub trait CompanyBoardRepository: Send + Sync {
+    /// Add a board, replacing the company name of a board already followed
+    async fn upsert(&self, board: &CompanyBoard) -> Result<CompanyBoard>;
+    async fn get_all(&self) -> Result<Vec<CompanyBoard>>;
+    async fn delete(&self, source: &JobSource, token: &str) -> Result<()>;
+}
@@ -13,8 +13,8 @@ pub struct Database {
 impl Database {
     /// Create a new database connection
     /// 
-    /// If the database file doesn't exist, it will be created
-    /// and migrations will be run automatically.
+    /// If the database file doesn't exist, it will be created.
+    /// Pending migrations are applied automatically.
     pub async fn new(db_path: &str) -> Result<Self> {
         // Create parent directories if needed
         if let Some(parent) = Path::new(db_path).parent() {
@@ -34,11 +34,42 @@ impl Database {
         Ok(db)
     }
     
-    /// Run database migrations
-    async fn run_migrations(&self) -> Result<()> {
-        sqlx::query(include_str!("migrations.sql"))
-            .execute(&self.pool)
+    /// Create a private in-memory database
+    /// 
+    /// The schema is migrated as for an on-disk database; all data is lost
+    /// when the last clone is dropped. Mainly useful for tests.
+    pub async fn in_memory() -> Result<Self> {
+        // Each connection to `sqlite::memory:` is a separate database, so
+        // keep exactly one connection alive for the lifetime of the pool
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .idle_timeout(None)
+            .max_lifetime(None)
+            .connect("sqlite::memory:")
             .await?;
+        
+        let db = Self { pool };
+        db.run_migrations().await?;
+        
+        Ok(db)
+    }
+    
+    /// Apply any pending schema migrations, then any pending data migrations
+    async fn run_migrations(&self) -> Result<()> {
+        use super::migrations::{data_migration_applied, record_data_migration, LINK_JOB_DUPLICATES};
+        
+        super::migrations::run_migrations(&self.pool).await?;
+        
+        // Deduplication needs the fuzzy matching in Rust, so it can't be a SQL
+        // script. It is recorded only once it has succeeded, so that a failed
+        // run is retried on the next start.
+        if !data_migration_applied(&self.pool, LINK_JOB_DUPLICATES).await? {
+            let linked = super::sqlite_impl::SqliteJobRepository::new(self.clone())
+                .link_duplicates()
+                .await?;
+            tracing::info!("Linked {} duplicate job postings", linked);
+            record_data_migration(&self.pool, LINK_JOB_DUPLICATES).await?;
+        }
         Ok(())
     }
     
@@ -56,3 +87,32 @@ pub fn default_db_path() -> String {
     
     data_dir.join("data.db").to_string_lossy().to_string()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::migrations::{data_migration_applied, LINK_JOB_DUPLICATES};
+
+    #[tokio::test]
+    async fn test_failed_linking_is_retried() {
+        let db = Database::in_memory().await.unwrap();
+        assert!(data_migration_applied(db.pool(), LINK_JOB_DUPLICATES).await.unwrap());
+        
+        // As if the jobs had been stored before duplicates were linked, with
+        // one row that can't be read
+        sqlx::query("DELETE FROM data_migrations").execute(db.pool()).await.unwrap();
+        sqlx::query(
+            "INSERT INTO jobs (id, title, company, source, source_url) \
+             VALUES ('not-a-uuid', 'Rust Engineer', 'Acme', 'remotive', 'https://example.com/1')"
+        )
+        .execute(db.pool())
+        .await
+        .unwrap();
+        assert!(db.run_migrations().await.is_err());
+        assert!(!data_migration_applied(db.pool(), LINK_JOB_DUPLICATES).await.unwrap());
+        
+        sqlx::query("DELETE FROM jobs").execute(db.pool()).await.unwrap();
+        db.run_migrations().await.unwrap();
+        assert!(data_migration_applied(db.pool(), LINK_JOB_DUPLICATES).await.unwrap());
+    }
+}
@@ -1,13 +1,16 @@
 //! SQLite repository implementations
 
+use super::dedup;
+use super::fts;
 use super::repository::*;
 use super::Database;
 use crate::models::*;
 use anyhow::Result;
 use async_trait::async_trait;
-use sqlx::Row;
+use sqlx::{QueryBuilder, Row, Sqlite};
 use uuid::Uuid;
 use chrono::{DateTime, Utc};
+use std::collections::HashMap;
 
 /// SQLite implementation of ProfileRepository
 pub struct SqliteProfileRepository {
@@ -73,6 +76,17 @@ impl ProfileRepository for SqliteProfileRepository {
         }
     }
     
+    async fn get_latest(&self) -> Result<Option<Profile>> {
+        let row = sqlx::query("SELECT * FROM profiles ORDER BY updated_at DESC LIMIT 1")
+            .fetch_optional(self.db.pool())
+            .await?;
+        
+        match row {
+            Some(row) => Ok(Some(row_to_profile(&row)?)),
+            None => Ok(None),
+        }
+    }
+    
     async fn update(&self, profile: &Profile) -> Result<Profile> {
         sqlx::query(
             r#"
@@ -439,6 +453,25 @@ impl SkillRepository for SqliteSkillRepository {
         Ok(skills)
     }
     
+    async fn update(&self, skill: &Skill) -> Result<Skill> {
+        sqlx::query(
+            r#"
+            UPDATE skills SET 
+                name = ?, category = ?, level = ?, years_experience = ?
+            WHERE id = ?
+            "#
+        )
+        .bind(&skill.name)
+        .bind(format!("{:?}", skill.category))
+        .bind(format!("{:?}", skill.proficiency))
+        .bind(skill.years_experience.map(|y| y as i64))
+        .bind(skill.id.to_string())
+        .execute(self.db.pool())
+        .await?;
+        
+        Ok(skill.clone())
+    }
+    
     async fn delete(&self, id: &Uuid) -> Result<()> {
         sqlx::query("DELETE FROM skills WHERE id = ?")
             .bind(id.to_string())
@@ -465,15 +498,10 @@ fn row_to_skill(row: &sqlx::sqlite::SqliteRow) -> Result<Skill> {
     let level_str: Option<String> = row.get("level");
     let years_exp: Option<i64> = row.get("years_experience");
     
-    let category = match category_str.as_deref() {
-        Some("Programming") => SkillCategory::Programming,
-        Some("Framework") => SkillCategory::Framework,
-        Some("Database") => SkillCategory::Database,
-        Some("Tool") => SkillCategory::Tool,
-        Some("Language") => SkillCategory::Language,
-        Some("Soft") => SkillCategory::Soft,
-        _ => SkillCategory::Other,
-    };
+    let category = category_str
+        .as_deref()
+        .and_then(SkillCategory::from_name)
+        .unwrap_or(SkillCategory::Other);
     
     let proficiency = match level_str.as_deref() {
         Some("Beginner") => SkillLevel::Beginner,
@@ -495,6 +523,216 @@ fn row_to_skill(row: &sqlx::sqlite::SqliteRow) -> Result<Skill> {
     })
 }
 
+/// SQLite implementation of CustomSkillRepository
+pub struct SqliteCustomSkillRepository {
+    db: Database,
+}
+
+impl SqliteCustomSkillRepository {
+    pub fn new(db: Database) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl CustomSkillRepository for SqliteCustomSkillRepository {
+    async fn upsert(&self, skill: &SkillDefinition) -> Result<SkillDefinition> {
+        sqlx::query(
+            r#"
+            INSERT INTO custom_skills (name, category, aliases, case_sensitive, created_at)
+            VALUES (?, ?, ?, ?, ?)
+            ON CONFLICT(name) DO UPDATE SET
+                name = excluded.name,
+                category = excluded.category,
+                aliases = excluded.aliases,
+                case_sensitive = excluded.case_sensitive
+            "#
+        )
+        .bind(&skill.name)
+        .bind(format!("{:?}", skill.category))
+        .bind(serde_json::to_string(&skill.aliases)?)
+        .bind(skill.case_sensitive)
+        .bind(Utc::now().to_rfc3339())
+        .execute(self.db.pool())
+        .await?;
+        
+        Ok(skill.clone())
+    }
+    
+    async fn get_all(&self) -> Result<Vec<SkillDefinition>> {
+        let rows = sqlx::query("SELECT * FROM custom_skills ORDER BY created_at, name")
+            .fetch_all(self.db.pool())
+            .await?;
+        
+        let mut skills = Vec::new();
+        for row in rows {
+            skills.push(row_to_skill_definition(&row)?);
+        }
+        Ok(skills)
+    }
+    
+    async fn delete(&self, name: &str) -> Result<()> {
+        sqlx::query("DELETE FROM custom_skills WHERE name = ?")
+            .bind(name)
+            .execute(self.db.pool())
+            .await?;
+        Ok(())
+    }
+}
+
+/// Helper to convert a database row to a SkillDefinition struct
+fn row_to_skill_definition(row: &sqlx::sqlite::SqliteRow) -> Result<SkillDefinition> {
+    let category_str: String = row.get("category");
+    let aliases_str: String = row.get("aliases");
+    
+    Ok(SkillDefinition {
+        name: row.get("name"),
+        category: SkillCategory::from_name(&category_str).unwrap_or(SkillCategory::Other),
+        aliases: serde_json::from_str(&aliases_str).unwrap_or_default(),
+        case_sensitive: row.get("case_sensitive"),
+    })
+}
+
+/// SQLite implementation of ProjectRepository
+pub struct SqliteProjectRepository {
+    db: Database,
+}
+
+impl SqliteProjectRepository {
+    pub fn new(db: Database) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl ProjectRepository for SqliteProjectRepository {
+    async fn create(&self, project: &Project) -> Result<Project> {
+        let technologies_json = serde_json::to_string(&project.technologies)?;
+        let highlights_json = serde_json::to_string(&project.highlights)?;
+        
+        sqlx::query(
+            r#"
+            INSERT INTO projects (id, profile_id, name, description, url, github_url,
+                technologies, start_date, end_date, highlights, created_at, updated_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            "#
+        )
+        .bind(project.id.to_string())
+        .bind(project.profile_id.to_string())
+        .bind(&project.name)
+        .bind(&project.description)
+        .bind(&project.url)
+        .bind(&project.github_url)
+        .bind(&technologies_json)
+        .bind(project.start_date.to_rfc3339())
+        .bind(project.end_date.map(|d| d.to_rfc3339()))
+        .bind(&highlights_json)
+        .bind(project.created_at.to_rfc3339())
+        .bind(project.updated_at.to_rfc3339())
+        .execute(self.db.pool())
+        .await?;
+        
+        Ok(project.clone())
+    }
+    
+    async fn get_by_id(&self, id: &Uuid) -> Result<Option<Project>> {
+        let row = sqlx::query("SELECT * FROM projects WHERE id = ?")
+            .bind(id.to_string())
+            .fetch_optional(self.db.pool())
+            .await?;
+        
+        match row {
+            Some(row) => Ok(Some(row_to_project(&row)?)),
+            None => Ok(None),
+        }
+    }
+    
+    async fn get_by_profile_id(&self, profile_id: &Uuid) -> Result<Vec<Project>> {
+        let rows = sqlx::query("SELECT * FROM projects WHERE profile_id = ? ORDER BY start_date DESC")
+            .bind(profile_id.to_string())
+            .fetch_all(self.db.pool())
+            .await?;
+        
+        let mut projects = Vec::new();
+        for row in rows {
+            projects.push(row_to_project(&row)?);
+        }
+        Ok(projects)
+    }
+    
+    async fn update(&self, project: &Project) -> Result<Project> {
+        let technologies_json = serde_json::to_string(&project.technologies)?;
+        let highlights_json = serde_json::to_string(&project.highlights)?;
+        
+        sqlx::query(
+            r#"
+            UPDATE projects SET 
+                name = ?, description = ?, url = ?, github_url = ?, technologies = ?,
+                start_date = ?, end_date = ?, highlights = ?, updated_at = ?
+            WHERE id = ?
+            "#
+        )
+        .bind(&project.name)
+        .bind(&project.description)
+        .bind(&project.url)
+        .bind(&project.github_url)
+        .bind(&technologies_json)
+        .bind(project.start_date.to_rfc3339())
+        .bind(project.end_date.map(|d| d.to_rfc3339()))
+        .bind(&highlights_json)
+        .bind(Utc::now().to_rfc3339())
+        .bind(project.id.to_string())
+        .execute(self.db.pool())
+        .await?;
+        
+        Ok(project.clone())
+    }
+    
+    async fn delete(&self, id: &Uuid) -> Result<()> {
+        sqlx::query("DELETE FROM projects WHERE id = ?")
+            .bind(id.to_string())
+            .execute(self.db.pool())
+            .await?;
+        Ok(())
+    }
+}
+
+/// Helper to convert a database row to a Project struct
+fn row_to_project(row: &sqlx::sqlite::SqliteRow) -> Result<Project> {
+    let id_str: String = row.get("id");
+    let profile_id_str: String = row.get("profile_id");
+    let start_date_str: Option<String> = row.get("start_date");
+    let end_date_str: Option<String> = row.get("end_date");
+    let created_at_str: String = row.get("created_at");
+    let updated_at_str: String = row.get("updated_at");
+    let technologies_json: Option<String> = row.get("technologies");
+    let highlights_json: Option<String> = row.get("highlights");
+    
+    let technologies: Vec<String> = technologies_json
+        .and_then(|s| serde_json::from_str(&s).ok())
+        .unwrap_or_default();
+    let highlights: Vec<String> = highlights_json
+        .and_then(|s| serde_json::from_str(&s).ok())
+        .unwrap_or_default();
+    
+    Ok(Project {
+        id: Uuid::parse_str(&id_str)?,
+        profile_id: Uuid::parse_str(&profile_id_str)?,
+        name: row.get("name"),
+        description: row.get::<Option<String>, _>("description").unwrap_or_default(),
+        technologies,
+        url: row.get("url"),
+        github_url: row.get("github_url"),
+        start_date: start_date_str
+            .and_then(|s| DateTime::parse_from_rfc3339(&s).ok().map(|d| d.with_timezone(&Utc)))
+            .unwrap_or_else(Utc::now),
+        end_date: end_date_str.and_then(|s| DateTime::parse_from_rfc3339(&s).ok().map(|d| d.with_timezone(&Utc))),
+        highlights,
+        created_at: DateTime::parse_from_rfc3339(&created_at_str)?.with_timezone(&Utc),
+        updated_at: DateTime::parse_from_rfc3339(&updated_at_str)?.with_timezone(&Utc),
+    })
+}
+
 /// SQLite implementation of JobRepository
 pub struct SqliteJobRepository {
     db: Database,
@@ -504,6 +742,89 @@ impl SqliteJobRepository {
     pub fn new(db: Database) -> Self {
         Self { db }
     }
+    
+    /// Find the stored job that `job` is another posting of
+    ///
+    /// Candidates are narrowed down in SQL by company name and then compared
+    /// with [`dedup::is_duplicate`]; the oldest match wins.
+    async fn find_canonical(&self, job: &Job) -> Result<Option<Uuid>> {
+        let company = crate::services::organisation::normalize_organisation(&job.company);
+        let Some(word) = company.split(' ').find(|word| !word.is_empty()) else {
+            return Ok(None);
+        };
+        
+        let rows = sqlx::query(
+            "SELECT * FROM jobs WHERE duplicate_of IS NULL AND id != ? AND LOWER(company) LIKE ?
+             ORDER BY created_at, rowid"
+        )
+        .bind(job.id.to_string())
+        .bind(format!("%{}%", word))
+        .fetch_all(self.db.pool())
+        .await?;
+        
+        for row in rows {
+            let candidate = row_to_job(&row)?;
+            if dedup::is_duplicate(job, &candidate) {
+                return Ok(Some(candidate.id));
+            }
+        }
+        Ok(None)
+    }
+    
+    /// Link the stored postings that duplicate an older posting
+    ///
+    /// For jobs stored before duplicates were tracked. A posting that turns
+    /// out to duplicate an older one takes its own duplicates along. Returns
+    /// the number of postings linked.
+    pub async fn link_duplicates(&self) -> Result<usize> {
+        let rows = sqlx::query("SELECT * FROM jobs WHERE duplicate_of IS NULL ORDER BY created_at, rowid")
+            .fetch_all(self.db.pool())
+            .await?;
+        let jobs = rows.iter().map(row_to_job).collect::<Result<Vec<_>>>()?;
+        let duplicates = dedup::find_duplicates(&jobs);
+        
+        let mut tx = self.db.pool().begin().await?;
+        for (duplicate, canonical) in &duplicates {
+            sqlx::query("UPDATE jobs SET duplicate_of = ? WHERE id = ? OR duplicate_of = ?")
+                .bind(canonical.to_string())
+                .bind(duplicate.to_string())
+                .bind(duplicate.to_string())
+                .execute(&mut *tx)
+                .await?;
+        }
+        tx.commit().await?;
+        
+        Ok(duplicates.len())
+    }
+    
+    /// Fill in `other_sources` with the duplicates of each listing
+    async fn attach_other_sources(&self, listings: &mut [&mut JobListing]) -> Result<()> {
+        if listings.is_empty() {
+            return Ok(());
+        }
+        
+        let mut builder = QueryBuilder::<Sqlite>::new("SELECT * FROM jobs WHERE duplicate_of IN (");
+        let mut separated = builder.separated(", ");
+        for listing in listings.iter() {
+            separated.push_bind(listing.job.id.to_string());
+        }
+        builder.push(") ORDER BY created_at, rowid");
+        let rows = builder.build().fetch_all(self.db.pool()).await?;
+        
+        let mut links: HashMap<String, Vec<JobSourceLink>> = HashMap::new();
+        for row in rows {
+            let duplicate = row_to_job(&row)?;
+            links.entry(row.get("duplicate_of")).or_default().push(JobSourceLink {
+                job_id: duplicate.id,
+                source: duplicate.source,
+                url: duplicate.source_url,
+            });
+        }
+        for listing in listings.iter_mut() {
+            listing.other_sources = links.remove(&listing.job.id.to_string()).unwrap_or_default();
+        }
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -521,39 +842,31 @@ impl JobRepository for SqliteJobRepository {
             None => (None, None, None, None),
         };
         
-        let source_str = match &job.source {
-            JobSource::LinkedIn => "LinkedIn",
-            JobSource::Indeed => "Indeed",
-            JobSource::Glassdoor => "Glassdoor",
-            JobSource::AngelList => "AngelList",
-            JobSource::Remotive => "Remotive",
-            JobSource::HNWhoIsHiring => "HN Who's Hiring",
-            JobSource::Arbeitnow => "Arbeitnow",
-            JobSource::Other(s) => s,
-        };
-        
         sqlx::query(
             r#"
-            INSERT INTO jobs (id, title, company, location, description, requirements, 
+            INSERT INTO jobs (id, title, company, location, department, description, requirements, 
                 salary_min, salary_max, salary_currency, salary_period, source, source_url, 
-                posted_date, scraped_at)
-            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                source_job_id, posted_date, scraped_at, is_remote)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
             "#
         )
         .bind(job.id.to_string())
         .bind(&job.title)
         .bind(&job.company)
         .bind(&job.location)
+        .bind(&job.department)
         .bind(&job.description)
         .bind(&requirements_json)
         .bind(salary_min)
         .bind(salary_max)
         .bind(salary_currency)
         .bind(salary_period)
-        .bind(source_str)
+        .bind(job.source.as_str())
         .bind(&job.source_url)
+        .bind(&job.source_job_id)
         .bind(job.posted_date.to_rfc3339())
         .bind(job.scraped_at.to_rfc3339())
+        .bind(job.is_remote())
         .execute(self.db.pool())
         .await?;
         
@@ -574,80 +887,48 @@ impl JobRepository for SqliteJobRepository {
         }
     }
     
-    async fn search(&self, query: &JobSearchQuery) -> Result<Vec<Job>> {
-        // Build the query with all parameters owned
-        let keywords_pattern = query.keywords.as_ref().map(|kw| format!("%{}%", kw));
-        let location_pattern = query.location.as_ref().map(|loc| format!("%{}%", loc));
-        
-        let source_strings: Vec<String> = query.sources.iter().map(|s| {
-            match s {
-                JobSource::LinkedIn => "LinkedIn".to_string(),
-                JobSource::Indeed => "Indeed".to_string(),
-                JobSource::Glassdoor => "Glassdoor".to_string(),
-                JobSource::AngelList => "AngelList".to_string(),
-                JobSource::Remotive => "Remotive".to_string(),
-                JobSource::HNWhoIsHiring => "HN Who's Hiring".to_string(),
-                JobSource::Arbeitnow => "Arbeitnow".to_string(),
-                JobSource::Other(name) => name.clone(),
-            }
-        }).collect();
-        
-        let mut sql = String::from("SELECT * FROM jobs WHERE 1=1");
-        
-        if keywords_pattern.is_some() {
-            sql.push_str(" AND (title LIKE ?1 OR description LIKE ?1 OR company LIKE ?1)");
-        }
-        if location_pattern.is_some() {
-            sql.push_str(" AND location LIKE ?2");
-        }
-        if query.min_salary.is_some() {
-            sql.push_str(" AND salary_min >= ?3");
-        }
-        if query.remote_only {
-            sql.push_str(" AND is_remote = 1");
-        }
-        if !source_strings.is_empty() {
-            let placeholders: Vec<String> = (0..source_strings.len())
-                .map(|i| format!("?{}", i + 4))
-                .collect();
-            sql.push_str(&format!(" AND source IN ({})", placeholders.join(",")));
-        }
-        
-        sql.push_str(" ORDER BY posted_date DESC");
-        
-        if let Some(limit) = query.limit {
-            sql.push_str(&format!(" LIMIT {}", limit));
-        }
-        if let Some(offset) = query.offset {
-            sql.push_str(&format!(" OFFSET {}", offset));
-        }
+    async fn search(&self, query: &JobSearchQuery) -> Result<Vec<JobSearchResult>> {
+        let fts_query = match query.keywords.as_deref() {
+            Some(kw) => fts::to_fts5_query(kw)?,
+            None => None,
+        };
         
-        // For SQLite, we need to use a simpler approach with raw queries
-        // due to the dynamic nature of the query
-        let rows = sqlx::query(&sql)
-            .bind(keywords_pattern.as_deref().unwrap_or(""))
-            .bind(location_pattern.as_deref().unwrap_or(""))
-            .bind(query.min_salary.unwrap_or(0) as i64)
+        let rows = build_search_query(query, fts_query.as_deref())
+            .build()
             .fetch_all(self.db.pool())
             .await?;
         
-        let mut jobs = Vec::new();
+        let mut results = Vec::new();
         for row in rows {
-            jobs.push(row_to_job(&row)?);
+            let listing = row_to_job_listing(&row)?;
+            if fts_query.is_some() {
+                let snippet: Option<String> = row.get("snippet");
+                results.push(JobSearchResult {
+                    listing,
+                    relevance: row.get("relevance"),
+                    snippet: snippet.as_deref().map(fts::parse_snippet).unwrap_or_default(),
+                });
+            } else {
+                results.push(JobSearchResult::from(listing));
+            }
         }
         
-        Ok(jobs)
+        let mut listings: Vec<_> = results.iter_mut().map(|r| &mut r.listing).collect();
+        self.attach_other_sources(&mut listings).await?;
+        
+        Ok(results)
     }
     
-    async fn get_saved(&self) -> Result<Vec<Job>> {
+    async fn get_saved(&self) -> Result<Vec<JobListing>> {
         let rows = sqlx::query("SELECT * FROM jobs WHERE is_saved = 1 ORDER BY created_at DESC")
             .fetch_all(self.db.pool())
             .await?;
         
         let mut jobs = Vec::new();
         for row in rows {
-            jobs.push(row_to_job(&row)?);
+            jobs.push(row_to_job_listing(&row)?);
         }
+        self.attach_other_sources(&mut jobs.iter_mut().collect::<Vec<_>>()).await?;
         Ok(jobs)
     }
     
@@ -677,33 +958,185 @@ impl JobRepository for SqliteJobRepository {
     }
     
     async fn upsert_by_source(&self, job: &Job) -> Result<Job> {
-        // Check if job already exists by source URL
-        let existing = sqlx::query("SELECT id FROM jobs WHERE source_url = ?")
-            .bind(&job.source_url)
-            .fetch_optional(self.db.pool())
-            .await?;
+        // Check if job already exists by source URL, or by its id at the
+        // source since some sources change a posting's URL
+        let existing = sqlx::query(
+            "SELECT id FROM jobs WHERE source_url = ?
+             OR (source = ? AND source_job_id IS NOT NULL AND source_job_id = ?)"
+        )
+        .bind(&job.source_url)
+        .bind(job.source.as_str())
+        .bind(&job.source_job_id)
+        .fetch_optional(self.db.pool())
+        .await?;
         
-        if existing.is_some() {
-            // Update existing
+        if let Some(existing) = existing {
+            // Update existing, keeping its id so callers can refer to the stored row
+            let id: String = existing.get("id");
             let requirements_json = serde_json::to_string(&job.requirements)?;
+            let (salary_min, salary_max, salary_currency, salary_period) = match &job.salary_range {
+                Some(range) => (
+                    Some(range.min as i64),
+                    Some(range.max as i64),
+                    Some(range.currency.clone()),
+                    Some(format!("{:?}", range.period)),
+                ),
+                None => (None, None, None, None),
+            };
             sqlx::query(
-                "UPDATE jobs SET title = ?, company = ?, location = ?, description = ?, 
-                 requirements = ?, updated_at = datetime('now') WHERE source_url = ?"
+                "UPDATE jobs SET title = ?, company = ?, location = ?, department = ?, description = ?, 
+                 requirements = ?, salary_min = ?, salary_max = ?, salary_currency = ?, 
+                 salary_period = ?, is_remote = ?, source_url = ?, source_job_id = ?,
+                 updated_at = datetime('now') WHERE id = ?"
             )
             .bind(&job.title)
             .bind(&job.company)
             .bind(&job.location)
+            .bind(&job.department)
             .bind(&job.description)
             .bind(&requirements_json)
+            .bind(salary_min)
+            .bind(salary_max)
+            .bind(salary_currency)
+            .bind(salary_period)
+            .bind(job.is_remote())
             .bind(&job.source_url)
+            .bind(&job.source_job_id)
+            .bind(&id)
             .execute(self.db.pool())
             .await?;
             
-            Ok(job.clone())
+            Ok(Job {
+                id: Uuid::parse_str(&id)?,
+                ..job.clone()
+            })
         } else {
-            self.create(job).await
+            let job = self.create(job).await?;
+            if let Some(canonical) = self.find_canonical(&job).await? {
+                sqlx::query("UPDATE jobs SET duplicate_of = ? WHERE id = ?")
+                    .bind(canonical.to_string())
+                    .bind(job.id.to_string())
+                    .execute(self.db.pool())
+                    .await?;
+            }
+            Ok(job)
+        }
+    }
+}
+
+/// Build the SQL for a job search, binding every filter in `query`
+///
+/// `fts_query` is the already translated FTS5 expression for the keywords;
+/// when present, results are joined against the full-text index and carry
+/// `relevance` and `snippet` columns.
+fn build_search_query<'a>(
+    query: &'a JobSearchQuery,
+    fts_query: Option<&'a str>,
+) -> QueryBuilder<'a, Sqlite> {
+    let mut builder = QueryBuilder::new("");
+    
+    if let Some(fts_query) = fts_query {
+        // Materialized, as the FTS functions can't run inside the grouping below.
+        // Column weights follow jobs_fts: job_id, title, company, description, requirements
+        builder.push(format!(
+            r#"WITH f AS MATERIALIZED (
+                SELECT job_id,
+                    -bm25(jobs_fts, 0.0, 10.0, 5.0, 1.0, 2.0) AS relevance,
+                    snippet(jobs_fts, 3, char({}), char({}), '…', 24) AS snippet
+                FROM jobs_fts WHERE jobs_fts MATCH "#,
+            fts::HIGHLIGHT_START as u32,
+            fts::HIGHLIGHT_END as u32,
+        ));
+        builder.push_bind(fts_query);
+        builder.push(") ");
+    }
+    
+    // The filters run over every posting. Unless duplicates are wanted, each
+    // matching posting then stands for the canonical listing of its group,
+    // so a job is found through any of its postings.
+    builder.push(match fts_query {
+        Some(_) => "SELECT jobs.*, m.relevance, m.snippet FROM (SELECT ",
+        None => "SELECT jobs.* FROM (SELECT ",
+    });
+    builder.push(match query.include_duplicates {
+        true => "jobs.id AS job_id",
+        false => "COALESCE(jobs.duplicate_of, jobs.id) AS job_id",
+    });
+    builder.push(match (fts_query.is_some(), query.include_duplicates) {
+        (true, true) => ", f.relevance, f.snippet FROM f JOIN jobs ON jobs.id = f.job_id WHERE 1=1",
+        // The best matching posting of a group gives its relevance and snippet
+        (true, false) => ", MAX(f.relevance) AS relevance, f.snippet FROM f JOIN jobs ON jobs.id = f.job_id WHERE 1=1",
+        (false, _) => " FROM jobs WHERE 1=1",
+    });
+    
+    if let Some(location) = &query.location {
+        builder.push(" AND jobs.location LIKE ").push_bind(format!("%{}%", location));
+    }
+    if let Some(min_salary) = query.min_salary {
+        builder.push(" AND jobs.salary_min >= ").push_bind(min_salary as i64);
+    }
+    if let Some(currency) = &query.salary_currency {
+        builder.push(" AND UPPER(jobs.salary_currency) = UPPER(").push_bind(currency.as_str()).push(")");
+    }
+    if let Some(period) = &query.salary_period {
+        builder.push(" AND jobs.salary_period = ").push_bind(format!("{:?}", period));
+    }
+    if query.remote_only {
+        builder.push(" AND jobs.is_remote = 1");
+    }
+    if let Some(since) = query.posted_since {
+        builder.push(" AND jobs.posted_date >= ").push_bind(since.to_rfc3339());
+    }
+    if !query.sources.is_empty() {
+        builder.push(" AND jobs.source IN (");
+        let mut separated = builder.separated(", ");
+        for source in &query.sources {
+            separated.push_bind(source.as_str());
+        }
+        builder.push(")");
+    }
+    if !query.include_companies.is_empty() {
+        builder.push(" AND LOWER(jobs.company) IN (");
+        let mut separated = builder.separated(", ");
+        for company in &query.include_companies {
+            separated.push_bind(company.trim().to_lowercase());
         }
+        builder.push(")");
     }
+    if !query.exclude_companies.is_empty() {
+        builder.push(" AND LOWER(jobs.company) NOT IN (");
+        let mut separated = builder.separated(", ");
+        for company in &query.exclude_companies {
+            separated.push_bind(company.trim().to_lowercase());
+        }
+        builder.push(")");
+    }
+    if !query.include_duplicates {
+        builder.push(" GROUP BY 1");
+    }
+    builder.push(") AS m JOIN jobs ON jobs.id = m.job_id");
+    
+    builder.push(match (query.sort, fts_query.is_some()) {
+        (JobSortOrder::Relevance, true) => " ORDER BY m.relevance DESC, jobs.posted_date DESC",
+        (JobSortOrder::Relevance, false) | (JobSortOrder::Newest, _) => " ORDER BY jobs.posted_date DESC",
+        (JobSortOrder::Oldest, _) => " ORDER BY jobs.posted_date ASC",
+        (JobSortOrder::HighestSalary, _) => {
+            " ORDER BY jobs.salary_max IS NULL, jobs.salary_max DESC, jobs.posted_date DESC"
+        }
+        (JobSortOrder::BestMatch, _) => {
+            " ORDER BY jobs.match_score IS NULL, jobs.match_score DESC, jobs.posted_date DESC"
+        }
+    });
+    
+    // SQLite only accepts OFFSET after a LIMIT; -1 means no limit
+    if query.limit.is_some() || query.offset.is_some() {
+        builder.push(" LIMIT ").push_bind(query.limit.map(|l| l as i64).unwrap_or(-1));
+    }
+    if let Some(offset) = query.offset {
+        builder.push(" OFFSET ").push_bind(offset as i64);
+    }
+    
+    builder
 }
 
 /// Helper to convert a database row to a Job struct
@@ -732,28 +1165,1484 @@ fn row_to_job(row: &sqlx::sqlite::SqliteRow) -> Result<Job> {
         _ => None,
     };
     
-    let source = match source_str.as_str() {
-        "LinkedIn" => JobSource::LinkedIn,
-        "Indeed" => JobSource::Indeed,
-        "Glassdoor" => JobSource::Glassdoor,
-        "AngelList" => JobSource::AngelList,
-        "Remotive" => JobSource::Remotive,
-        "HN Who's Hiring" => JobSource::HNWhoIsHiring,
-        "Arbeitnow" => JobSource::Arbeitnow,
-        other => JobSource::Other(other.to_string()),
-    };
-    
     Ok(Job {
         id: Uuid::parse_str(&id_str)?,
         title: row.get("title"),
         company: row.get("company"),
         location: row.get("location"),
+        department: row.get("department"),
         description: row.get("description"),
         requirements: serde_json::from_str(&requirements_json)?,
         salary_range,
-        source,
+        source: JobSource::from(source_str.as_str()),
         source_url: row.get("source_url"),
+        source_job_id: row.get("source_job_id"),
         posted_date: DateTime::parse_from_rfc3339(&posted_date_str)?.with_timezone(&Utc),
         scraped_at: DateTime::parse_from_rfc3339(&scraped_at_str)?.with_timezone(&Utc),
     })
 }
+
+/// Helper to convert a database row to a JobListing, keeping the per-job state
+fn row_to_job_listing(row: &sqlx::sqlite::SqliteRow) -> Result<JobListing> {
+    Ok(JobListing {
+        job: row_to_job(row)?,
+        match_score: row.get("match_score"),
+        is_saved: row.get("is_saved"),
+        is_remote: row.get("is_remote"),
+        other_sources: Vec::new(),
+    })
+}
+
+/// SQLite implementation of ApplicationRepository
+pub struct SqliteApplicationRepository {
+    db: Database,
+}
+
+impl SqliteApplicationRepository {
+    pub fn new(db: Database) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl ApplicationRepository for SqliteApplicationRepository {
+    async fn create(&self, application: &JobApplication) -> Result<JobApplication> {
+        let mut tx = self.db.pool().begin().await?;
+        
+        sqlx::query(
+            r#"
+            INSERT INTO job_applications (id, user_id, job_id, resume_id, status, applied_date,
+                cover_letter, notes, follow_up_date, created_at, updated_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            "#
+        )
+        .bind(application.id.to_string())
+        .bind(application.user_id.to_string())
+        .bind(application.job_id.to_string())
+        .bind(application.custom_resume_id.map(|id| id.to_string()))
+        .bind(application_status_str(&application.status))
+        .bind(application.applied_date.to_rfc3339())
+        .bind(&application.cover_letter)
+        .bind(&application.notes)
+        .bind(application.follow_up_date.map(|d| d.to_rfc3339()))
+        .bind(application.created_at.to_rfc3339())
+        .bind(application.updated_at.to_rfc3339())
+        .execute(&mut *tx)
+        .await?;
+        
+        // The initial status starts the application's timeline
+        record_status_change(&mut tx, &application.id, &application.status, None, &application.created_at.to_rfc3339()).await?;
+        
+        tx.commit().await?;
+        
+        Ok(application.clone())
+    }
+    
+    async fn get_by_id(&self, id: &Uuid) -> Result<Option<JobApplication>> {
+        let row = sqlx::query("SELECT * FROM job_applications WHERE id = ?")
+            .bind(id.to_string())
+            .fetch_optional(self.db.pool())
+            .await?;
+        
+        match row {
+            Some(row) => Ok(Some(row_to_application(&row)?)),
+            None => Ok(None),
+        }
+    }
+    
+    async fn get_by_user_id(&self, user_id: &Uuid) -> Result<Vec<JobApplication>> {
+        let rows = sqlx::query("SELECT * FROM job_applications WHERE user_id = ? ORDER BY updated_at DESC")
+            .bind(user_id.to_string())
+            .fetch_all(self.db.pool())
+            .await?;
+        
+        let mut applications = Vec::new();
+        for row in rows {
+            applications.push(row_to_application(&row)?);
+        }
+        Ok(applications)
+    }
+    
+    async fn get_by_user_and_job(&self, user_id: &Uuid, job_id: &Uuid) -> Result<Option<JobApplication>> {
+        let row = sqlx::query("SELECT * FROM job_applications WHERE user_id = ? AND job_id = ?")
+            .bind(user_id.to_string())
+            .bind(job_id.to_string())
+            .fetch_optional(self.db.pool())
+            .await?;
+        
+        match row {
+            Some(row) => Ok(Some(row_to_application(&row)?)),
+            None => Ok(None),
+        }
+    }
+    
+    async fn update(&self, application: &JobApplication) -> Result<JobApplication> {
+        let now = Utc::now().to_rfc3339();
+        let mut tx = self.db.pool().begin().await?;
+        
+        let previous_status: Option<String> = sqlx::query_scalar("SELECT status FROM job_applications WHERE id = ?")
+            .bind(application.id.to_string())
+            .fetch_optional(&mut *tx)
+            .await?;
+        
+        sqlx::query(
+            r#"
+            UPDATE job_applications SET 
+                resume_id = ?, status = ?, applied_date = ?, cover_letter = ?, notes = ?,
+                follow_up_date = ?, updated_at = ?
+            WHERE id = ?
+            "#
+        )
+        .bind(application.custom_resume_id.map(|id| id.to_string()))
+        .bind(application_status_str(&application.status))
+        .bind(application.applied_date.to_rfc3339())
+        .bind(&application.cover_letter)
+        .bind(&application.notes)
+        .bind(application.follow_up_date.map(|d| d.to_rfc3339()))
+        .bind(&now)
+        .bind(application.id.to_string())
+        .execute(&mut *tx)
+        .await?;
+        
+        if let Some(previous) = previous_status {
+            if parse_application_status(&previous) != application.status {
+                record_status_change(&mut tx, &application.id, &application.status, None, &now).await?;
+            }
+        }
+        
+        tx.commit().await?;
+        
+        Ok(application.clone())
+    }
+    
+    async fn update_status(&self, id: &Uuid, status: ApplicationStatus, notes: Option<String>) -> Result<JobApplication> {
+        let now = Utc::now().to_rfc3339();
+        
+        // Interviews, offers and rejections are responses from the employer
+        let response_date = match status {
+            ApplicationStatus::Interviewing | ApplicationStatus::Offered | ApplicationStatus::Rejected => Some(now.clone()),
+            _ => None,
+        };
+        
+        let mut tx = self.db.pool().begin().await?;
+        
+        let result = sqlx::query(
+            r#"
+            UPDATE job_applications SET 
+                status = ?, notes = COALESCE(?, notes),
+                response_date = COALESCE(response_date, ?), updated_at = ?
+            WHERE id = ?
+            "#
+        )
+        .bind(application_status_str(&status))
+        .bind(&notes)
+        .bind(response_date)
+        .bind(&now)
+        .bind(id.to_string())
+        .execute(&mut *tx)
+        .await?;
+        
+        if result.rows_affected() == 0 {
+            return Err(anyhow::anyhow!("Application {} not found", id));
+        }
+        
+        record_status_change(&mut tx, id, &status, notes.as_deref(), &now).await?;
+        
+        tx.commit().await?;
+        
+        self.get_by_id(id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Application {} not found", id))
+    }
+    
+    async fn get_status_history(&self, application_id: &Uuid) -> Result<Vec<ApplicationStatusChange>> {
+        // rowid breaks ties between changes recorded within the same instant
+        let rows = sqlx::query(
+            "SELECT * FROM application_status_history WHERE application_id = ? ORDER BY created_at, rowid"
+        )
+        .bind(application_id.to_string())
+        .fetch_all(self.db.pool())
+        .await?;
+        
+        let mut history = Vec::new();
+        for row in rows {
+            history.push(row_to_status_change(&row)?);
+        }
+        Ok(history)
+    }
+    
+    async fn delete(&self, id: &Uuid) -> Result<()> {
+        sqlx::query("DELETE FROM job_applications WHERE id = ?")
+            .bind(id.to_string())
+            .execute(self.db.pool())
+            .await?;
+        Ok(())
+    }
+}
+
+/// Convert an ApplicationStatus to its stored (lowercase) form
+fn application_status_str(status: &ApplicationStatus) -> &'static str {
+    match status {
+        ApplicationStatus::Draft => "draft",
+        ApplicationStatus::Applied => "applied",
+        ApplicationStatus::Interviewing => "interviewing",
+        ApplicationStatus::Offered => "offered",
+        ApplicationStatus::Rejected => "rejected",
+        ApplicationStatus::Withdrawn => "withdrawn",
+    }
+}
+
+/// Parse an ApplicationStatus from its stored form
+fn parse_application_status(status: &str) -> ApplicationStatus {
+    match status {
+        "applied" => ApplicationStatus::Applied,
+        "interviewing" => ApplicationStatus::Interviewing,
+        "offered" => ApplicationStatus::Offered,
+        "rejected" => ApplicationStatus::Rejected,
+        "withdrawn" => ApplicationStatus::Withdrawn,
+        _ => ApplicationStatus::Draft,
+    }
+}
+
+/// Append an entry to an application's status history
+async fn record_status_change(
+    tx: &mut sqlx::Transaction<'_, Sqlite>,
+    application_id: &Uuid,
+    status: &ApplicationStatus,
+    notes: Option<&str>,
+    at: &str,
+) -> Result<()> {
+    sqlx::query(
+        "INSERT INTO application_status_history (id, application_id, status, notes, created_at) VALUES (?, ?, ?, ?, ?)"
+    )
+    .bind(Uuid::new_v4().to_string())
+    .bind(application_id.to_string())
+    .bind(application_status_str(status))
+    .bind(notes)
+    .bind(at)
+    .execute(&mut **tx)
+    .await?;
+    Ok(())
+}
+
+/// Helper to convert a database row to an ApplicationStatusChange struct
+fn row_to_status_change(row: &sqlx::sqlite::SqliteRow) -> Result<ApplicationStatusChange> {
+    let id_str: String = row.get("id");
+    let application_id_str: String = row.get("application_id");
+    let status_str: String = row.get("status");
+    let created_at_str: String = row.get("created_at");
+    
+    Ok(ApplicationStatusChange {
+        id: Uuid::parse_str(&id_str)?,
+        application_id: Uuid::parse_str(&application_id_str)?,
+        status: parse_application_status(&status_str),
+        notes: row.get("notes"),
+        created_at: DateTime::parse_from_rfc3339(&created_at_str)?.with_timezone(&Utc),
+    })
+}
+
+/// Helper to convert a database row to a JobApplication struct
+fn row_to_application(row: &sqlx::sqlite::SqliteRow) -> Result<JobApplication> {
+    let id_str: String = row.get("id");
+    let user_id_str: String = row.get("user_id");
+    let job_id_str: String = row.get("job_id");
+    let resume_id_str: Option<String> = row.get("resume_id");
+    let status_str: String = row.get("status");
+    let applied_date_str: Option<String> = row.get("applied_date");
+    let follow_up_date_str: Option<String> = row.get("follow_up_date");
+    let created_at_str: String = row.get("created_at");
+    let updated_at_str: String = row.get("updated_at");
+    
+    let status = parse_application_status(&status_str);
+    
+    let created_at = DateTime::parse_from_rfc3339(&created_at_str)?.with_timezone(&Utc);
+    
+    Ok(JobApplication {
+        id: Uuid::parse_str(&id_str)?,
+        user_id: Uuid::parse_str(&user_id_str)?,
+        job_id: Uuid::parse_str(&job_id_str)?,
+        status,
+        applied_date: applied_date_str
+            .and_then(|s| DateTime::parse_from_rfc3339(&s).ok().map(|d| d.with_timezone(&Utc)))
+            .unwrap_or(created_at),
+        cover_letter: row.get("cover_letter"),
+        custom_resume_id: resume_id_str.and_then(|s| Uuid::parse_str(&s).ok()),
+        notes: row.get("notes"),
+        follow_up_date: follow_up_date_str.and_then(|s| DateTime::parse_from_rfc3339(&s).ok().map(|d| d.with_timezone(&Utc))),
+        created_at,
+        updated_at: DateTime::parse_from_rfc3339(&updated_at_str)?.with_timezone(&Utc),
+    })
+}
+
+/// Resume fields that have no dedicated column, stored as JSON in `content`
+#[derive(serde::Serialize, serde::Deserialize)]
+struct ResumeContent {
+    profile_snapshot: Profile,
+    selected_experiences: Vec<Uuid>,
+    selected_projects: Vec<Uuid>,
+    selected_skills: Vec<Uuid>,
+}
+
+impl ResumeContent {
+    /// Content of a resume without a profile snapshot or selections
+    fn empty(user_id: Uuid, created_at: DateTime<Utc>) -> Self {
+        Self {
+            profile_snapshot: Profile {
+                id: Uuid::nil(),
+                user_id,
+                name: String::new(),
+                headline: None,
+                summary: None,
+                phone: None,
+                email: String::new(),
+                location: None,
+                linkedin_url: None,
+                github_url: None,
+                portfolio_url: None,
+                created_at,
+                updated_at: created_at,
+            },
+            selected_experiences: Vec::new(),
+            selected_projects: Vec::new(),
+            selected_skills: Vec::new(),
+        }
+    }
+    
+    fn from_resume(resume: &Resume) -> Self {
+        Self {
+            profile_snapshot: resume.profile_snapshot.clone(),
+            selected_experiences: resume.selected_experiences.clone(),
+            selected_projects: resume.selected_projects.clone(),
+            selected_skills: resume.selected_skills.clone(),
+        }
+    }
+}
+
+/// SQLite implementation of ResumeRepository
+pub struct SqliteResumeRepository {
+    db: Database,
+}
+
+impl SqliteResumeRepository {
+    pub fn new(db: Database) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl ResumeRepository for SqliteResumeRepository {
+    async fn create(&self, resume: &Resume) -> Result<Resume> {
+        let content_json = serde_json::to_string(&ResumeContent::from_resume(resume))?;
+        let custom_sections_json = serde_json::to_string(&resume.custom_sections)?;
+        
+        sqlx::query(
+            r#"
+            INSERT INTO resumes (id, user_id, name, template, content, custom_sections,
+                created_at, updated_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+            "#
+        )
+        .bind(resume.id.to_string())
+        .bind(resume.user_id.to_string())
+        .bind(&resume.name)
+        .bind(resume.template.as_str())
+        .bind(&content_json)
+        .bind(&custom_sections_json)
+        .bind(resume.created_at.to_rfc3339())
+        .bind(resume.updated_at.to_rfc3339())
+        .execute(self.db.pool())
+        .await?;
+        
+        Ok(resume.clone())
+    }
+    
+    async fn get_by_id(&self, id: &Uuid) -> Result<Option<Resume>> {
+        let row = sqlx::query("SELECT * FROM resumes WHERE id = ?")
+            .bind(id.to_string())
+            .fetch_optional(self.db.pool())
+            .await?;
+        
+        match row {
+            Some(row) => Ok(Some(row_to_resume(&row)?)),
+            None => Ok(None),
+        }
+    }
+    
+    async fn get_by_user_id(&self, user_id: &Uuid) -> Result<Vec<Resume>> {
+        let rows = sqlx::query("SELECT * FROM resumes WHERE user_id = ? ORDER BY updated_at DESC")
+            .bind(user_id.to_string())
+            .fetch_all(self.db.pool())
+            .await?;
+        
+        let mut resumes = Vec::new();
+        for row in rows {
+            resumes.push(row_to_resume(&row)?);
+        }
+        Ok(resumes)
+    }
+    
+    async fn update(&self, resume: &Resume) -> Result<Resume> {
+        let content_json = serde_json::to_string(&ResumeContent::from_resume(resume))?;
+        let custom_sections_json = serde_json::to_string(&resume.custom_sections)?;
+        
+        sqlx::query(
+            r#"
+            UPDATE resumes SET 
+                name = ?, template = ?, content = ?, custom_sections = ?, updated_at = ?
+            WHERE id = ?
+            "#
+        )
+        .bind(&resume.name)
+        .bind(resume.template.as_str())
+        .bind(&content_json)
+        .bind(&custom_sections_json)
+        .bind(Utc::now().to_rfc3339())
+        .bind(resume.id.to_string())
+        .execute(self.db.pool())
+        .await?;
+        
+        Ok(resume.clone())
+    }
+    
+    async fn delete(&self, id: &Uuid) -> Result<()> {
+        sqlx::query("DELETE FROM resumes WHERE id = ?")
+            .bind(id.to_string())
+            .execute(self.db.pool())
+            .await?;
+        Ok(())
+    }
+}
+
+/// Helper to convert a database row to a Resume struct
+fn row_to_resume(row: &sqlx::sqlite::SqliteRow) -> Result<Resume> {
+    let id_str: String = row.get("id");
+    let user_id_str: String = row.get("user_id");
+    let template_str: String = row.get("template");
+    let content_json: Option<String> = row.get("content");
+    let custom_sections_json: Option<String> = row.get("custom_sections");
+    let created_at_str: String = row.get("created_at");
+    let updated_at_str: String = row.get("updated_at");
+    
+    let user_id = Uuid::parse_str(&user_id_str)?;
+    let created_at = DateTime::parse_from_rfc3339(&created_at_str)?.with_timezone(&Utc);
+    
+    // Rows created outside the repository may have no content
+    let content = match content_json {
+        Some(json) => serde_json::from_str(&json)?,
+        None => ResumeContent::empty(user_id, created_at),
+    };
+    let custom_sections: Vec<CustomSection> = custom_sections_json
+        .and_then(|s| serde_json::from_str(&s).ok())
+        .unwrap_or_default();
+    
+    Ok(Resume {
+        id: Uuid::parse_str(&id_str)?,
+        user_id,
+        name: row.get("name"),
+        template: ResumeTemplate::from(template_str.as_str()),
+        profile_snapshot: content.profile_snapshot,
+        selected_experiences: content.selected_experiences,
+        selected_projects: content.selected_projects,
+        selected_skills: content.selected_skills,
+        custom_sections,
+        created_at,
+        updated_at: DateTime::parse_from_rfc3339(&updated_at_str)?.with_timezone(&Utc),
+    })
+}
+
+/// SQLite implementation of UserRepository
+pub struct SqliteUserRepository {
+    db: Database,
+}
+
+impl SqliteUserRepository {
+    pub fn new(db: Database) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl UserRepository for SqliteUserRepository {
+    async fn create(&self, user: &User) -> Result<User> {
+        sqlx::query(
+            r#"
+            INSERT INTO users (id, email, name, created_at, updated_at)
+            VALUES (?, ?, ?, ?, ?)
+            "#
+        )
+        .bind(user.id.to_string())
+        .bind(&user.email)
+        .bind(&user.name)
+        .bind(user.created_at.to_rfc3339())
+        .bind(user.updated_at.to_rfc3339())
+        .execute(self.db.pool())
+        .await?;
+        
+        Ok(user.clone())
+    }
+    
+    async fn get_by_id(&self, id: &Uuid) -> Result<Option<User>> {
+        let row = sqlx::query("SELECT * FROM users WHERE id = ?")
+            .bind(id.to_string())
+            .fetch_optional(self.db.pool())
+            .await?;
+        
+        match row {
+            Some(row) => Ok(Some(row_to_user(&row)?)),
+            None => Ok(None),
+        }
+    }
+    
+    async fn get_by_email(&self, email: &str) -> Result<Option<User>> {
+        let row = sqlx::query("SELECT * FROM users WHERE email = ?")
+            .bind(email)
+            .fetch_optional(self.db.pool())
+            .await?;
+        
+        match row {
+            Some(row) => Ok(Some(row_to_user(&row)?)),
+            None => Ok(None),
+        }
+    }
+    
+    async fn update(&self, user: &User) -> Result<User> {
+        sqlx::query("UPDATE users SET email = ?, name = ?, updated_at = ? WHERE id = ?")
+            .bind(&user.email)
+            .bind(&user.name)
+            .bind(Utc::now().to_rfc3339())
+            .bind(user.id.to_string())
+            .execute(self.db.pool())
+            .await?;
+        
+        Ok(user.clone())
+    }
+    
+    async fn delete(&self, id: &Uuid) -> Result<()> {
+        sqlx::query("DELETE FROM users WHERE id = ?")
+            .bind(id.to_string())
+            .execute(self.db.pool())
+            .await?;
+        Ok(())
+    }
+}
+
+/// Helper to convert a database row to a User struct
+fn row_to_user(row: &sqlx::sqlite::SqliteRow) -> Result<User> {
+    let id_str: String = row.get("id");
+    let created_at_str: String = row.get("created_at");
+    let updated_at_str: String = row.get("updated_at");
+    
+    Ok(User {
+        id: Uuid::parse_str(&id_str)?,
+        email: row.get("email"),
+        name: row.get("name"),
+        created_at: DateTime::parse_from_rfc3339(&created_at_str)?.with_timezone(&Utc),
+        updated_at: DateTime::parse_from_rfc3339(&updated_at_str)?.with_timezone(&Utc),
+    })
+}
+
+/// SQLite implementation of FetchRunRepository
+pub struct SqliteFetchRunRepository {
+    db: Database,
+}
+
+impl SqliteFetchRunRepository {
+    pub fn new(db: Database) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl FetchRunRepository for SqliteFetchRunRepository {
+    async fn create(&self, run: &FetchRun) -> Result<FetchRun> {
+        sqlx::query(
+            r#"
+            INSERT INTO fetch_runs (id, source, started_at, finished_at, fetched, saved, errors, error)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+            "#
+        )
+        .bind(run.id.to_string())
+        .bind(&run.source)
+        .bind(run.started_at.to_rfc3339())
+        .bind(run.finished_at.map(|d| d.to_rfc3339()))
+        .bind(run.fetched as i64)
+        .bind(run.saved as i64)
+        .bind(run.errors as i64)
+        .bind(&run.error)
+        .execute(self.db.pool())
+        .await?;
+        
+        Ok(run.clone())
+    }
+    
+    async fn finish(&self, run: &FetchRun) -> Result<FetchRun> {
+        let mut run = run.clone();
+        let finished_at = *run.finished_at.get_or_insert_with(Utc::now);
+        
+        sqlx::query(
+            r#"
+            UPDATE fetch_runs
+            SET finished_at = ?, fetched = ?, saved = ?, errors = ?, error = ?
+            WHERE id = ?
+            "#
+        )
+        .bind(finished_at.to_rfc3339())
+        .bind(run.fetched as i64)
+        .bind(run.saved as i64)
+        .bind(run.errors as i64)
+        .bind(&run.error)
+        .bind(run.id.to_string())
+        .execute(self.db.pool())
+        .await?;
+        
+        Ok(run)
+    }
+    
+    async fn get_recent(&self, limit: u32) -> Result<Vec<FetchRun>> {
+        let rows = sqlx::query("SELECT * FROM fetch_runs ORDER BY started_at DESC LIMIT ?")
+            .bind(limit as i64)
+            .fetch_all(self.db.pool())
+            .await?;
+        
+        rows.iter().map(row_to_fetch_run).collect()
+    }
+    
+    async fn get_latest_by_source(&self, source: &str) -> Result<Option<FetchRun>> {
+        let row = sqlx::query("SELECT * FROM fetch_runs WHERE source = ? ORDER BY started_at DESC LIMIT 1")
+            .bind(source)
+            .fetch_optional(self.db.pool())
+            .await?;
+        
+        row.as_ref().map(row_to_fetch_run).transpose()
+    }
+}
+
+/// Helper to convert a database row to a FetchRun struct
+fn row_to_fetch_run(row: &sqlx::sqlite::SqliteRow) -> Result<FetchRun> {
+    let id_str: String = row.get("id");
+    let started_at_str: String = row.get("started_at");
+    let finished_at_str: Option<String> = row.get("finished_at");
+    
+    Ok(FetchRun {
+        id: Uuid::parse_str(&id_str)?,
+        source: row.get("source"),
+        started_at: DateTime::parse_from_rfc3339(&started_at_str)?.with_timezone(&Utc),
+        finished_at: finished_at_str
+            .map(|s| DateTime::parse_from_rfc3339(&s).map(|d| d.with_timezone(&Utc)))
+            .transpose()?,
+        fetched: row.get::<i64, _>("fetched") as u32,
+        saved: row.get::<i64, _>("saved") as u32,
+        errors: row.get::<i64, _>("errors") as u32,
+        error: row.get("error"),
+    })
+}
+
+/// SQLite implementation of SavedSearchRepository
+pub struct SqliteSavedSearchRepository {
+    db: Database,
+}
+
+impl SqliteSavedSearchRepository {
+    pub fn new(db: Database) -> Self {
+        Self { db }
+    }
+}
+
+/// Saved searches with their number of unseen matches
+const SAVED_SEARCH_SELECT: &str = r#"
+    SELECT saved_searches.*,
+        (SELECT COUNT(*) FROM saved_search_matches m
+         WHERE m.search_id = saved_searches.id AND m.seen = 0) AS new_matches
+    FROM saved_searches
+"#;
+
+#[async_trait]
+impl SavedSearchRepository for SqliteSavedSearchRepository {
+    async fn create(&self, search: &SavedSearch, query: &JobSearchQuery) -> Result<SavedSearch> {
+        sqlx::query(
+            r#"
+            INSERT INTO saved_searches (id, user_id, name, query, created_at, last_checked_at)
+            VALUES (?, ?, ?, ?, ?, ?)
+            "#
+        )
+        .bind(search.id.to_string())
+        .bind(search.user_id.to_string())
+        .bind(&search.name)
+        .bind(serde_json::to_string(query)?)
+        .bind(search.created_at.to_rfc3339())
+        .bind(search.last_checked_at.map(|d| d.to_rfc3339()))
+        .execute(self.db.pool())
+        .await?;
+        
+        Ok(search.clone())
+    }
+    
+    async fn get_by_user_id(&self, user_id: &Uuid) -> Result<Vec<SavedSearch>> {
+        let rows = sqlx::query(&format!("{} WHERE user_id = ? ORDER BY created_at", SAVED_SEARCH_SELECT))
+            .bind(user_id.to_string())
+            .fetch_all(self.db.pool())
+            .await?;
+        
+        rows.iter().map(row_to_saved_search).collect()
+    }
+    
+    async fn get_all(&self) -> Result<Vec<(SavedSearch, JobSearchQuery)>> {
+        let rows = sqlx::query(&format!("{} ORDER BY created_at", SAVED_SEARCH_SELECT))
+            .fetch_all(self.db.pool())
+            .await?;
+        
+        let mut searches = Vec::new();
+        for row in rows {
+            let query: String = row.get("query");
+            searches.push((row_to_saved_search(&row)?, serde_json::from_str(&query)?));
+        }
+        Ok(searches)
+    }
+    
+    async fn record_matches(&self, search_id: &Uuid, job_ids: &[Uuid], seen: bool) -> Result<u32> {
+        let now = Utc::now().to_rfc3339();
+        let mut tx = self.db.pool().begin().await?;
+        
+        let mut recorded = 0;
+        for job_id in job_ids {
+            recorded += sqlx::query(
+                r#"
+                INSERT OR IGNORE INTO saved_search_matches (search_id, job_id, found_at, seen)
+                VALUES (?, ?, ?, ?)
+                "#
+            )
+            .bind(search_id.to_string())
+            .bind(job_id.to_string())
+            .bind(&now)
+            .bind(seen)
+            .execute(&mut *tx)
+            .await?
+            .rows_affected() as u32;
+        }
+        
+        sqlx::query("UPDATE saved_searches SET last_checked_at = ? WHERE id = ?")
+            .bind(&now)
+            .bind(search_id.to_string())
+            .execute(&mut *tx)
+            .await?;
+        
+        tx.commit().await?;
+        Ok(recorded)
+    }
+    
+    async fn get_new_matches(&self, search_id: &Uuid) -> Result<Vec<JobListing>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT jobs.* FROM jobs
+            JOIN saved_search_matches m ON m.job_id = jobs.id
+            WHERE m.search_id = ? AND m.seen = 0
+            ORDER BY jobs.posted_date DESC
+            "#
+        )
+        .bind(search_id.to_string())
+        .fetch_all(self.db.pool())
+        .await?;
+        
+        let mut jobs = Vec::new();
+        for row in rows {
+            jobs.push(row_to_job_listing(&row)?);
+        }
+        SqliteJobRepository::new(self.db.clone())
+            .attach_other_sources(&mut jobs.iter_mut().collect::<Vec<_>>())
+            .await?;
+        Ok(jobs)
+    }
+    
+    async fn mark_seen(&self, search_id: &Uuid) -> Result<()> {
+        sqlx::query("UPDATE saved_search_matches SET seen = 1 WHERE search_id = ?")
+            .bind(search_id.to_string())
+            .execute(self.db.pool())
+            .await?;
+        Ok(())
+    }
+    
+    async fn delete(&self, id: &Uuid) -> Result<()> {
+        sqlx::query("DELETE FROM saved_searches WHERE id = ?")
+            .bind(id.to_string())
+            .execute(self.db.pool())
+            .await?;
+        Ok(())
+    }
+}
+
+/// Helper to convert a database row to a SavedSearch struct
+fn row_to_saved_search(row: &sqlx::sqlite::SqliteRow) -> Result<SavedSearch> {
+    let id_str: String = row.get("id");
+    let user_id_str: String = row.get("user_id");
+    let created_at_str: String = row.get("created_at");
+    let last_checked_at_str: Option<String> = row.get("last_checked_at");
+    
+    Ok(SavedSearch {
+        id: Uuid::parse_str(&id_str)?,
+        user_id: Uuid::parse_str(&user_id_str)?,
+        name: row.get("name"),
+        created_at: DateTime::parse_from_rfc3339(&created_at_str)?.with_timezone(&Utc),
+        last_checked_at: last_checked_at_str
+            .map(|s| DateTime::parse_from_rfc3339(&s).map(|d| d.with_timezone(&Utc)))
+            .transpose()?,
+        new_matches: row.get::<i64, _>("new_matches") as u32,
+    })
+}
+
+/// SQLite implementation of CompanyBoardRepository
+pub struct SqliteCompanyBoardRepository {
+    db: Database,
+}
+
+impl SqliteCompanyBoardRepository {
+    pub fn new(db: Database) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl CompanyBoardRepository for SqliteCompanyBoardRepository {
+    async fn upsert(&self, board: &CompanyBoard) -> Result<CompanyBoard> {
+        sqlx::query(
+            r#"
+            INSERT INTO company_boards (source, token, company, created_at)
+            VALUES (?, ?, ?, ?)
+            ON CONFLICT(source, token) DO UPDATE SET company = excluded.company
+            "#
+        )
+        .bind(board.source.as_str())
+        .bind(&board.token)
+        .bind(&board.company)
+        .bind(Utc::now().to_rfc3339())
+        .execute(self.db.pool())
+        .await?;
+        
+        Ok(board.clone())
+    }
+    
+    async fn get_all(&self) -> Result<Vec<CompanyBoard>> {
+        let rows = sqlx::query("SELECT * FROM company_boards ORDER BY source, token")
+            .fetch_all(self.db.pool())
+            .await?;
+        
+        Ok(rows
+            .iter()
+            .map(|row| {
+                let source: String = row.get("source");
+                CompanyBoard {
+                    source: JobSource::from(source.as_str()),
+                    token: row.get("token"),
+                    company: row.get("company"),
+                }
+            })
+            .collect())
+    }
+    
+    async fn delete(&self, source: &JobSource, token: &str) -> Result<()> {
+        sqlx::query("DELETE FROM company_boards WHERE source = ? AND token = ?")
+            .bind(source.as_str())
+            .bind(token)
+            .execute(self.db.pool())
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{job, skill, user};
+    use chrono::Duration;
+
+    fn salary(min: u32, max: u32, currency: &str, period: SalaryPeriod) -> Option<SalaryRange> {
+        Some(SalaryRange { min, max, currency: currency.to_string(), period })
+    }
+
+    async fn search_titles(repo: &SqliteJobRepository, query: JobSearchQuery) -> Vec<String> {
+        repo.search(&query)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|r| r.listing.job.title)
+            .collect()
+    }
+
+    /// Seed a small set of jobs covering every search filter
+    async fn seeded_repo() -> SqliteJobRepository {
+        let repo = SqliteJobRepository::new(Database::in_memory().await.unwrap());
+
+        let mut rust = job("Rust Engineer", "Acme", "Remote - Europe", JobSource::Remotive);
+        rust.salary_range = salary(90_000, 120_000, "EUR", SalaryPeriod::Annual);
+        rust.posted_date = Utc::now() - Duration::days(1);
+
+        let mut go = job("Go Developer", "Globex", "Berlin", JobSource::Arbeitnow);
+        go.salary_range = salary(60, 80, "EUR", SalaryPeriod::Hourly);
+        go.posted_date = Utc::now() - Duration::days(10);
+
+        let mut python = job("Python Developer", "Initech", "New York", JobSource::HNWhoIsHiring);
+        python.salary_range = salary(150_000, 180_000, "USD", SalaryPeriod::Annual);
+        python.posted_date = Utc::now() - Duration::days(30);
+
+        let mut rust_contract = job("Rust Contractor", "Globex", "Berlin (Remote)", JobSource::Remotive);
+        rust_contract.posted_date = Utc::now() - Duration::days(5);
+
+        for job in [rust, go, python, rust_contract] {
+            repo.create(&job).await.unwrap();
+        }
+        repo
+    }
+
+    #[tokio::test]
+    async fn test_status_changes_are_recorded_in_history() {
+        let db = Database::in_memory().await.unwrap();
+        let users = SqliteUserRepository::new(db.clone());
+        let jobs = SqliteJobRepository::new(db.clone());
+        let applications = SqliteApplicationRepository::new(db.clone());
+
+        let user = user("Jane", "jane@example.com");
+        users.create(&user).await.unwrap();
+        let job = job("Rust Engineer", "Acme", "Remote", JobSource::Remotive);
+        jobs.create(&job).await.unwrap();
+
+        let mut application = JobApplication {
+            id: Uuid::new_v4(),
+            user_id: user.id,
+            job_id: job.id,
+            status: ApplicationStatus::Draft,
+            applied_date: Utc::now(),
+            cover_letter: None,
+            custom_resume_id: None,
+            notes: None,
+            follow_up_date: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        };
+        applications.create(&application).await.unwrap();
+
+        // Saving without a status change does not add an entry
+        application.cover_letter = Some("Dear Acme".to_string());
+        applications.update(&application).await.unwrap();
+        application.status = ApplicationStatus::Applied;
+        applications.update(&application).await.unwrap();
+
+        applications
+            .update_status(&application.id, ApplicationStatus::Interviewing, Some("Phone screen booked".to_string()))
+            .await
+            .unwrap();
+        let updated = applications
+            .update_status(&application.id, ApplicationStatus::Rejected, None)
+            .await
+            .unwrap();
+        assert_eq!(updated.status, ApplicationStatus::Rejected);
+
+        let history = applications.get_status_history(&application.id).await.unwrap();
+        let statuses: Vec<ApplicationStatus> = history.iter().map(|h| h.status.clone()).collect();
+        assert_eq!(
+            statuses,
+            vec![
+                ApplicationStatus::Draft,
+                ApplicationStatus::Applied,
+                ApplicationStatus::Interviewing,
+                ApplicationStatus::Rejected,
+            ]
+        );
+        assert_eq!(history[2].notes.as_deref(), Some("Phone screen booked"));
+        assert!(history.iter().all(|h| h.application_id == application.id));
+
+        let missing = applications.update_status(&Uuid::new_v4(), ApplicationStatus::Applied, None).await;
+        assert!(missing.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_users_round_trip() {
+        let users = SqliteUserRepository::new(Database::in_memory().await.unwrap());
+        let mut user = user("Jane", "jane@example.com");
+        users.create(&user).await.unwrap();
+
+        let loaded = users.get_by_email("jane@example.com").await.unwrap().unwrap();
+        assert_eq!((loaded.id, loaded.name.as_str()), (user.id, "Jane"));
+
+        user.name = "Jane Doe".to_string();
+        users.update(&user).await.unwrap();
+        assert_eq!(users.get_by_id(&user.id).await.unwrap().unwrap().name, "Jane Doe");
+
+        users.delete(&user.id).await.unwrap();
+        assert!(users.get_by_id(&user.id).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_resumes_round_trip() {
+        let db = Database::in_memory().await.unwrap();
+        let users = SqliteUserRepository::new(db.clone());
+        let resumes = SqliteResumeRepository::new(db.clone());
+        let user = user("Jane", "jane@example.com");
+        users.create(&user).await.unwrap();
+
+        let mut resume = Resume {
+            id: Uuid::new_v4(),
+            user_id: user.id,
+            name: "Backend roles".to_string(),
+            template: ResumeTemplate::Modern,
+            profile_snapshot: ResumeContent::empty(user.id, Utc::now()).profile_snapshot,
+            selected_experiences: vec![Uuid::new_v4()],
+            selected_projects: Vec::new(),
+            selected_skills: vec![Uuid::new_v4(), Uuid::new_v4()],
+            custom_sections: vec![CustomSection { title: "Talks".to_string(), content: "RustConf".to_string(), order: 1 }],
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        };
+        resumes.create(&resume).await.unwrap();
+
+        // Templates are stored by their stable identifier
+        let stored: String = sqlx::query_scalar("SELECT template FROM resumes WHERE id = ?")
+            .bind(resume.id.to_string())
+            .fetch_one(db.pool())
+            .await
+            .unwrap();
+        assert_eq!(stored, "modern");
+
+        let loaded = resumes.get_by_id(&resume.id).await.unwrap().unwrap();
+        assert_eq!(loaded.template, ResumeTemplate::Modern);
+        assert_eq!(loaded.selected_experiences, resume.selected_experiences);
+        assert_eq!(loaded.selected_skills, resume.selected_skills);
+        assert_eq!(loaded.custom_sections[0].title, "Talks");
+
+        resume.template = ResumeTemplate::Academic;
+        resume.name = "Research roles".to_string();
+        resumes.update(&resume).await.unwrap();
+        let listed = resumes.get_by_user_id(&user.id).await.unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!((listed[0].name.as_str(), &listed[0].template), ("Research roles", &ResumeTemplate::Academic));
+
+        resumes.delete(&resume.id).await.unwrap();
+        assert!(resumes.get_by_id(&resume.id).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_resume_rows_without_content() {
+        let db = Database::in_memory().await.unwrap();
+        let jane = user("Jane", "jane@example.com");
+        SqliteUserRepository::new(db.clone()).create(&jane).await.unwrap();
+        let user_id = jane.id;
+
+        // A legacy row: template stored as the variant name, no content
+        let id = Uuid::new_v4();
+        sqlx::query("INSERT INTO resumes (id, user_id, name, template, created_at, updated_at) VALUES (?, ?, 'Old', 'Creative', ?, ?)")
+            .bind(id.to_string())
+            .bind(user_id.to_string())
+            .bind(Utc::now().to_rfc3339())
+            .bind(Utc::now().to_rfc3339())
+            .execute(db.pool())
+            .await
+            .unwrap();
+
+        let resume = SqliteResumeRepository::new(db).get_by_id(&id).await.unwrap().unwrap();
+        assert_eq!(resume.template, ResumeTemplate::Creative);
+        assert_eq!(resume.profile_snapshot.user_id, user_id);
+        assert!(resume.selected_skills.is_empty() && resume.custom_sections.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_skills_update_in_place() {
+        let db = Database::in_memory().await.unwrap();
+        let jane = user("Jane", "jane@example.com");
+        SqliteUserRepository::new(db.clone()).create(&jane).await.unwrap();
+        let user_id = jane.id;
+        let profile_id = Uuid::new_v4();
+        sqlx::query("INSERT INTO profiles (id, user_id) VALUES (?, ?)")
+            .bind(profile_id.to_string())
+            .bind(user_id.to_string())
+            .execute(db.pool())
+            .await
+            .unwrap();
+
+        let repo = SqliteSkillRepository::new(db);
+        let mut skill = Skill { profile_id, years_experience: Some(2), ..skill("Rust", SkillLevel::Intermediate) };
+        repo.create(&skill).await.unwrap();
+
+        skill.proficiency = SkillLevel::Expert;
+        skill.years_experience = Some(6);
+        repo.update(&skill).await.unwrap();
+
+        let skills = repo.get_by_profile_id(&profile_id).await.unwrap();
+        assert_eq!(skills.len(), 1);
+        assert_eq!(skills[0].id, skill.id);
+        assert_eq!(skills[0].proficiency, SkillLevel::Expert);
+        assert_eq!(skills[0].years_experience, Some(6));
+    }
+
+    #[tokio::test]
+    async fn test_custom_skills_round_trip() {
+        let repo = SqliteCustomSkillRepository::new(Database::in_memory().await.unwrap());
+
+        let mut skill = SkillDefinition {
+            name: "Frobnicator".to_string(),
+            category: SkillCategory::Tool,
+            aliases: vec!["frob".to_string()],
+            case_sensitive: false,
+        };
+        repo.upsert(&skill).await.unwrap();
+        assert_eq!(repo.get_all().await.unwrap(), vec![skill.clone()]);
+
+        // Names are unique regardless of case
+        skill.name = "frobnicator".to_string();
+        skill.category = SkillCategory::Other;
+        skill.case_sensitive = true;
+        repo.upsert(&skill).await.unwrap();
+        assert_eq!(repo.get_all().await.unwrap(), vec![skill.clone()]);
+
+        repo.delete("FROBNICATOR").await.unwrap();
+        assert!(repo.get_all().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_search_without_filters_returns_newest_first() {
+        let repo = seeded_repo().await;
+        let titles = search_titles(&repo, JobSearchQuery::new()).await;
+        assert_eq!(titles, vec!["Rust Engineer", "Rust Contractor", "Go Developer", "Python Developer"]);
+    }
+
+    #[tokio::test]
+    async fn test_search_by_sources() {
+        let repo = seeded_repo().await;
+
+        let titles = search_titles(&repo, JobSearchQuery::new().source(JobSource::Remotive)).await;
+        assert_eq!(titles, vec!["Rust Engineer", "Rust Contractor"]);
+
+        let titles = search_titles(
+            &repo,
+            JobSearchQuery::new().source(JobSource::Arbeitnow).source(JobSource::HNWhoIsHiring),
+        )
+        .await;
+        assert_eq!(titles, vec!["Go Developer", "Python Developer"]);
+
+        assert!(search_titles(&repo, JobSearchQuery::new().source(JobSource::LinkedIn)).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_search_remote_only() {
+        let repo = seeded_repo().await;
+        let titles = search_titles(&repo, JobSearchQuery::new().remote_only(true)).await;
+        assert_eq!(titles, vec!["Rust Engineer", "Rust Contractor"]);
+    }
+
+    #[tokio::test]
+    async fn test_upsert_updates_remote_flag() {
+        let repo = seeded_repo().await;
+
+        let mut moved = job("Go Developer", "Globex", "Berlin", JobSource::Arbeitnow);
+        repo.create(&moved).await.unwrap();
+        moved.location = "Remote".to_string();
+        repo.upsert_by_source(&moved).await.unwrap();
+
+        let titles = search_titles(&repo, JobSearchQuery::new().remote_only(true).source(JobSource::Arbeitnow)).await;
+        assert_eq!(titles, vec!["Go Developer"]);
+    }
+
+    #[tokio::test]
+    async fn test_search_by_salary() {
+        let repo = seeded_repo().await;
+
+        let titles = search_titles(&repo, JobSearchQuery::new().min_salary(100_000)).await;
+        assert_eq!(titles, vec!["Python Developer"]);
+
+        let titles = search_titles(&repo, JobSearchQuery::new().salary_currency("eur")).await;
+        assert_eq!(titles, vec!["Rust Engineer", "Go Developer"]);
+
+        let titles = search_titles(&repo, JobSearchQuery::new().salary_period(SalaryPeriod::Hourly)).await;
+        assert_eq!(titles, vec!["Go Developer"]);
+    }
+
+    #[tokio::test]
+    async fn test_search_posted_since() {
+        let repo = seeded_repo().await;
+        let titles = search_titles(&repo, JobSearchQuery::new().posted_since(Utc::now() - Duration::days(7))).await;
+        assert_eq!(titles, vec!["Rust Engineer", "Rust Contractor"]);
+    }
+
+    #[tokio::test]
+    async fn test_search_company_filters() {
+        let repo = seeded_repo().await;
+
+        let titles = search_titles(&repo, JobSearchQuery::new().include_company("globex")).await;
+        assert_eq!(titles, vec!["Rust Contractor", "Go Developer"]);
+
+        let titles = search_titles(
+            &repo,
+            JobSearchQuery::new().exclude_company("Globex").exclude_company(" ACME "),
+        )
+        .await;
+        assert_eq!(titles, vec!["Python Developer"]);
+    }
+
+    #[tokio::test]
+    async fn test_search_sort_orders() {
+        let repo = seeded_repo().await;
+
+        let titles = search_titles(&repo, JobSearchQuery::new().sort(JobSortOrder::Oldest)).await;
+        assert_eq!(titles, vec!["Python Developer", "Go Developer", "Rust Contractor", "Rust Engineer"]);
+
+        let titles = search_titles(&repo, JobSearchQuery::new().sort(JobSortOrder::HighestSalary)).await;
+        assert_eq!(titles, vec!["Python Developer", "Rust Engineer", "Go Developer", "Rust Contractor"]);
+    }
+
+    #[tokio::test]
+    async fn test_search_best_match_after_upsert() {
+        let repo = SqliteJobRepository::new(Database::in_memory().await.unwrap());
+        let rust = job("Rust Engineer", "Acme", "Remote", JobSource::Remotive);
+        let go = job("Go Developer", "Globex", "Berlin", JobSource::Arbeitnow);
+        let python = job("Python Developer", "Initech", "New York", JobSource::HNWhoIsHiring);
+        for job in [&rust, &go, &python] {
+            repo.create(job).await.unwrap();
+        }
+
+        // Re-fetched postings get a fresh id, but the stored row keeps its own
+        let refetched = Job { id: Uuid::new_v4(), ..go.clone() };
+        let stored = repo.upsert_by_source(&refetched).await.unwrap();
+        assert_eq!(stored.id, go.id);
+
+        repo.update_match_score(&stored.id, 85.0).await.unwrap();
+        repo.update_match_score(&rust.id, 40.0).await.unwrap();
+
+        let titles = search_titles(&repo, JobSearchQuery::new().sort(JobSortOrder::BestMatch)).await;
+        assert_eq!(titles, vec!["Go Developer", "Rust Engineer", "Python Developer"]);
+    }
+
+    #[tokio::test]
+    async fn test_job_source_round_trip() {
+        let repo = SqliteJobRepository::new(Database::in_memory().await.unwrap());
+        let sources = [
+            JobSource::LinkedIn,
+            JobSource::Indeed,
+            JobSource::Glassdoor,
+            JobSource::AngelList,
+            JobSource::Remotive,
+            JobSource::HNWhoIsHiring,
+            JobSource::Arbeitnow,
+            JobSource::Greenhouse,
+            JobSource::Lever,
+            JobSource::Ashby,
+            JobSource::Workable,
+            JobSource::SmartRecruiters,
+            JobSource::Other("Company careers page".to_string()),
+        ];
+        for (i, source) in sources.iter().enumerate() {
+            let mut posting = job(&format!("Job {}", i), &format!("Company {}", i), "Remote", source.clone());
+            posting.source_job_id = Some(i.to_string());
+            repo.create(&posting).await.unwrap();
+
+            let stored = repo.get_by_id(&posting.id).await.unwrap().unwrap();
+            assert_eq!(stored.source, *source);
+            assert_eq!(stored.source_job_id, Some(i.to_string()));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_upsert_matches_source_job_id() {
+        let repo = SqliteJobRepository::new(Database::in_memory().await.unwrap());
+        let mut posting = job("Rust Engineer", "Acme", "Remote", JobSource::HNWhoIsHiring);
+        posting.source_job_id = Some("4242".to_string());
+        repo.upsert_by_source(&posting).await.unwrap();
+
+        let moved = Job {
+            id: Uuid::new_v4(),
+            source_url: "https://example.com/moved".to_string(),
+            ..posting.clone()
+        };
+        let stored = repo.upsert_by_source(&moved).await.unwrap();
+        assert_eq!(stored.id, posting.id);
+        let stored = repo.get_by_id(&posting.id).await.unwrap().unwrap();
+        assert_eq!(stored.source_url, "https://example.com/moved");
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_postings_are_collapsed() {
+        let repo = SqliteJobRepository::new(Database::in_memory().await.unwrap());
+        let remotive = job("Senior Rust Engineer", "Acme Inc.", "Remote", JobSource::Remotive);
+        let arbeitnow = job("Sr. Rust Engineer (m/w/d)", "ACME GmbH", "Berlin", JobSource::Arbeitnow);
+        let hn = job("Senior Rust Engineer", "Acme", "Remote", JobSource::HNWhoIsHiring);
+        let other = job("Senior Rust Engineer", "Globex", "Remote", JobSource::Remotive);
+        for job in [&remotive, &arbeitnow, &hn, &other] {
+            repo.upsert_by_source(job).await.unwrap();
+        }
+        // Refetching the canonical posting keeps it canonical
+        repo.upsert_by_source(&remotive).await.unwrap();
+
+        let results = repo.search(&JobSearchQuery::new().sort(JobSortOrder::Oldest)).await.unwrap();
+        assert_eq!(results.len(), 2);
+        let acme = &results[0].listing;
+        assert_eq!(acme.job.id, remotive.id);
+        let sources: Vec<_> = acme.other_sources.iter().map(|s| (s.job_id, s.url.clone())).collect();
+        assert_eq!(sources, vec![(arbeitnow.id, arbeitnow.source_url.clone()), (hn.id, hn.source_url.clone())]);
+        assert!(results[1].listing.other_sources.is_empty());
+
+        let results = repo.search(&JobSearchQuery::new().include_duplicates(true)).await.unwrap();
+        assert_eq!(results.len(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_link_duplicates_of_stored_jobs() {
+        let repo = SqliteJobRepository::new(Database::in_memory().await.unwrap());
+        // Stored without deduplication, as before duplicates were tracked
+        let oldest = job("Senior Rust Engineer", "Acme Inc.", "Remote", JobSource::Remotive);
+        let copy = job("Sr. Rust Engineer (m/w/d)", "ACME GmbH", "Berlin", JobSource::Arbeitnow);
+        let other = job("Senior Rust Engineer", "Globex", "Remote", JobSource::Remotive);
+        for job in [&oldest, &copy, &other] {
+            repo.create(job).await.unwrap();
+        }
+        // Linked on arrival, but to a newer posting than the oldest
+        let newer = job("Senior Rust Engineer", "Acme", "Remote", JobSource::HNWhoIsHiring);
+        repo.create(&newer).await.unwrap();
+        let linked = job("Senior Rust Engineer", "Acme", "Remote - Europe", JobSource::Indeed);
+        repo.create(&linked).await.unwrap();
+        sqlx::query("UPDATE jobs SET duplicate_of = ? WHERE id = ?")
+            .bind(newer.id.to_string())
+            .bind(linked.id.to_string())
+            .execute(repo.db.pool())
+            .await
+            .unwrap();
+
+        assert_eq!(repo.link_duplicates().await.unwrap(), 2);
+        assert_eq!(repo.link_duplicates().await.unwrap(), 0);
+
+        let results = repo.search(&JobSearchQuery::new().sort(JobSortOrder::Oldest)).await.unwrap();
+        let ids: Vec<_> = results.iter().map(|r| r.listing.job.id).collect();
+        assert_eq!(ids, vec![oldest.id, other.id]);
+        let mut others: Vec<_> = results[0].listing.other_sources.iter().map(|s| s.job_id).collect();
+        others.sort();
+        let mut expected = vec![copy.id, newer.id, linked.id];
+        expected.sort();
+        assert_eq!(others, expected);
+    }
+
+    #[tokio::test]
+    async fn test_requisitions_on_one_board_are_not_collapsed() {
+        let repo = SqliteJobRepository::new(Database::in_memory().await.unwrap());
+        let berlin = Job {
+            source_job_id: Some("4001".to_string()),
+            ..job("Backend Engineer", "Acme", "Berlin", JobSource::Greenhouse)
+        };
+        let remote = Job {
+            source_job_id: Some("4002".to_string()),
+            ..job("Backend Engineer", "Acme", "Remote", JobSource::Greenhouse)
+        };
+        repo.upsert_by_source(&berlin).await.unwrap();
+        repo.upsert_by_source(&remote).await.unwrap();
+
+        let results = repo.search(&JobSearchQuery::new().sort(JobSortOrder::Oldest)).await.unwrap();
+        let ids: Vec<_> = results.iter().map(|r| r.listing.job.id).collect();
+        assert_eq!(ids, vec![berlin.id, remote.id]);
+        assert!(results.iter().all(|r| r.listing.other_sources.is_empty()));
+    }
+
+    #[tokio::test]
+    async fn test_filters_match_duplicate_postings() {
+        let repo = SqliteJobRepository::new(Database::in_memory().await.unwrap());
+        let remotive = job("Senior Rust Engineer", "Acme Inc.", "Remote", JobSource::Remotive);
+        let arbeitnow = Job {
+            description: "Relocation and visa sponsorship for our Berlin office.".to_string(),
+            ..job("Sr. Rust Engineer (m/w/d)", "ACME GmbH", "Berlin", JobSource::Arbeitnow)
+        };
+        repo.upsert_by_source(&remotive).await.unwrap();
+        repo.upsert_by_source(&arbeitnow).await.unwrap();
+
+        // Only the duplicate matches, and the job is returned as its canonical listing
+        for query in [
+            JobSearchQuery::new().source(JobSource::Arbeitnow),
+            JobSearchQuery::new().location("Berlin"),
+            JobSearchQuery::new().keywords("visa"),
+        ] {
+            let results = repo.search(&query).await.unwrap();
+            assert_eq!(results.len(), 1, "{:?}", query);
+            assert_eq!(results[0].listing.job.id, remotive.id);
+            assert_eq!(results[0].listing.other_sources[0].job_id, arbeitnow.id);
+        }
+        let results = repo.search(&JobSearchQuery::new().keywords("visa")).await.unwrap();
+        assert!(results[0].snippet.iter().any(|span| span.highlighted && span.text == "visa"));
+
+        let results = repo.search(&JobSearchQuery::new().keywords("visa").include_duplicates(true)).await.unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].listing.job.id, arbeitnow.id);
+    }
+
+    #[tokio::test]
+    async fn test_listings_carry_saved_state_and_match_score() {
+        let repo = SqliteJobRepository::new(Database::in_memory().await.unwrap());
+        let rust = job("Rust Engineer", "Acme", "Remote", JobSource::Remotive);
+        let go = job("Go Developer", "Globex", "Berlin", JobSource::Arbeitnow);
+        repo.create(&rust).await.unwrap();
+        repo.create(&go).await.unwrap();
+        repo.save(&rust.id).await.unwrap();
+        repo.update_match_score(&rust.id, 72.0).await.unwrap();
+
+        let saved = repo.get_saved().await.unwrap();
+        assert_eq!(saved.len(), 1);
+        assert_eq!(saved[0].job.id, rust.id);
+        assert!(saved[0].is_saved);
+        assert!(saved[0].is_remote);
+        assert_eq!(saved[0].match_score, Some(72.0));
+
+        let results = repo.search(&JobSearchQuery::new().keywords("go")).await.unwrap();
+        assert_eq!(results.len(), 1);
+        let listing = &results[0].listing;
+        assert!(!listing.is_saved && !listing.is_remote);
+        assert_eq!(listing.match_score, None);
+    }
+
+    #[tokio::test]
+    async fn test_search_keywords_with_filters_and_paging() {
+        let repo = seeded_repo().await;
+
+        let titles = search_titles(&repo, JobSearchQuery::new().keywords("rust").include_company("Globex")).await;
+        assert_eq!(titles, vec!["Rust Contractor"]);
+
+        let titles = search_titles(&repo, JobSearchQuery::new().sort(JobSortOrder::Newest).limit(2).offset(1)).await;
+        assert_eq!(titles, vec!["Rust Contractor", "Go Developer"]);
+
+        let titles = search_titles(&repo, JobSearchQuery::new().sort(JobSortOrder::Newest).offset(3)).await;
+        assert_eq!(titles, vec!["Python Developer"]);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_runs() {
+        let repo = SqliteFetchRunRepository::new(Database::in_memory().await.unwrap());
+        let mut first = FetchRun::start("Remotive");
+        first.started_at -= chrono::Duration::hours(6);
+        repo.create(&first).await.unwrap();
+        let running = repo.create(&FetchRun::start("Remotive")).await.unwrap();
+        repo.create(&FetchRun::start("Arbeitnow")).await.unwrap();
+
+        let latest = repo.get_latest_by_source("Remotive").await.unwrap().unwrap();
+        assert_eq!(latest.id, running.id);
+        assert_eq!(latest.finished_at, None);
+
+        let mut done = latest.clone();
+        done.fetched = 12;
+        done.saved = 11;
+        done.errors = 1;
+        done.error = Some("Timed out after 30s".to_string());
+        let done = repo.finish(&done).await.unwrap();
+        assert!(done.finished_at.is_some());
+        assert_eq!(repo.get_latest_by_source("Remotive").await.unwrap(), Some(done));
+
+        let recent = repo.get_recent(2).await.unwrap();
+        assert_eq!(recent.len(), 2);
+        assert!(recent.iter().all(|run| run.id != first.id));
+        assert_eq!(repo.get_latest_by_source("Glassdoor").await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_company_boards() {
+        let repo = SqliteCompanyBoardRepository::new(Database::in_memory().await.unwrap());
+        repo.upsert(&CompanyBoard::new(JobSource::Lever, "acme")).await.unwrap();
+        repo.upsert(&CompanyBoard::new(JobSource::Greenhouse, "acme")).await.unwrap();
+        let named = CompanyBoard { company: Some("Acme, Inc.".to_string()), ..CompanyBoard::new(JobSource::Lever, "acme") };
+        repo.upsert(&named).await.unwrap();
+
+        let boards = repo.get_all().await.unwrap();
+        assert_eq!(boards, vec![CompanyBoard::new(JobSource::Greenhouse, "acme"), named]);
+
+        repo.delete(&JobSource::Greenhouse, "acme").await.unwrap();
+        assert_eq!(repo.get_all().await.unwrap().len(), 1);
+    }
+}
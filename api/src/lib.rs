@@ -7,6 +7,9 @@ pub mod services;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod db;
 
+#[cfg(test)]
+mod test_fixtures;
+
 // Re-export all models and services for easy access
 pub use models::*;
 pub use services::*;
@@ -8,15 +8,29 @@ pub struct Job {
     pub title: String,
     pub company: String,
     pub location: String,
+    /// Department or team, from sources that report one
+    pub department: Option<String>,
     pub description: String,
     pub requirements: Vec<String>,
     pub salary_range: Option<SalaryRange>,
     pub source: JobSource,
     pub source_url: String,
+    /// The posting's id at its source, e.g. the HN comment id
+    pub source_job_id: Option<String>,
     pub posted_date: DateTime<Utc>,
     pub scraped_at: DateTime<Utc>,
 }
 
+impl Job {
+    /// Whether the posting is for a remote position
+    ///
+    /// Job sources report remote work as part of the location
+    /// (e.g. "Remote - USA", "Berlin (Remote)").
+    pub fn is_remote(&self) -> bool {
+        self.location.to_lowercase().contains("remote")
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct SalaryRange {
     pub min: u32,
@@ -43,11 +57,42 @@ pub enum JobSource {
     HNWhoIsHiring,
     /// Jobs from Arbeitnow.com - Germany/EU focused (free API, no key required)
     Arbeitnow,
+    /// Company job boards on Greenhouse (public API, no key required)
+    Greenhouse,
+    /// Company job boards on Lever (public API, no key required)
+    Lever,
+    /// Company job boards on Ashby (public API, no key required)
+    Ashby,
+    /// Company job boards on Workable (public API, no key required)
+    Workable,
+    /// Company job boards on SmartRecruiters (public API, no key required)
+    SmartRecruiters,
     /// Other/custom source
     Other(String),
 }
 
 impl JobSource {
+    /// Stable identifier for this source, as stored in the database
+    ///
+    /// Other sources are identified by their name.
+    pub fn as_str(&self) -> &str {
+        match self {
+            JobSource::LinkedIn => "linkedin",
+            JobSource::Indeed => "indeed",
+            JobSource::Glassdoor => "glassdoor",
+            JobSource::AngelList => "angellist",
+            JobSource::Remotive => "remotive",
+            JobSource::HNWhoIsHiring => "hn_who_is_hiring",
+            JobSource::Arbeitnow => "arbeitnow",
+            JobSource::Greenhouse => "greenhouse",
+            JobSource::Lever => "lever",
+            JobSource::Ashby => "ashby",
+            JobSource::Workable => "workable",
+            JobSource::SmartRecruiters => "smartrecruiters",
+            JobSource::Other(name) => name,
+        }
+    }
+    
     /// Get the display name for this source
     pub fn display_name(&self) -> &str {
         match self {
@@ -58,6 +103,11 @@ impl JobSource {
             JobSource::Remotive => "Remotive",
             JobSource::HNWhoIsHiring => "HN Who's Hiring",
             JobSource::Arbeitnow => "Arbeitnow",
+            JobSource::Greenhouse => "Greenhouse",
+            JobSource::Lever => "Lever",
+            JobSource::Ashby => "Ashby",
+            JobSource::Workable => "Workable",
+            JobSource::SmartRecruiters => "SmartRecruiters",
             JobSource::Other(name) => name,
         }
     }
@@ -72,7 +122,218 @@ impl JobSource {
             JobSource::Remotive => false,
             JobSource::HNWhoIsHiring => false,
             JobSource::Arbeitnow => false,
+            JobSource::Greenhouse => false,
+            JobSource::Lever => false,
+            JobSource::Ashby => false,
+            JobSource::Workable => false,
+            JobSource::SmartRecruiters => false,
             JobSource::Other(_) => false,
         }
     }
-}
\ No newline at end of file
+}
+
+impl From<&str> for JobSource {
+    /// Parse an identifier from [`JobSource::as_str`]; anything else is an
+    /// `Other` source with that name
+    fn from(id: &str) -> Self {
+        match id {
+            "linkedin" => JobSource::LinkedIn,
+            "indeed" => JobSource::Indeed,
+            "glassdoor" => JobSource::Glassdoor,
+            "angellist" => JobSource::AngelList,
+            "remotive" => JobSource::Remotive,
+            "hn_who_is_hiring" => JobSource::HNWhoIsHiring,
+            "arbeitnow" => JobSource::Arbeitnow,
+            "greenhouse" => JobSource::Greenhouse,
+            "lever" => JobSource::Lever,
+            "ashby" => JobSource::Ashby,
+            "workable" => JobSource::Workable,
+            "smartrecruiters" => JobSource::SmartRecruiters,
+            other => JobSource::Other(other.to_string()),
+        }
+    }
+}
+
+/// A company's public job board on an applicant tracking system
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CompanyBoard {
+    /// The system hosting the board, one of [`CompanyBoard::SOURCES`]
+    pub source: JobSource,
+    /// The company's board token, e.g. "stripe" for boards.greenhouse.io/stripe
+    pub token: String,
+    /// Company name for the board's jobs; derived from the token when not set
+    pub company: Option<String>,
+}
+
+impl CompanyBoard {
+    /// Sources that host company job boards
+    pub const SOURCES: [JobSource; 5] = [
+        JobSource::Greenhouse,
+        JobSource::Lever,
+        JobSource::Ashby,
+        JobSource::Workable,
+        JobSource::SmartRecruiters,
+    ];
+
+    pub fn new(source: JobSource, token: impl Into<String>) -> Self {
+        Self { source, token: token.into(), company: None }
+    }
+
+    /// `token` as stored for `source`: board names are case-insensitive,
+    /// except for SmartRecruiters' mixed-case company identifiers
+    pub fn normalize_token(source: &JobSource, token: &str) -> String {
+        match source {
+            JobSource::SmartRecruiters => token.trim().to_string(),
+            _ => token.trim().to_lowercase(),
+        }
+    }
+
+    /// Company name for the board's jobs, e.g. "Acme Corp" for the token "acme-corp"
+    pub fn company_name(&self) -> String {
+        if let Some(company) = self.company.as_deref().filter(|c| !c.trim().is_empty()) {
+            return company.trim().to_string();
+        }
+        self.token
+            .split(['-', '_', '.'])
+            .filter(|word| !word.is_empty())
+            .map(|word| {
+                let mut chars = word.chars();
+                chars.next().map(|c| c.to_uppercase().chain(chars).collect()).unwrap_or_default()
+            })
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+}
+
+/// How fetching from one job source went
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SourceFetchReport {
+    /// Name of the source, e.g. "Remotive"
+    pub source: String,
+    /// Number of jobs fetched
+    pub jobs: u32,
+    pub duration_ms: u64,
+    /// Why the fetch failed or timed out; `None` on success
+    pub error: Option<String>,
+}
+
+/// A scheduled fetch of one job source
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FetchRun {
+    pub id: Uuid,
+    /// Name of the source, e.g. "Remotive"
+    pub source: String,
+    pub started_at: DateTime<Utc>,
+    /// `None` while the fetch is running
+    pub finished_at: Option<DateTime<Utc>>,
+    /// Number of jobs fetched
+    pub fetched: u32,
+    /// Number of fetched jobs stored
+    pub saved: u32,
+    /// Number of fetched jobs that failed to store
+    pub errors: u32,
+    /// Why the fetch failed or timed out; `None` on success
+    pub error: Option<String>,
+}
+
+impl FetchRun {
+    /// A run of `source` starting now
+    pub fn start(source: &str) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            source: source.to_string(),
+            started_at: Utc::now(),
+            finished_at: None,
+            fetched: 0,
+            saved: 0,
+            errors: 0,
+            error: None,
+        }
+    }
+}
+
+/// When a job source is fetched in the background
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SourceSchedule {
+    /// Name of the source, e.g. "Remotive"
+    pub source: String,
+    /// Time between scheduled fetches
+    pub interval_secs: u64,
+    /// The most recent scheduled fetch, if any
+    pub last_run: Option<FetchRun>,
+    pub next_run_at: DateTime<Utc>,
+}
+
+/// Ordering of job search results
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum JobSortOrder {
+    /// Best full-text match first; newest first when there are no keywords
+    #[default]
+    Relevance,
+    Newest,
+    Oldest,
+    /// Highest advertised salary first; jobs without a salary last
+    HighestSalary,
+    /// Best fit for the user's profile first; unscored jobs last
+    BestMatch,
+}
+
+/// A stored job together with what the user has done with it
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct JobListing {
+    pub job: Job,
+    /// How well the job fits the user's profile, 0 to 100; `None` until scored
+    pub match_score: Option<f64>,
+    pub is_saved: bool,
+    pub is_remote: bool,
+    /// Other postings of the same job, from other sources
+    pub other_sources: Vec<JobSourceLink>,
+}
+
+/// Where else a job is posted
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct JobSourceLink {
+    pub job_id: Uuid,
+    pub source: JobSource,
+    pub url: String,
+}
+
+impl From<Job> for JobListing {
+    fn from(job: Job) -> Self {
+        Self {
+            is_remote: job.is_remote(),
+            job,
+            match_score: None,
+            is_saved: false,
+            other_sources: Vec::new(),
+        }
+    }
+}
+
+/// A job returned from a search, with relevance information
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct JobSearchResult {
+    pub listing: JobListing,
+    /// BM25 relevance score (higher is more relevant); `None` when the
+    /// search had no keywords
+    pub relevance: Option<f64>,
+    /// Excerpt of the description around the matched terms
+    pub snippet: Vec<HighlightSpan>,
+}
+
+/// A piece of a search snippet, marked if it matched the query
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct HighlightSpan {
+    pub text: String,
+    pub highlighted: bool,
+}
+
+impl From<JobListing> for JobSearchResult {
+    fn from(listing: JobListing) -> Self {
+        Self {
+            listing,
+            relevance: None,
+            snippet: Vec::new(),
+        }
+    }
+}
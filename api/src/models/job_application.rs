@@ -2,7 +2,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct JobApplication {
     pub id: Uuid,
     pub user_id: Uuid,
@@ -17,7 +17,7 @@ pub struct JobApplication {
     pub updated_at: DateTime<Utc>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum ApplicationStatus {
     Draft,
     Applied,
@@ -25,4 +25,26 @@ pub enum ApplicationStatus {
     Offered,
     Rejected,
     Withdrawn,
+}
+
+impl ApplicationStatus {
+    /// All statuses, in the order an application usually moves through them
+    pub const ALL: [ApplicationStatus; 6] = [
+        ApplicationStatus::Draft,
+        ApplicationStatus::Applied,
+        ApplicationStatus::Interviewing,
+        ApplicationStatus::Offered,
+        ApplicationStatus::Rejected,
+        ApplicationStatus::Withdrawn,
+    ];
+}
+
+/// An entry in an application's status history
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ApplicationStatusChange {
+    pub id: Uuid,
+    pub application_id: Uuid,
+    pub status: ApplicationStatus,
+    pub notes: Option<String>,
+    pub created_at: DateTime<Utc>,
 }
\ No newline at end of file
@@ -6,17 +6,19 @@ pub mod skill;
 pub mod project;
 pub mod job;
 pub mod job_application;
+pub mod saved_search;
 pub mod resume;
 pub mod resume_analysis;
 
 // Re-export all models for easy importing
-pub use user::User;
+pub use user::{User, DEFAULT_USER_ID};
 pub use profile::{Profile, FullProfile};
 pub use experience::Experience;
 pub use education::Education;
-pub use skill::{Skill, SkillCategory, SkillLevel};
+pub use skill::{Skill, SkillCategory, SkillDefinition, SkillLevel};
 pub use project::Project;
-pub use job::{Job, JobSource, SalaryRange, SalaryPeriod};
-pub use job_application::{JobApplication, ApplicationStatus};
+pub use job::{Job, JobListing, JobSourceLink, JobSource, CompanyBoard, SourceFetchReport, FetchRun, SourceSchedule, SalaryRange, SalaryPeriod, JobSortOrder, JobSearchResult, HighlightSpan};
+pub use job_application::{JobApplication, ApplicationStatus, ApplicationStatusChange};
+pub use saved_search::SavedSearch;
 pub use resume::{Resume, ResumeTemplate, CustomSection};
 pub use resume_analysis::{ResumeAnalysis, Suggestion, SuggestionCategory, Priority};
\ No newline at end of file
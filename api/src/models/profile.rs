@@ -26,6 +26,7 @@ pub struct FullProfile {
     pub skills: Vec<crate::models::Skill>,
     pub experiences: Vec<crate::models::Experience>,
     pub education: Vec<crate::models::Education>,
+    pub projects: Vec<crate::models::Project>,
 }
 
 impl FullProfile {
@@ -84,6 +85,18 @@ impl FullProfile {
             }
         }
         
+        // Projects
+        if !self.projects.is_empty() {
+            summary.push_str("\nProjects:\n");
+            for project in &self.projects {
+                summary.push_str(&format!("- {}", project.name));
+                if !project.technologies.is_empty() {
+                    summary.push_str(&format!(" ({})", project.technologies.join(", ")));
+                }
+                summary.push_str(&format!("\n  {}\n", project.description));
+            }
+        }
+        
         summary
     }
 }
\ No newline at end of file
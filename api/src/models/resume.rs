@@ -27,6 +27,34 @@ pub enum ResumeTemplate {
     Academic,
 }
 
+impl ResumeTemplate {
+    /// Stable identifier for this template, as stored in the database
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ResumeTemplate::Professional => "professional",
+            ResumeTemplate::Modern => "modern",
+            ResumeTemplate::Creative => "creative",
+            ResumeTemplate::Simple => "simple",
+            ResumeTemplate::Academic => "academic",
+        }
+    }
+}
+
+impl From<&str> for ResumeTemplate {
+    /// Parse an identifier from [`ResumeTemplate::as_str`]; rows written before
+    /// identifiers were used hold the variant name instead, which is accepted
+    /// too. Anything else is the professional template.
+    fn from(id: &str) -> Self {
+        match id.to_ascii_lowercase().as_str() {
+            "modern" => ResumeTemplate::Modern,
+            "creative" => ResumeTemplate::Creative,
+            "simple" => ResumeTemplate::Simple,
+            "academic" => ResumeTemplate::Academic,
+            _ => ResumeTemplate::Professional,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CustomSection {
     pub title: String,
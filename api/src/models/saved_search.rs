@@ -0,0 +1,30 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A job search the user saved to be told about new matching jobs
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SavedSearch {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    /// Name shown in alerts, e.g. "Rust backend, Remote EU"
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+    /// When the search was last re-run against the stored jobs
+    pub last_checked_at: Option<DateTime<Utc>>,
+    /// Matching jobs found since the user last looked at the search
+    pub new_matches: u32,
+}
+
+impl SavedSearch {
+    pub fn new(user_id: Uuid, name: impl Into<String>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            user_id,
+            name: name.into(),
+            created_at: Utc::now(),
+            last_checked_at: None,
+            new_matches: 0,
+        }
+    }
+}
@@ -25,10 +25,39 @@ pub enum SkillCategory {
     Other,
 }
 
+impl SkillCategory {
+    /// Parse a category from its variant name, e.g. "Database"
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "Programming" => Some(SkillCategory::Programming),
+            "Framework" => Some(SkillCategory::Framework),
+            "Database" => Some(SkillCategory::Database),
+            "Tool" => Some(SkillCategory::Tool),
+            "Language" => Some(SkillCategory::Language),
+            "Soft" => Some(SkillCategory::Soft),
+            "Other" => Some(SkillCategory::Other),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum SkillLevel {
     Beginner,
     Intermediate,
     Advanced,
     Expert,
-}
\ No newline at end of file
+}
+
+/// A skill known to the skill taxonomy, with the other names it goes by
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SkillDefinition {
+    /// Canonical name, e.g. "Kubernetes"
+    pub name: String,
+    pub category: SkillCategory,
+    /// Other spellings, e.g. "k8s"
+    pub aliases: Vec<String>,
+    /// Only match the name with the capitalization given, for names that
+    /// are also ordinary words such as "Go"
+    pub case_sensitive: bool,
+}
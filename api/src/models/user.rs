@@ -2,6 +2,10 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+/// Until there is authentication every profile, application and saved
+/// search belongs to this user
+pub const DEFAULT_USER_ID: &str = "00000000-0000-0000-0000-000000000001";
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct User {
     pub id: Uuid,
@@ -0,0 +1,313 @@
+//! DOCX document reading
+//!
+//! A `.docx` file is a zip archive of WordprocessingML parts. This module
+//! reads the paragraphs of the page headers and the main document, and uses
+//! the style definitions to tell headings and list items apart from plain
+//! paragraphs, so the resume parser can rely on the document's own structure.
+
+use anyhow::{anyhow, Context, Result};
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use std::collections::HashMap;
+use std::io::{Cursor, Read};
+use zip::ZipArchive;
+
+use crate::services::resume_parser::{BlockKind, DocumentBlock};
+
+/// Read the paragraphs of a DOCX file in document order
+///
+/// Page headers come first, since resumes often keep the name and contact
+/// details there.
+pub fn read_blocks(docx_data: &[u8]) -> Result<Vec<DocumentBlock>> {
+    let mut archive = ZipArchive::new(Cursor::new(docx_data)).context("Not a valid DOCX file")?;
+
+    let styles = match read_part(&mut archive, "word/styles.xml")? {
+        Some(xml) => parse_styles(&xml)?,
+        None => HashMap::new(),
+    };
+
+    let mut header_parts: Vec<String> = archive
+        .file_names()
+        .filter(|name| name.starts_with("word/header") && name.ends_with(".xml"))
+        .map(str::to_string)
+        .collect();
+    header_parts.sort();
+
+    let mut blocks = Vec::new();
+    for part in header_parts {
+        if let Some(xml) = read_part(&mut archive, &part)? {
+            blocks.extend(parse_paragraphs(&xml, &styles)?);
+        }
+    }
+
+    let document = read_part(&mut archive, "word/document.xml")?
+        .ok_or_else(|| anyhow!("DOCX file has no word/document.xml"))?;
+    blocks.extend(parse_paragraphs(&document, &styles)?);
+
+    Ok(blocks)
+}
+
+fn read_part(archive: &mut ZipArchive<Cursor<&[u8]>>, name: &str) -> Result<Option<String>> {
+    let mut file = match archive.by_name(name) {
+        Ok(file) => file,
+        Err(zip::result::ZipError::FileNotFound) => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+    let mut xml = String::new();
+    file.read_to_string(&mut xml).with_context(|| format!("Could not read {}", name))?;
+    Ok(Some(xml))
+}
+
+/// What a paragraph style contributes to the paragraphs that use it
+#[derive(Debug, Default, Clone)]
+struct StyleInfo {
+    name: String,
+    based_on: Option<String>,
+    outline_level: Option<u8>,
+    is_list: bool,
+}
+
+/// Resolved role of a paragraph style
+#[derive(Debug, Default, Clone, Copy)]
+struct StyleRole {
+    heading: Option<u8>,
+    is_list: bool,
+}
+
+fn parse_styles(xml: &str) -> Result<HashMap<String, StyleRole>> {
+    let mut reader = Reader::from_str(xml);
+    let mut styles: HashMap<String, StyleInfo> = HashMap::new();
+    let mut current: Option<(String, StyleInfo)> = None;
+
+    loop {
+        match reader.read_event()? {
+            Event::Start(e) | Event::Empty(e) => match e.local_name().as_ref() {
+                b"style" => {
+                    let is_paragraph = attr(&e, b"type").is_none_or(|t| t == "paragraph");
+                    current = match attr(&e, b"styleId") {
+                        Some(id) if is_paragraph => Some((id, StyleInfo::default())),
+                        _ => None,
+                    };
+                }
+                b"name" => {
+                    if let (Some((_, info)), Some(name)) = (current.as_mut(), attr(&e, b"val")) {
+                        info.name = name;
+                    }
+                }
+                b"basedOn" => {
+                    if let Some((_, info)) = current.as_mut() {
+                        info.based_on = attr(&e, b"val");
+                    }
+                }
+                b"outlineLvl" => {
+                    if let Some((_, info)) = current.as_mut() {
+                        info.outline_level = attr(&e, b"val").and_then(|v| v.parse().ok());
+                    }
+                }
+                b"numId" => {
+                    if let Some((_, info)) = current.as_mut() {
+                        info.is_list = attr(&e, b"val").is_some_and(|v| v != "0");
+                    }
+                }
+                _ => {}
+            },
+            Event::End(e) if e.local_name().as_ref() == b"style" => {
+                if let Some((id, info)) = current.take() {
+                    styles.insert(id, info);
+                }
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    Ok(styles
+        .keys()
+        .map(|id| (id.clone(), resolve_style(&styles, id)))
+        .collect())
+}
+
+/// Work out whether a style is a heading or list style, following `basedOn`
+fn resolve_style(styles: &HashMap<String, StyleInfo>, id: &str) -> StyleRole {
+    let mut role = StyleRole::default();
+    let mut next = Some(id.to_string());
+
+    // Guard against cycles in malformed style sheets
+    for _ in 0..10 {
+        let Some(info) = next.as_ref().and_then(|id| styles.get(id)) else { break };
+
+        if role.heading.is_none() {
+            role.heading = heading_level(info);
+        }
+        role.is_list |= info.is_list;
+        next = info.based_on.clone();
+    }
+
+    role
+}
+
+fn heading_level(info: &StyleInfo) -> Option<u8> {
+    let name = info.name.to_lowercase();
+    if name == "title" {
+        return Some(0);
+    }
+    if let Some(level) = name.strip_prefix("heading ").and_then(|l| l.trim().parse::<u8>().ok()) {
+        return Some(level);
+    }
+    // Outline levels are zero-based; 9 means body text
+    info.outline_level.filter(|l| *l < 9).map(|l| l + 1)
+}
+
+/// A paragraph being read
+#[derive(Default)]
+struct ParagraphBuilder {
+    style: Option<String>,
+    outline_level: Option<u8>,
+    list_level: Option<u8>,
+    numbered: Option<bool>,
+    text: String,
+}
+
+impl ParagraphBuilder {
+    fn finish(self, styles: &HashMap<String, StyleRole>) -> Option<DocumentBlock> {
+        let text = self.text.split_whitespace().collect::<Vec<_>>().join(" ");
+        if text.is_empty() {
+            return None;
+        }
+
+        let role = self.style.as_ref().and_then(|s| styles.get(s)).copied().unwrap_or_default();
+        let heading = self.outline_level.filter(|l| *l < 9).map(|l| l + 1).or(role.heading);
+        // Direct numbering overrides the style; numId 0 removes list formatting
+        let is_list = self.numbered.unwrap_or(role.is_list);
+
+        // Numbered headings ("1. Experience") are still headings
+        let kind = if let Some(level) = heading {
+            BlockKind::Heading(level)
+        } else if is_list {
+            BlockKind::ListItem(self.list_level.unwrap_or(0))
+        } else {
+            BlockKind::Paragraph
+        };
+
+        Some(DocumentBlock { kind, text })
+    }
+}
+
+fn parse_paragraphs(xml: &str, styles: &HashMap<String, StyleRole>) -> Result<Vec<DocumentBlock>> {
+    let mut reader = Reader::from_str(xml);
+    let mut blocks = Vec::new();
+    // Paragraphs nest when a text box is anchored inside another paragraph
+    let mut stack: Vec<ParagraphBuilder> = Vec::new();
+    let mut in_text = false;
+
+    loop {
+        match reader.read_event()? {
+            Event::Start(e) => match e.local_name().as_ref() {
+                b"p" => stack.push(ParagraphBuilder::default()),
+                b"t" => in_text = true,
+                _ => apply_property(&e, stack.last_mut()),
+            },
+            Event::Empty(e) => match e.local_name().as_ref() {
+                b"tab" | b"br" | b"cr" => {
+                    if let Some(paragraph) = stack.last_mut() {
+                        paragraph.text.push(' ');
+                    }
+                }
+                // An empty paragraph has no text
+                b"p" => {}
+                _ => apply_property(&e, stack.last_mut()),
+            },
+            Event::Text(e) if in_text => {
+                if let Some(paragraph) = stack.last_mut() {
+                    paragraph.text.push_str(&e.unescape()?);
+                }
+            }
+            Event::End(e) => match e.local_name().as_ref() {
+                b"t" => in_text = false,
+                b"p" => {
+                    if let Some(block) = stack.pop().and_then(|p| p.finish(styles)) {
+                        blocks.push(block);
+                    }
+                }
+                _ => {}
+            },
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    Ok(blocks)
+}
+
+/// Record a paragraph property (style, list level, outline level)
+fn apply_property(e: &BytesStart, paragraph: Option<&mut ParagraphBuilder>) {
+    let Some(paragraph) = paragraph else { return };
+
+    match e.local_name().as_ref() {
+        b"pStyle" => paragraph.style = attr(e, b"val"),
+        b"outlineLvl" => paragraph.outline_level = attr(e, b"val").and_then(|v| v.parse().ok()),
+        b"ilvl" => paragraph.list_level = attr(e, b"val").and_then(|v| v.parse().ok()),
+        b"numId" => paragraph.numbered = Some(attr(e, b"val").is_some_and(|v| v != "0")),
+        _ => {}
+    }
+}
+
+/// Get an attribute by local name, ignoring its namespace prefix
+fn attr(e: &BytesStart, name: &[u8]) -> Option<String> {
+    e.attributes()
+        .flatten()
+        .find(|a| a.key.local_name().as_ref() == name)
+        .and_then(|a| a.unescape_value().ok().map(|v| v.into_owned()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture() -> Vec<DocumentBlock> {
+        read_blocks(include_bytes!("../../tests/fixtures/resume.docx")).unwrap()
+    }
+
+    #[test]
+    fn test_headers_come_first() {
+        let blocks = fixture();
+        assert_eq!(blocks[0].text, "maria.garcia@example.com | +1 (555) 222-3333 | Denver, CO");
+    }
+
+    #[test]
+    fn test_heading_styles() {
+        let blocks = fixture();
+        let find = |text: &str| blocks.iter().find(|b| b.text == text).unwrap().kind;
+
+        assert_eq!(find("Maria Garcia"), BlockKind::Heading(0));
+        // Custom style based on "heading 1"
+        assert_eq!(find("Work Experience"), BlockKind::Heading(1));
+        assert_eq!(find("Skills"), BlockKind::Heading(1));
+        // Direct outline level without a heading style
+        assert_eq!(find("Education"), BlockKind::Heading(1));
+    }
+
+    #[test]
+    fn test_list_items() {
+        let blocks = fixture();
+        let find = |text: &str| blocks.iter().find(|b| b.text.starts_with(text)).unwrap().kind;
+
+        // Direct numbering and a list style
+        assert_eq!(find("Cut deploy times"), BlockKind::ListItem(0));
+        assert_eq!(find("Mentored four"), BlockKind::ListItem(1));
+        assert_eq!(find("Designed the event"), BlockKind::ListItem(0));
+        // numId 0 removes list formatting inherited from the style
+        assert_eq!(find("Jan 2018 - Dec 2020"), BlockKind::Paragraph);
+    }
+
+    #[test]
+    fn test_text_runs_are_joined() {
+        let blocks = fixture();
+        assert!(blocks.iter().any(|b| b.text == "Cut deploy times from 40 to 5 minutes with Rust tooling"));
+    }
+
+    #[test]
+    fn test_invalid_docx_is_an_error() {
+        assert!(read_blocks(b"not a zip file").is_err());
+    }
+}
@@ -0,0 +1,384 @@
+//! Europass CV export
+//!
+//! Writes a profile as a Europass CV in the Europass XML format (XSD
+//! version 3.4, the `SkillsPassport` document), which can be imported into
+//! the Europass editor and other CV tools used across the EU.
+
+use chrono::{DateTime, Datelike, Utc};
+use quick_xml::events::{BytesDecl, BytesEnd, BytesStart, BytesText, Event};
+use quick_xml::Writer;
+
+use crate::models::{FullProfile, SkillCategory, SkillLevel};
+
+const EUROPASS_NAMESPACE: &str = "http://europass.cedefop.europa.eu/Europass";
+
+/// Write a profile as a Europass CV
+pub fn to_europass_xml(full: &FullProfile) -> String {
+    let profile = &full.profile;
+    let mut xml = XmlWriter::default();
+
+    xml.declaration();
+    xml.open("SkillsPassport", &[("xmlns", EUROPASS_NAMESPACE), ("locale", "en")]);
+
+    xml.open("DocumentInfo", &[]);
+    xml.element("DocumentType", "ECV");
+    xml.element("CreationDate", &profile.created_at.to_rfc3339());
+    xml.element("LastUpdateDate", &profile.updated_at.to_rfc3339());
+    xml.element("XSDVersion", "V3.4");
+    xml.element("Generator", "employment-barage");
+    xml.element("EuropassLogo", "false");
+    xml.close("DocumentInfo");
+
+    xml.open("LearnerInfo", &[]);
+
+    // Identification
+    let (first_name, surname) = split_name(&profile.name);
+    xml.open("Identification", &[]);
+    xml.open("PersonName", &[]);
+    xml.element("FirstName", first_name);
+    xml.element("Surname", surname);
+    xml.close("PersonName");
+    xml.open("ContactInfo", &[]);
+    if let Some(location) = &profile.location {
+        xml.open("Address", &[]);
+        xml.open("Contact", &[]);
+        xml.element("Municipality", location);
+        xml.close("Contact");
+        xml.close("Address");
+    }
+    xml.open("Email", &[]);
+    xml.element("Contact", &profile.email);
+    xml.close("Email");
+    if let Some(phone) = &profile.phone {
+        xml.open("TelephoneList", &[]);
+        xml.open("Telephone", &[]);
+        xml.element("Contact", phone);
+        xml.close("Telephone");
+        xml.close("TelephoneList");
+    }
+    let websites: Vec<(&str, &str)> = [
+        (&profile.portfolio_url, "personal"),
+        (&profile.linkedin_url, "business"),
+        (&profile.github_url, "business"),
+    ]
+    .into_iter()
+    .filter_map(|(url, kind)| url.as_deref().map(|u| (u, kind)))
+    .collect();
+    if !websites.is_empty() {
+        xml.open("WebsiteList", &[]);
+        for (url, kind) in websites {
+            xml.open("Website", &[]);
+            xml.element("Contact", url);
+            xml.open("Use", &[]);
+            xml.element("Code", kind);
+            xml.close("Use");
+            xml.close("Website");
+        }
+        xml.close("WebsiteList");
+    }
+    xml.close("ContactInfo");
+    xml.close("Identification");
+
+    if let Some(headline) = &profile.headline {
+        xml.open("Headline", &[]);
+        xml.open("Type", &[]);
+        xml.element("Code", "position");
+        xml.element("Label", "Desired employment / Occupational field");
+        xml.close("Type");
+        xml.open("Description", &[]);
+        xml.element("Label", headline);
+        xml.close("Description");
+        xml.close("Headline");
+    }
+
+    if !full.experiences.is_empty() {
+        xml.open("WorkExperienceList", &[]);
+        for exp in &full.experiences {
+            xml.open("WorkExperience", &[]);
+            write_period(&mut xml, &exp.start_date, exp.end_date.as_ref(), exp.current);
+            xml.open("Position", &[]);
+            xml.element("Label", &exp.position);
+            xml.close("Position");
+            let activities = rich_text(&exp.description, &exp.achievements);
+            if !activities.is_empty() {
+                xml.element("Activities", &activities);
+            }
+            xml.open("Employer", &[]);
+            xml.element("Name", &exp.company);
+            if let Some(location) = &exp.location {
+                xml.open("ContactInfo", &[]);
+                xml.open("Address", &[]);
+                xml.open("Contact", &[]);
+                xml.element("Municipality", location);
+                xml.close("Contact");
+                xml.close("Address");
+                xml.close("ContactInfo");
+            }
+            xml.close("Employer");
+            xml.close("WorkExperience");
+        }
+        xml.close("WorkExperienceList");
+    }
+
+    if !full.education.is_empty() {
+        xml.open("EducationList", &[]);
+        for edu in &full.education {
+            xml.open("Education", &[]);
+            write_period(&mut xml, &edu.start_date, edu.end_date.as_ref(), false);
+            let title = match (edu.degree.is_empty(), edu.field.is_empty()) {
+                (false, false) => format!("{} in {}", edu.degree, edu.field),
+                (false, true) => edu.degree.clone(),
+                _ => edu.field.clone(),
+            };
+            xml.element("Title", &title);
+            let mut details = Vec::new();
+            if let Some(gpa) = edu.gpa {
+                details.push(format!("GPA {}", gpa));
+            }
+            details.extend(edu.honors.iter().cloned());
+            let activities = rich_text("", &details);
+            if !activities.is_empty() {
+                xml.element("Activities", &activities);
+            }
+            xml.open("Organisation", &[]);
+            xml.element("Name", &edu.institution);
+            xml.close("Organisation");
+            xml.close("Education");
+        }
+        xml.close("EducationList");
+    }
+
+    write_skills(&mut xml, full);
+
+    if !full.projects.is_empty() {
+        xml.open("AchievementList", &[]);
+        for project in &full.projects {
+            xml.open("Achievement", &[]);
+            xml.open("Title", &[]);
+            xml.element("Code", "projects");
+            xml.element("Label", "Projects");
+            xml.close("Title");
+            let mut description = format!("<p><strong>{}</strong></p>", escape(&project.name));
+            description.push_str(&rich_text(&project.description, &project.highlights));
+            if !project.technologies.is_empty() {
+                description.push_str(&format!("<p>{}</p>", escape(&project.technologies.join(", "))));
+            }
+            if let Some(url) = project.url.as_ref().or(project.github_url.as_ref()) {
+                description.push_str(&format!("<p>{}</p>", escape(url)));
+            }
+            xml.element("Description", &description);
+            xml.close("Achievement");
+        }
+        xml.close("AchievementList");
+    }
+
+    xml.close("LearnerInfo");
+    xml.close("SkillsPassport");
+    xml.finish()
+}
+
+/// Europass groups skills into languages and a few free-text categories
+fn write_skills(xml: &mut XmlWriter, full: &FullProfile) {
+    let names = |categories: &[SkillCategory]| {
+        full.skills
+            .iter()
+            .filter(|s| categories.contains(&s.category))
+            .map(|s| s.name.as_str())
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    let computer = names(&[
+        SkillCategory::Programming,
+        SkillCategory::Framework,
+        SkillCategory::Database,
+        SkillCategory::Tool,
+    ]);
+    let communication = names(&[SkillCategory::Soft]);
+    let other = names(&[SkillCategory::Other]);
+    let languages: Vec<_> = full.skills.iter().filter(|s| s.category == SkillCategory::Language).collect();
+
+    if computer.is_empty() && communication.is_empty() && other.is_empty() && languages.is_empty() {
+        return;
+    }
+
+    xml.open("Skills", &[]);
+    if !languages.is_empty() {
+        xml.open("Linguistic", &[]);
+        xml.open("ForeignLanguageList", &[]);
+        for language in languages {
+            xml.open("ForeignLanguage", &[]);
+            xml.open("Description", &[]);
+            xml.element("Label", &language.name);
+            xml.close("Description");
+            // Europass rates languages on the CEFR scale
+            let level = cefr_level(&language.proficiency);
+            xml.open("ProficiencyLevel", &[]);
+            for skill in ["Listening", "Reading", "SpokenInteraction", "SpokenProduction", "Writing"] {
+                xml.element(skill, level);
+            }
+            xml.close("ProficiencyLevel");
+            xml.close("ForeignLanguage");
+        }
+        xml.close("ForeignLanguageList");
+        xml.close("Linguistic");
+    }
+    for (element, skills) in [("Communication", communication), ("Computer", computer), ("Other", other)] {
+        if !skills.is_empty() {
+            xml.open(element, &[]);
+            xml.element("Description", &format!("<p>{}</p>", escape(&skills)));
+            xml.close(element);
+        }
+    }
+    xml.close("Skills");
+}
+
+fn write_period(xml: &mut XmlWriter, start: &DateTime<Utc>, end: Option<&DateTime<Utc>>, current: bool) {
+    xml.open("Period", &[]);
+    xml.empty("From", &[("year", &start.year().to_string()), ("month", &format!("--{:02}", start.month()))]);
+    match end {
+        Some(end) if !current => {
+            xml.empty("To", &[("year", &end.year().to_string()), ("month", &format!("--{:02}", end.month()))]);
+        }
+        _ if current => xml.element("Current", "true"),
+        _ => {}
+    }
+    xml.close("Period");
+}
+
+/// Europass descriptions are escaped HTML: a paragraph and a bullet list
+fn rich_text(paragraph: &str, items: &[String]) -> String {
+    let mut html = String::new();
+    if !paragraph.trim().is_empty() {
+        html.push_str(&format!("<p>{}</p>", escape(paragraph.trim())));
+    }
+    if !items.is_empty() {
+        html.push_str("<ul>");
+        for item in items {
+            html.push_str(&format!("<li>{}</li>", escape(item)));
+        }
+        html.push_str("</ul>");
+    }
+    html
+}
+
+/// Split a full name into first name(s) and surname
+fn split_name(name: &str) -> (&str, &str) {
+    let name = name.trim();
+    match name.rsplit_once(' ') {
+        Some((first, last)) => (first.trim(), last),
+        None => (name, ""),
+    }
+}
+
+fn cefr_level(level: &SkillLevel) -> &'static str {
+    match level {
+        SkillLevel::Beginner => "A2",
+        SkillLevel::Intermediate => "B1",
+        SkillLevel::Advanced => "C1",
+        SkillLevel::Expert => "C2",
+    }
+}
+
+/// Escape text for use inside XML, or inside the HTML of a description
+fn escape(text: &str) -> String {
+    quick_xml::escape::escape(text).into_owned()
+}
+
+/// Indenting XML writer over [`quick_xml::Writer`]
+struct XmlWriter {
+    writer: Writer<Vec<u8>>,
+}
+
+impl Default for XmlWriter {
+    fn default() -> Self {
+        Self { writer: Writer::new_with_indent(Vec::new(), b' ', 2) }
+    }
+}
+
+impl XmlWriter {
+    fn write(&mut self, event: Event) {
+        // Writing to a Vec cannot fail
+        self.writer.write_event(event).expect("XML is written to memory");
+    }
+
+    fn declaration(&mut self) {
+        self.write(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)));
+    }
+
+    fn open(&mut self, name: &str, attrs: &[(&str, &str)]) {
+        self.write(Event::Start(BytesStart::new(name).with_attributes(attrs.iter().copied())));
+    }
+
+    fn close(&mut self, name: &str) {
+        self.write(Event::End(BytesEnd::new(name)));
+    }
+
+    fn empty(&mut self, name: &str, attrs: &[(&str, &str)]) {
+        self.write(Event::Empty(BytesStart::new(name).with_attributes(attrs.iter().copied())));
+    }
+
+    fn element(&mut self, name: &str, text: &str) {
+        self.open(name, &[]);
+        self.write(Event::Text(BytesText::new(text)));
+        self.close(name);
+    }
+
+    fn finish(self) -> String {
+        let mut xml = String::from_utf8(self.writer.into_inner()).expect("XML is written as UTF-8");
+        xml.push('\n');
+        xml
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::json_resume::JsonResume;
+    use crate::services::skill_taxonomy::SkillTaxonomy;
+
+    fn fixture() -> FullProfile {
+        let resume: JsonResume = serde_json::from_str(include_str!("../../tests/fixtures/resume.json")).unwrap();
+        resume.to_parsed_resume(&SkillTaxonomy::builtin()).unwrap().into_full_profile()
+    }
+
+    #[test]
+    fn test_document_is_well_formed() {
+        let xml = to_europass_xml(&fixture());
+        let mut reader = quick_xml::Reader::from_str(&xml);
+        loop {
+            match reader.read_event() {
+                Ok(quick_xml::events::Event::Eof) => break,
+                Ok(_) => {}
+                Err(e) => panic!("invalid XML: {}", e),
+            }
+        }
+    }
+
+    #[test]
+    fn test_identification_and_work() {
+        let xml = to_europass_xml(&fixture());
+        assert!(xml.contains("<FirstName>Sam</FirstName>"));
+        assert!(xml.contains("<Surname>Rivera</Surname>"));
+        assert!(xml.contains("<Contact>sam.rivera@example.com</Contact>"));
+        assert!(xml.contains(r#"<From year="2021" month="--03"/>"#));
+        assert!(xml.contains("<Current>true</Current>"));
+        assert!(xml.contains(r#"<To year="2021" month="--02"/>"#));
+        // Descriptions are HTML, escaped inside the XML
+        assert!(xml.contains("&lt;li&gt;Reduced paging volume by 60%&lt;/li&gt;"));
+    }
+
+    #[test]
+    fn test_skills_and_languages() {
+        let xml = to_europass_xml(&fixture());
+        assert!(xml.contains("<Label>Spanish</Label>"));
+        assert!(xml.contains("<Listening>C2</Listening>"));
+        assert!(xml.contains("Kubernetes"));
+        assert!(xml.contains("<Title>Bachelor in Computer Engineering</Title>"));
+    }
+
+    #[test]
+    fn test_split_name() {
+        assert_eq!(split_name("Mary Ann Smith"), ("Mary Ann", "Smith"));
+        assert_eq!(split_name("Cher"), ("Cher", ""));
+    }
+}
@@ -0,0 +1,110 @@
+//! HTML document reading
+//!
+//! Turns an HTML resume (for example one saved from a personal site or
+//! exported from a word processor) into headings, list items and paragraphs
+//! for the resume parser. Scripts, styles and the document head are skipped.
+
+use scraper::{ElementRef, Html, Node};
+
+use crate::services::resume_parser::{BlockKind, BlockWriter, DocumentBlock};
+
+/// Elements whose content is never shown as text
+const SKIPPED_ELEMENTS: [&str; 6] = ["head", "script", "style", "noscript", "template", "svg"];
+
+/// Elements that start a new block of text
+const BLOCK_ELEMENTS: [&str; 25] = [
+    "html", "body", "main", "article", "section", "header", "footer", "aside", "nav", "div", "p",
+    "blockquote", "pre", "address", "figure", "figcaption", "table", "thead", "tbody", "tr", "dl",
+    "dt", "dd", "ul", "ol",
+];
+
+/// Read the blocks of an HTML document in order
+pub fn read_blocks(html: &str) -> Vec<DocumentBlock> {
+    let document = Html::parse_document(html);
+    let mut writer = BlockWriter::default();
+    visit(document.root_element(), &mut writer, 0);
+    writer.finish()
+}
+
+fn visit(element: ElementRef, writer: &mut BlockWriter, list_depth: u8) {
+    let name = element.value().name();
+    if SKIPPED_ELEMENTS.contains(&name) {
+        return;
+    }
+
+    let kind = match name {
+        "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => Some(BlockKind::Heading(name[1..].parse().unwrap_or(1))),
+        "li" => Some(BlockKind::ListItem(list_depth.saturating_sub(1))),
+        // Table cells of a row are read as one line
+        "td" | "th" | "br" => {
+            writer.push_text(" ");
+            None
+        }
+        _ if BLOCK_ELEMENTS.contains(&name) => {
+            // Text directly inside a list item's nested blocks still belongs to the item
+            match writer.current() {
+                Some(item @ BlockKind::ListItem(_)) if name != "ul" && name != "ol" => Some(item),
+                _ => Some(BlockKind::Paragraph),
+            }
+        }
+        _ => None,
+    };
+
+    let list_depth = if name == "ul" || name == "ol" { list_depth + 1 } else { list_depth };
+
+    if let Some(kind) = kind {
+        writer.open(kind);
+    }
+    for child in element.children() {
+        match child.value() {
+            Node::Text(text) => writer.push_text(text),
+            Node::Element(_) => {
+                if let Some(child) = ElementRef::wrap(child) {
+                    visit(child, writer, list_depth);
+                }
+            }
+            _ => {}
+        }
+    }
+    if kind.is_some() {
+        writer.close();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture() -> Vec<DocumentBlock> {
+        read_blocks(include_str!("../../tests/fixtures/resume.html"))
+    }
+
+    #[test]
+    fn test_headings_and_paragraphs() {
+        let blocks = fixture();
+        assert_eq!(blocks[0], DocumentBlock { kind: BlockKind::Heading(1), text: "Priya Patel".to_string() });
+        assert!(blocks.contains(&DocumentBlock { kind: BlockKind::Heading(2), text: "Experience".to_string() }));
+        assert!(blocks.iter().any(|b| b.kind == BlockKind::Paragraph && b.text.contains("priya.patel@example.com")));
+    }
+
+    #[test]
+    fn test_head_and_scripts_are_skipped() {
+        let blocks = fixture();
+        assert!(!blocks.iter().any(|b| b.text.contains("Resume of Priya") || b.text.contains("analytics")));
+    }
+
+    #[test]
+    fn test_nested_lists() {
+        let blocks = read_blocks(
+            "<ul><li>Shipped <b>search</b> v2<ul><li>Halved p99 latency</li></ul></li><li><p>Ran on-call</p></li></ul>",
+        );
+        assert_eq!(
+            blocks,
+            vec![
+                DocumentBlock { kind: BlockKind::ListItem(0), text: "Shipped search v2".to_string() },
+                DocumentBlock { kind: BlockKind::ListItem(1), text: "Halved p99 latency".to_string() },
+                DocumentBlock { kind: BlockKind::ListItem(0), text: "Ran on-call".to_string() },
+            ]
+        );
+    }
+}
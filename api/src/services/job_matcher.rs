@@ -0,0 +1,309 @@
+//! Rule-based scoring of how well a job fits a profile
+//!
+//! Unlike `ai_service::analyze_job_match` this needs no
+//! LLM, so it runs on every fetched job. The score adds up three parts: the
+//! share of the job's skills the profile has, years of experience against
+//! any "N+ years" the posting asks for, and whether the job is remote or
+//! near the profile's location. Skills on both sides are mapped through the
+//! skill taxonomy, so "Golang" in a posting matches "Go" in the profile.
+
+use std::collections::HashSet;
+use std::sync::OnceLock;
+
+use chrono::{DateTime, Utc};
+use dioxus::prelude::*;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::models::*;
+use crate::services::skill_taxonomy::SkillTaxonomy;
+
+/// Points for having every skill the job mentions
+const SKILL_WEIGHT: f64 = 70.0;
+/// Points for meeting the years of experience the job asks for
+const EXPERIENCE_WEIGHT: f64 = 15.0;
+/// Points for a remote job or one where the profile is located
+const LOCATION_WEIGHT: f64 = 15.0;
+
+/// How well a job fits a profile
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct JobMatch {
+    /// Overall fit from 0 to 100
+    pub score: u8,
+    /// Skills the job mentions that are in the profile
+    pub matched_skills: Vec<String>,
+    /// Skills the job mentions that are not in the profile
+    pub missing_skills: Vec<String>,
+    /// One sentence per part of the score, for display
+    pub explanation: Vec<String>,
+}
+
+/// Score how well `job` fits `profile`
+pub fn score_job(profile: &FullProfile, job: &Job, taxonomy: &SkillTaxonomy) -> JobMatch {
+    let mut explanation = Vec::new();
+
+    let (skill_fit, matched_skills, missing_skills) = skill_fit(profile, job, taxonomy);
+    if matched_skills.is_empty() && missing_skills.is_empty() {
+        explanation.push("The posting names no skills to compare".to_string());
+    } else {
+        explanation.push(format!(
+            "Matches {} of {} skills",
+            matched_skills.len(),
+            matched_skills.len() + missing_skills.len()
+        ));
+    }
+
+    let have_years = years_of_experience(&profile.experiences, Utc::now());
+    let experience_fit = match required_years(&job.description) {
+        None => {
+            explanation.push("No experience requirement stated".to_string());
+            1.0
+        }
+        Some(required) => {
+            explanation.push(format!(
+                "Asks for {} years of experience; you have {:.0}",
+                required, have_years
+            ));
+            (have_years / required as f64).min(1.0)
+        }
+    };
+
+    let (location_fit, location_note) = location_fit(profile.profile.location.as_deref(), job);
+    explanation.push(location_note);
+
+    let score = skill_fit * SKILL_WEIGHT + experience_fit * EXPERIENCE_WEIGHT + location_fit * LOCATION_WEIGHT;
+
+    JobMatch {
+        score: score.round().clamp(0.0, 100.0) as u8,
+        matched_skills,
+        missing_skills,
+        explanation,
+    }
+}
+
+/// Share of the job's skills the profile has, with the skills split into
+/// matched and missing
+///
+/// Job skills are its requirements plus skills named in the title and
+/// description. A posting without any gets half marks rather than none.
+fn skill_fit(profile: &FullProfile, job: &Job, taxonomy: &SkillTaxonomy) -> (f64, Vec<String>, Vec<String>) {
+    let have: HashSet<String> = profile
+        .skills
+        .iter()
+        .map(|skill| taxonomy.canonical_name(&skill.name).to_lowercase())
+        .collect();
+
+    let mut wanted = job.requirements.clone();
+    wanted.extend(taxonomy.skill_names(&format!("{}\n{}", job.title, job.description)));
+    let wanted = taxonomy.normalize(&wanted);
+
+    let (matched, missing): (Vec<String>, Vec<String>) = wanted
+        .into_iter()
+        .partition(|skill| have.contains(&skill.to_lowercase()));
+
+    let total = matched.len() + missing.len();
+    let fit = if total == 0 { 0.5 } else { matched.len() as f64 / total as f64 };
+    (fit, matched, missing)
+}
+
+/// Total years worked, counting overlapping jobs once
+fn years_of_experience(experiences: &[Experience], now: DateTime<Utc>) -> f64 {
+    let mut periods: Vec<(DateTime<Utc>, DateTime<Utc>)> = experiences
+        .iter()
+        .map(|e| {
+            let end = if e.current { now } else { e.end_date.unwrap_or(now) };
+            (e.start_date, end.max(e.start_date))
+        })
+        .collect();
+    periods.sort();
+
+    let mut days = 0;
+    let mut covered_until: Option<DateTime<Utc>> = None;
+    for (start, end) in periods {
+        let start = covered_until.map_or(start, |until| start.max(until));
+        if end > start {
+            days += (end - start).num_days();
+            covered_until = Some(end);
+        }
+    }
+    days as f64 / 365.25
+}
+
+/// The years of experience a posting asks for, e.g. 5 for "5+ years of
+/// experience" or 3 for "3-5 years of professional experience"
+///
+/// When several are mentioned the highest is taken.
+fn required_years(description: &str) -> Option<u32> {
+    static YEARS: OnceLock<Regex> = OnceLock::new();
+    let years = YEARS.get_or_init(|| {
+        Regex::new(r"(?i)\b(\d{1,2})\s*\+?\s*(?:(?:-|–|to)\s*\d{1,2}\s*\+?\s*)?years?\b[^.\n]{0,40}?\bexperience")
+            .unwrap()
+    });
+
+    years
+        .captures_iter(description)
+        .filter_map(|c| c[1].parse::<u32>().ok())
+        .filter(|&years| (1..=30).contains(&years))
+        .max()
+}
+
+/// How well the job's location suits the profile, with a note explaining it
+///
+/// Remote jobs and jobs in the profile's city fit fully, jobs elsewhere in
+/// the same region or country half.
+fn location_fit(home: Option<&str>, job: &Job) -> (f64, String) {
+    if job.is_remote() {
+        return (1.0, "Remote position".to_string());
+    }
+    let Some(home) = home.map(str::trim).filter(|home| !home.is_empty()) else {
+        return (0.5, "Add a location to your profile to score on-site jobs".to_string());
+    };
+
+    let job_location = job.location.to_lowercase();
+    let mut parts = home
+        .split(',')
+        .map(|part| part.trim().to_lowercase())
+        .filter(|part| !part.is_empty());
+
+    if parts.next().is_some_and(|city| job_location.contains(&city)) {
+        (1.0, format!("Located in {}", job.location))
+    } else if parts.any(|region| job_location.contains(&region)) {
+        (0.5, format!("In {}, near {}", job.location, home))
+    } else {
+        (0.0, format!("On-site in {}, away from {}", job.location, home))
+    }
+}
+
+/// Score `jobs` against the most recently updated profile and store the scores
+///
+/// Returns how many jobs were scored; none are when there is no profile yet.
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn update_match_scores(db: &crate::db::Database, jobs: &[Job]) -> anyhow::Result<usize> {
+    use crate::db::{JobRepository, ProfileRepository, SqliteJobRepository, SqliteProfileRepository};
+    use crate::services::profile_service::load_full_profile;
+    use crate::services::skill_taxonomy::load_skill_taxonomy;
+
+    let Some(profile) = SqliteProfileRepository::new(db.clone()).get_latest().await? else {
+        return Ok(0);
+    };
+    let Some(profile) = load_full_profile(db, &profile.user_id).await? else {
+        return Ok(0);
+    };
+    let taxonomy = load_skill_taxonomy(db).await?;
+
+    let repo = SqliteJobRepository::new(db.clone());
+    for job in jobs {
+        let job_match = score_job(&profile, job, &taxonomy);
+        repo.update_match_score(&job.id, job_match.score as f64).await?;
+    }
+    Ok(jobs.len())
+}
+
+/// Explain how well a job fits the user's profile
+///
+/// Returns `None` when the user has no profile yet.
+#[server(GetJobMatch)]
+pub async fn get_job_match(user_id: String, job_id: String) -> Result<Option<JobMatch>, ServerFnError> {
+    use crate::db::{get_database, JobRepository, SqliteJobRepository};
+    use crate::services::skill_taxonomy::load_skill_taxonomy;
+
+    let job_uuid = uuid::Uuid::parse_str(&job_id)
+        .map_err(|e| ServerFnError::new(format!("Invalid job ID: {}", e)))?;
+
+    let db = get_database();
+    let job = SqliteJobRepository::new(db.clone())
+        .get_by_id(&job_uuid)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?
+        .ok_or_else(|| ServerFnError::new("Job not found".to_string()))?;
+    let Some(profile) = crate::get_full_profile(user_id).await? else {
+        return Ok(None);
+    };
+    let taxonomy = load_skill_taxonomy(db)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+
+    Ok(Some(score_job(&profile, &job, &taxonomy)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{date, experience, full_profile, job, skill};
+
+    fn candidate(location: Option<&str>, skills: &[&str], experiences: Vec<Experience>) -> FullProfile {
+        let mut candidate = full_profile(
+            skills.iter().map(|name| skill(name, SkillLevel::Advanced)).collect(),
+            experiences,
+        );
+        candidate.profile.location = location.map(str::to_string);
+        candidate
+    }
+
+    fn posting(location: &str, description: &str, requirements: &[&str]) -> Job {
+        Job {
+            description: description.to_string(),
+            requirements: requirements.iter().map(|r| r.to_string()).collect(),
+            ..job("Backend Engineer", "Acme", location, JobSource::Remotive)
+        }
+    }
+
+    #[test]
+    fn test_perfect_match() {
+        let taxonomy = SkillTaxonomy::builtin();
+        let profile = candidate(
+            Some("Berlin, Germany"),
+            &["Rust", "PostgreSQL"],
+            vec![experience("Acme", date(2015, 1), Some(date(2022, 1)))],
+        );
+        let job = posting("Remote", "We want 5+ years of experience with Rust and Postgres.", &[]);
+
+        let result = score_job(&profile, &job, &taxonomy);
+        assert_eq!(result.score, 100);
+        assert_eq!(result.matched_skills, vec!["Rust", "PostgreSQL"]);
+        assert!(result.missing_skills.is_empty());
+    }
+
+    #[test]
+    fn test_skills_are_matched_through_aliases() {
+        let taxonomy = SkillTaxonomy::builtin();
+        let profile = candidate(Some("Berlin"), &["golang"], Vec::new());
+        let job = posting("Berlin", "Services written in Go and deployed with Docker", &["Go"]);
+
+        let result = score_job(&profile, &job, &taxonomy);
+        assert_eq!(result.matched_skills, vec!["Go"]);
+        assert_eq!(result.missing_skills, vec!["Docker"]);
+        // Half the skills, no experience requirement, same city
+        assert_eq!(result.score, 65);
+        assert_eq!(result.explanation[0], "Matches 1 of 2 skills");
+    }
+
+    #[test]
+    fn test_experience_shortfall_and_location() {
+        let taxonomy = SkillTaxonomy::builtin();
+        let profile = candidate(
+            Some("Munich, Germany"),
+            &["Python"],
+            vec![experience("Acme", date(2020, 1), Some(date(2022, 1))), experience("Acme", date(2021, 1), Some(date(2023, 1)))],
+        );
+
+        let berlin = posting("Berlin, Germany", "Python. 3-5 years of professional experience required.", &[]);
+        let result = score_job(&profile, &berlin, &taxonomy);
+        // Overlapping jobs count once: 3 of 3 years, half marks for the same country
+        assert_eq!(result.score, 70 + 15 + 8);
+
+        let paris = posting("Paris, France", "Python, at least 6 years experience", &[]);
+        let result = score_job(&profile, &paris, &taxonomy);
+        assert_eq!(result.score, 70 + 8);
+        assert_eq!(result.explanation[2], "On-site in Paris, France, away from Munich, Germany");
+    }
+
+    #[test]
+    fn test_required_years() {
+        assert_eq!(required_years("5+ years of experience in backend development"), Some(5));
+        assert_eq!(required_years("3 - 5 years professional Rust experience"), Some(3));
+        assert_eq!(required_years("2 years experience with AWS. 4+ years of experience overall"), Some(4));
+        assert_eq!(required_years("Founded 20 years ago. Great experience guaranteed"), None);
+        assert_eq!(required_years("No requirements"), None);
+    }
+}
@@ -0,0 +1,198 @@
+//! Background fetching of job sources
+//!
+//! The scheduler wakes up every [`SCHEDULER_TICK`] and fetches each source
+//! whose `fetch_interval` has passed since its last run. Every run is
+//! recorded in the `fetch_runs` table, so the schedule carries over server
+//! restarts and a source is never fetched more often than its interval
+//! allows.
+
+use crate::db::{Database, FetchRunRepository, SqliteFetchRunRepository};
+use crate::models::{FetchRun, SourceSchedule};
+use crate::services::job_service::store_fetched_jobs;
+use crate::services::job_sources::{load_job_aggregator, JobAggregator};
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+/// How often the scheduler checks for sources that are due
+pub const SCHEDULER_TICK: Duration = Duration::from_secs(60);
+
+/// Start the scheduler on a thread of its own
+///
+/// The thread runs its own async runtime, so the scheduler does not depend
+/// on the web server's runtime and can be started before it.
+pub fn start_job_scheduler(db: Database) -> std::io::Result<JoinHandle<()>> {
+    std::thread::Builder::new()
+        .name("job-scheduler".to_string())
+        .spawn(move || {
+            let rt = match tokio::runtime::Builder::new_current_thread().enable_all().build() {
+                Ok(rt) => rt,
+                Err(e) => {
+                    tracing::error!("Failed to start job scheduler: {}", e);
+                    return;
+                }
+            };
+            rt.block_on(run_job_scheduler(db));
+        })
+}
+
+/// Fetch due sources every [`SCHEDULER_TICK`], forever
+pub async fn run_job_scheduler(db: Database) {
+    let mut aggregator = JobAggregator::new();
+    let mut tick = tokio::time::interval(SCHEDULER_TICK);
+    tick.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+    loop {
+        tick.tick().await;
+
+        // Reloaded every round so the user's custom skills and company
+        // boards are picked up
+        match load_job_aggregator(&db).await {
+            Ok(loaded) => aggregator = loaded,
+            Err(e) => tracing::warn!("Failed to load job sources: {}", e),
+        }
+
+        match run_due_sources(&db, &aggregator, Utc::now()).await {
+            Ok(runs) => {
+                for run in runs {
+                    match &run.error {
+                        Some(error) => tracing::warn!("Scheduled fetch from {} failed: {}", run.source, error),
+                        None => tracing::info!(
+                            "Scheduled fetch from {}: {} fetched, {} saved",
+                            run.source, run.fetched, run.saved
+                        ),
+                    }
+                }
+            }
+            Err(e) => tracing::warn!("Scheduled job fetching failed: {}", e),
+        }
+    }
+}
+
+/// When each of the aggregator's sources was last fetched and is next due
+///
+/// Sources that have never been fetched are due at `now`.
+pub async fn source_schedules(
+    db: &Database,
+    aggregator: &JobAggregator,
+    now: DateTime<Utc>,
+) -> Result<Vec<SourceSchedule>> {
+    let repo = SqliteFetchRunRepository::new(db.clone());
+
+    let mut schedules = Vec::new();
+    for source in aggregator.sources() {
+        let last_run = repo.get_latest_by_source(source.source_name()).await?;
+        schedules.push(SourceSchedule {
+            source: source.source_name().to_string(),
+            interval_secs: source.fetch_interval().as_secs(),
+            next_run_at: last_run.as_ref().map_or(now, |run| {
+                run.started_at + chrono::Duration::seconds(source.fetch_interval().as_secs() as i64)
+            }),
+            last_run,
+        });
+    }
+    Ok(schedules)
+}
+
+/// Fetch every source that is due at `now` and record the runs
+///
+/// Due sources are fetched at the same time; the finished runs are returned.
+pub async fn run_due_sources(
+    db: &Database,
+    aggregator: &JobAggregator,
+    now: DateTime<Utc>,
+) -> Result<Vec<FetchRun>> {
+    let due: Vec<String> = source_schedules(db, aggregator, now)
+        .await?
+        .into_iter()
+        .filter(|schedule| schedule.next_run_at <= now)
+        .map(|schedule| schedule.source)
+        .collect();
+
+    let runs = due.iter().map(|source| run_source(db, aggregator, source));
+    futures::future::join_all(runs).await.into_iter().collect()
+}
+
+/// Fetch and store the jobs of one source, recording the run
+async fn run_source(db: &Database, aggregator: &JobAggregator, source: &str) -> Result<FetchRun> {
+    let repo = SqliteFetchRunRepository::new(db.clone());
+    let mut run = repo.create(&FetchRun::start(source)).await?;
+
+    let fetched = aggregator.fetch_from(source, None, None, None).await;
+    let result = store_fetched_jobs(db, fetched).await;
+
+    run.fetched = result.fetched;
+    run.saved = result.saved;
+    run.errors = result.errors;
+    run.error = result.sources.into_iter().find_map(|report| report.error);
+    repo.finish(&run).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Job, JobSource};
+    use crate::services::job_sources::JobSourceProvider;
+    use crate::test_fixtures::job;
+    use async_trait::async_trait;
+
+    /// A source fetched every `interval` that returns one job, or fails
+    struct FakeSource {
+        name: &'static str,
+        interval: Duration,
+        fails: bool,
+    }
+
+    #[async_trait]
+    impl JobSourceProvider for FakeSource {
+        async fn fetch_jobs(&self, _: Option<&str>, _: Option<&str>, _: Option<u32>) -> Result<Vec<Job>> {
+            if self.fails {
+                anyhow::bail!("Service unavailable");
+            }
+            Ok(vec![job(&format!("{} job", self.name), "Acme", "Remote", JobSource::Other(self.name.to_string()))])
+        }
+
+        fn source_name(&self) -> &'static str {
+            self.name
+        }
+
+        fn fetch_interval(&self) -> Duration {
+            self.interval
+        }
+    }
+
+    fn sources(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[tokio::test]
+    async fn test_sources_run_on_their_own_cadence() {
+        let db = Database::in_memory().await.unwrap();
+        let aggregator = JobAggregator::new().with_sources(vec![
+            Box::new(FakeSource { name: "hourly", interval: Duration::from_secs(60 * 60), fails: false }),
+            Box::new(FakeSource { name: "daily", interval: Duration::from_secs(24 * 60 * 60), fails: true }),
+        ]);
+
+        let now = Utc::now();
+        let runs = run_due_sources(&db, &aggregator, now).await.unwrap();
+        let ran: Vec<String> = runs.iter().map(|run| run.source.clone()).collect();
+        assert_eq!(ran, sources(&["hourly", "daily"]));
+        assert_eq!((runs[0].fetched, runs[0].saved, runs[0].error.as_deref()), (1, 1, None));
+        assert_eq!((runs[1].fetched, runs[1].error.as_deref()), (0, Some("Service unavailable")));
+        assert!(runs.iter().all(|run| run.finished_at.is_some()));
+
+        assert!(run_due_sources(&db, &aggregator, now).await.unwrap().is_empty());
+
+        let later = now + chrono::Duration::hours(2);
+        let runs = run_due_sources(&db, &aggregator, later).await.unwrap();
+        let ran: Vec<String> = runs.iter().map(|run| run.source.clone()).collect();
+        assert_eq!(ran, sources(&["hourly"]));
+
+        let schedules = source_schedules(&db, &aggregator, later).await.unwrap();
+        assert_eq!(schedules[1].source, "daily");
+        assert_eq!(schedules[1].interval_secs, 24 * 60 * 60);
+        assert!(schedules[1].last_run.as_ref().is_some_and(|run| run.error.is_some()));
+        assert!(schedules[1].next_run_at > later);
+    }
+}
@@ -3,29 +3,30 @@ use crate::models::*;
 use anyhow::Result;
 
 #[cfg(not(target_arch = "wasm32"))]
-use crate::db::{get_database, SqliteJobRepository, JobRepository, JobSearchQuery};
+use crate::db::{get_database, Database, SqliteJobRepository, JobRepository, JobSearchQuery};
 
 /// Search for jobs across multiple platforms
+///
+/// Keywords support boolean and phrase syntax (`rust AND (tokio OR async) -"crypto"`);
+/// keyword searches are ranked by relevance and include highlighted snippets.
 #[server(SearchJobs)]
 pub async fn search_jobs(
     keywords: String,
     location: String,
     salary_min: Option<u32>,
-    sources: Vec<JobSource>
-) -> Result<Vec<Job>, ServerFnError> {
+    sources: Vec<JobSource>,
+    remote_only: bool,
+    sort: JobSortOrder,
+) -> Result<Vec<JobSearchResult>, ServerFnError> {
     #[cfg(not(target_arch = "wasm32"))]
     {
         let db = get_database();
         let repo = SqliteJobRepository::new(db.clone());
         
         let query = JobSearchQuery {
-            keywords: if keywords.is_empty() { None } else { Some(keywords) },
-            location: if location.is_empty() { None } else { Some(location) },
-            min_salary: salary_min,
-            sources,
-            remote_only: false,
+            sort,
             limit: Some(50),
-            offset: None,
+            ..search_filters(keywords, location, salary_min, sources, remote_only)
         };
         
         repo.search(&query)
@@ -39,10 +40,31 @@ pub async fn search_jobs(
     }
 }
 
+/// Query for the filters of the search form; empty fields don't filter
+#[cfg(not(target_arch = "wasm32"))]
+pub fn search_filters(
+    keywords: String,
+    location: String,
+    salary_min: Option<u32>,
+    sources: Vec<JobSource>,
+    remote_only: bool,
+) -> JobSearchQuery {
+    JobSearchQuery {
+        keywords: if keywords.trim().is_empty() { None } else { Some(keywords) },
+        location: if location.is_empty() { None } else { Some(location) },
+        min_salary: salary_min,
+        sources,
+        remote_only,
+        ..Default::default()
+    }
+}
+
 /// Fetch jobs from external sources and store them in the database
 /// 
-/// This function fetches jobs from free job sources (Remotive, HN Who's Hiring, Arbeitnow)
-/// and stores them in SQLite for later searching.
+/// This function fetches jobs from free job sources (Remotive, HN Who's Hiring, Arbeitnow,
+/// and the company boards on the watchlist)
+/// and stores them in SQLite for later searching. Stored jobs are scored against the
+/// user's profile so they can be sorted by best match.
 #[server(FetchExternalJobs)]
 pub async fn fetch_external_jobs(
     keywords: Option<String>,
@@ -51,39 +73,20 @@ pub async fn fetch_external_jobs(
 ) -> Result<FetchJobsResult, ServerFnError> {
     #[cfg(not(target_arch = "wasm32"))]
     {
-        use crate::services::job_sources::JobAggregator;
-        use crate::db::{get_database, SqliteJobRepository, JobRepository};
+        use crate::services::job_sources::load_job_aggregator;
         
-        let aggregator = JobAggregator::new();
+        let db = get_database();
+        let aggregator = load_job_aggregator(db)
+            .await
+            .map_err(|e| ServerFnError::new(e.to_string()))?;
         
-        let jobs = aggregator.fetch_all(
+        let fetched = aggregator.fetch_all(
             keywords.as_deref(),
             location.as_deref(),
             limit,
-        ).await.map_err(|e| ServerFnError::new(e.to_string()))?;
-        
-        // Store jobs in the database
-        let db = get_database();
-        let repo = SqliteJobRepository::new(db.clone());
+        ).await;
         
-        let mut saved_count = 0;
-        let mut error_count = 0;
-        
-        for job in &jobs {
-            match repo.upsert_by_source(job).await {
-                Ok(_) => saved_count += 1,
-                Err(e) => {
-                    tracing::warn!("Failed to save job {}: {}", job.title, e);
-                    error_count += 1;
-                }
-            }
-        }
-        
-        Ok(FetchJobsResult {
-            fetched: jobs.len() as u32,
-            saved: saved_count,
-            errors: error_count,
-        })
+        Ok(store_fetched_jobs(db, fetched).await)
     }
     
     #[cfg(target_arch = "wasm32")]
@@ -92,6 +95,49 @@ pub async fn fetch_external_jobs(
     }
 }
 
+/// Store fetched jobs, score them against the user's profile and look for
+/// new matches of saved searches
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) async fn store_fetched_jobs(
+    db: &crate::db::Database,
+    fetched: crate::services::job_sources::AggregatedJobs,
+) -> FetchJobsResult {
+    use crate::services::job_matcher::update_match_scores;
+    use crate::services::saved_search_service::check_saved_searches;
+    
+    let repo = SqliteJobRepository::new(db.clone());
+    
+    let mut saved = Vec::new();
+    let mut error_count = 0;
+    
+    for job in &fetched.jobs {
+        match repo.upsert_by_source(job).await {
+            Ok(stored) => saved.push(stored),
+            Err(e) => {
+                tracing::warn!("Failed to save job {}: {}", job.title, e);
+                error_count += 1;
+            }
+        }
+    }
+    
+    // Scoring is best effort; the jobs are stored either way
+    if let Err(e) = update_match_scores(db, &saved).await {
+        tracing::warn!("Failed to score fetched jobs: {}", e);
+    }
+    
+    // Also best effort: searches are checked again after the next fetch
+    if let Err(e) = check_saved_searches(db).await {
+        tracing::warn!("Failed to check saved searches: {}", e);
+    }
+    
+    FetchJobsResult {
+        fetched: fetched.jobs.len() as u32,
+        saved: saved.len() as u32,
+        errors: error_count,
+        sources: fetched.reports,
+    }
+}
+
 /// Result of fetching external jobs
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct FetchJobsResult {
@@ -101,11 +147,132 @@ pub struct FetchJobsResult {
     pub saved: u32,
     /// Number of jobs that failed to save
     pub errors: u32,
+    /// How fetching went for each source
+    pub sources: Vec<SourceFetchReport>,
+}
+
+/// Background fetching schedule of each job source, with the latest runs
+#[server(GetFetchSchedule)]
+pub async fn get_fetch_schedule() -> Result<FetchSchedule, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use crate::services::job_scheduler::source_schedules;
+        use crate::services::job_sources::load_job_aggregator;
+        use crate::db::{SqliteFetchRunRepository, FetchRunRepository};
+        
+        let db = get_database();
+        let aggregator = load_job_aggregator(db)
+            .await
+            .map_err(|e| ServerFnError::new(e.to_string()))?;
+        let sources = source_schedules(db, &aggregator, chrono::Utc::now())
+            .await
+            .map_err(|e| ServerFnError::new(e.to_string()))?;
+        let recent_runs = SqliteFetchRunRepository::new(db.clone())
+            .get_recent(20)
+            .await
+            .map_err(|e| ServerFnError::new(e.to_string()))?;
+        
+        Ok(FetchSchedule { sources, recent_runs })
+    }
+    
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Job fetching only available on server"))
+    }
+}
+
+/// Background fetching schedule returned by [`get_fetch_schedule`]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct FetchSchedule {
+    /// When each source was last fetched and is next due
+    pub sources: Vec<SourceSchedule>,
+    /// Latest scheduled fetches of all sources, newest first
+    pub recent_runs: Vec<FetchRun>,
+}
+
+/// Company job boards on the watchlist, fetched along with the other sources
+#[server(GetCompanyBoards)]
+pub async fn get_company_boards() -> Result<Vec<CompanyBoard>, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use crate::db::{SqliteCompanyBoardRepository, CompanyBoardRepository};
+        
+        SqliteCompanyBoardRepository::new(get_database().clone())
+            .get_all()
+            .await
+            .map_err(|e| ServerFnError::new(e.to_string()))
+    }
+    
+    #[cfg(target_arch = "wasm32")]
+    {
+        Ok(Vec::new())
+    }
+}
+
+/// Add a company's job board to the watchlist
+///
+/// `token` is the company's name in the board's URL, e.g. `acme` for
+/// `boards.greenhouse.io/acme` or `HooliGroup` for
+/// `jobs.smartrecruiters.com/HooliGroup`. `company` overrides the name shown
+/// on its jobs.
+#[server(AddCompanyBoard)]
+pub async fn add_company_board(
+    source: JobSource,
+    token: String,
+    company: Option<String>,
+) -> Result<CompanyBoard, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use crate::db::{SqliteCompanyBoardRepository, CompanyBoardRepository};
+        use crate::services::job_sources::boards::check_token;
+        
+        if !CompanyBoard::SOURCES.contains(&source) {
+            return Err(ServerFnError::new(format!("{} has no company boards", source.display_name())));
+        }
+        let token = CompanyBoard::normalize_token(&source, &token);
+        check_token(&token).map_err(|e| ServerFnError::new(e.to_string()))?;
+        
+        let board = CompanyBoard {
+            company: company.map(|c| c.trim().to_string()).filter(|c| !c.is_empty()),
+            ..CompanyBoard::new(source, &token)
+        };
+        SqliteCompanyBoardRepository::new(get_database().clone())
+            .upsert(&board)
+            .await
+            .map_err(|e| ServerFnError::new(e.to_string()))
+    }
+    
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Company boards only available on server"))
+    }
+}
+
+/// Remove a company's job board from the watchlist
+///
+/// Jobs already fetched from the board are kept.
+#[server(RemoveCompanyBoard)]
+pub async fn remove_company_board(source: JobSource, token: String) -> Result<(), ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use crate::db::{SqliteCompanyBoardRepository, CompanyBoardRepository};
+        
+        let token = CompanyBoard::normalize_token(&source, &token);
+        SqliteCompanyBoardRepository::new(get_database().clone())
+            .delete(&source, &token)
+            .await
+            .map_err(|e| ServerFnError::new(e.to_string()))
+    }
+    
+    #[cfg(target_arch = "wasm32")]
+    {
+        Ok(())
+    }
 }
 
 /// Get saved jobs
 #[server(GetSavedJobs)]
-pub async fn get_saved_jobs() -> Result<Vec<Job>, ServerFnError> {
+pub async fn get_saved_jobs() -> Result<Vec<JobListing>, ServerFnError> {
     #[cfg(not(target_arch = "wasm32"))]
     {
         let db = get_database();
@@ -162,6 +329,26 @@ pub async fn unsave_job(job_id: String) -> Result<(), ServerFnError> {
     }
 }
 
+/// Get a single job by id
+#[server(GetJob)]
+pub async fn get_job(job_id: String) -> Result<Option<Job>, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let db = get_database();
+        let repo = SqliteJobRepository::new(db.clone());
+        let id = uuid::Uuid::parse_str(&job_id).map_err(|e| ServerFnError::new(e.to_string()))?;
+        
+        repo.get_by_id(&id)
+            .await
+            .map_err(|e| ServerFnError::new(e.to_string()))
+    }
+    
+    #[cfg(target_arch = "wasm32")]
+    {
+        Ok(None)
+    }
+}
+
 /// Generate a personalized cover letter for a job
 #[server(GenerateCoverLetter)]
 pub async fn generate_cover_letter(
@@ -214,28 +401,98 @@ pub async fn apply_to_job(
     resume_id: String,
     auto_submit: bool
 ) -> Result<JobApplication, ServerFnError> {
-    // TODO: Save to database and potentially trigger browser automation
+    // TODO: Potentially trigger browser automation when auto_submit is set
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let user_uuid = uuid::Uuid::parse_str(&user_id).map_err(|e| ServerFnError::new(e.to_string()))?;
+        let job_uuid = uuid::Uuid::parse_str(&job_id).map_err(|e| ServerFnError::new(e.to_string()))?;
+        let resume_uuid = if resume_id.is_empty() {
+            None
+        } else {
+            Some(uuid::Uuid::parse_str(&resume_id).map_err(|e| ServerFnError::new(e.to_string()))?)
+        };
+        
+        submit_application(get_database(), user_uuid, job_uuid, cover_letter, resume_uuid, auto_submit)
+            .await
+            .map_err(|e| ServerFnError::new(e.to_string()))
+    }
     
-    Ok(JobApplication {
-        id: uuid::Uuid::new_v4(),
-        user_id: uuid::Uuid::parse_str(&user_id).map_err(|e| ServerFnError::new(e.to_string()))?,
-        job_id: uuid::Uuid::parse_str(&job_id).map_err(|e| ServerFnError::new(e.to_string()))?,
-        status: if auto_submit { ApplicationStatus::Applied } else { ApplicationStatus::Draft },
-        applied_date: chrono::Utc::now(),
-        cover_letter: Some(cover_letter),
-        custom_resume_id: Some(uuid::Uuid::parse_str(&resume_id).map_err(|e| ServerFnError::new(e.to_string()))?),
-        notes: None,
-        follow_up_date: None,
-        created_at: chrono::Utc::now(),
-        updated_at: chrono::Utc::now(),
-    })
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Applying to jobs only available on server"))
+    }
+}
+
+/// Create the user's application for a job, or update it when they re-apply
+///
+/// A user can only have one application per job.
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn submit_application(
+    db: &Database,
+    user_id: uuid::Uuid,
+    job_id: uuid::Uuid,
+    cover_letter: String,
+    resume_id: Option<uuid::Uuid>,
+    auto_submit: bool,
+) -> Result<JobApplication> {
+    use crate::db::{SqliteApplicationRepository, ApplicationRepository};
+    
+    let repo = SqliteApplicationRepository::new(db.clone());
+    
+    match repo.get_by_user_and_job(&user_id, &job_id).await? {
+        Some(mut application) => {
+            // Re-applying never moves an application back; submitting sends a draft
+            if auto_submit && application.status == ApplicationStatus::Draft {
+                application.status = ApplicationStatus::Applied;
+                application.applied_date = chrono::Utc::now();
+            }
+            application.cover_letter = Some(cover_letter);
+            application.custom_resume_id = resume_id;
+            application.updated_at = chrono::Utc::now();
+            
+            repo.update(&application).await
+        }
+        None => {
+            let application = JobApplication {
+                id: uuid::Uuid::new_v4(),
+                user_id,
+                job_id,
+                status: if auto_submit { ApplicationStatus::Applied } else { ApplicationStatus::Draft },
+                applied_date: chrono::Utc::now(),
+                cover_letter: Some(cover_letter),
+                custom_resume_id: resume_id,
+                notes: None,
+                follow_up_date: None,
+                created_at: chrono::Utc::now(),
+                updated_at: chrono::Utc::now(),
+            };
+            
+            repo.create(&application).await
+        }
+    }
 }
 
 /// Get user's job applications
 #[server(GetUserApplications)]
 pub async fn get_user_applications(user_id: String) -> Result<Vec<JobApplication>, ServerFnError> {
-    // TODO: Query from database
-    Ok(Vec::new())
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use crate::db::{SqliteApplicationRepository, ApplicationRepository};
+        
+        let user_uuid = uuid::Uuid::parse_str(&user_id).map_err(|e| ServerFnError::new(e.to_string()))?;
+        
+        let db = get_database();
+        let repo = SqliteApplicationRepository::new(db.clone());
+        
+        repo.get_by_user_id(&user_uuid)
+            .await
+            .map_err(|e| ServerFnError::new(e.to_string()))
+    }
+    
+    #[cfg(target_arch = "wasm32")]
+    {
+        Ok(Vec::new())
+    }
 }
 
 /// Update application status
@@ -245,20 +502,47 @@ pub async fn update_application_status(
     status: ApplicationStatus,
     notes: Option<String>
 ) -> Result<JobApplication, ServerFnError> {
-    // TODO: Update in database
-    Ok(JobApplication {
-        id: uuid::Uuid::parse_str(&application_id).map_err(|e| ServerFnError::new(e.to_string()))?,
-        user_id: uuid::Uuid::new_v4(),
-        job_id: uuid::Uuid::new_v4(),
-        status,
-        applied_date: chrono::Utc::now(),
-        cover_letter: None,
-        custom_resume_id: None,
-        notes,
-        follow_up_date: None,
-        created_at: chrono::Utc::now(),
-        updated_at: chrono::Utc::now(),
-    })
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use crate::db::{SqliteApplicationRepository, ApplicationRepository};
+        
+        let id = uuid::Uuid::parse_str(&application_id).map_err(|e| ServerFnError::new(e.to_string()))?;
+        
+        let db = get_database();
+        let repo = SqliteApplicationRepository::new(db.clone());
+        
+        repo.update_status(&id, status, notes)
+            .await
+            .map_err(|e| ServerFnError::new(e.to_string()))
+    }
+    
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Updating applications only available on server"))
+    }
+}
+
+/// Get the status history of an application, oldest first
+#[server(GetApplicationTimeline)]
+pub async fn get_application_timeline(application_id: String) -> Result<Vec<ApplicationStatusChange>, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use crate::db::{SqliteApplicationRepository, ApplicationRepository};
+        
+        let id = uuid::Uuid::parse_str(&application_id).map_err(|e| ServerFnError::new(e.to_string()))?;
+        
+        let db = get_database();
+        let repo = SqliteApplicationRepository::new(db.clone());
+        
+        repo.get_status_history(&id)
+            .await
+            .map_err(|e| ServerFnError::new(e.to_string()))
+    }
+    
+    #[cfg(target_arch = "wasm32")]
+    {
+        Ok(Vec::new())
+    }
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -327,3 +611,52 @@ With enthusiasm and gratitude,
 [Your Name]
 "#.to_string())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::{ApplicationRepository, SqliteApplicationRepository};
+    use crate::test_fixtures::job;
+
+    #[tokio::test]
+    async fn test_default_user_can_apply_on_a_fresh_database() {
+        let db = Database::in_memory().await.unwrap();
+        let posting = job("Rust Engineer", "Acme", "Remote", JobSource::Remotive);
+        SqliteJobRepository::new(db.clone()).create(&posting).await.unwrap();
+        let user_id = uuid::Uuid::parse_str(DEFAULT_USER_ID).unwrap();
+
+        let application = submit_application(&db, user_id, posting.id, "Dear Acme".to_string(), None, true)
+            .await
+            .unwrap();
+        assert_eq!(application.status, ApplicationStatus::Applied);
+
+        let stored = SqliteApplicationRepository::new(db).get_by_user_id(&user_id).await.unwrap();
+        assert_eq!(stored.len(), 1);
+        assert_eq!(stored[0].cover_letter.as_deref(), Some("Dear Acme"));
+    }
+
+    #[tokio::test]
+    async fn test_reapplying_keeps_the_status() {
+        let db = Database::in_memory().await.unwrap();
+        let posting = job("Rust Engineer", "Acme", "Remote", JobSource::Remotive);
+        SqliteJobRepository::new(db.clone()).create(&posting).await.unwrap();
+        let user_id = uuid::Uuid::parse_str(DEFAULT_USER_ID).unwrap();
+        let applications = SqliteApplicationRepository::new(db.clone());
+
+        let draft = submit_application(&db, user_id, posting.id, "Draft".to_string(), None, false).await.unwrap();
+        assert_eq!(draft.status, ApplicationStatus::Draft);
+        let applied = submit_application(&db, user_id, posting.id, "Final".to_string(), None, true).await.unwrap();
+        assert_eq!(applied.status, ApplicationStatus::Applied);
+
+        applications.update_status(&applied.id, ApplicationStatus::Interviewing, None).await.unwrap();
+        let reapplied = submit_application(&db, user_id, posting.id, "Again".to_string(), None, false).await.unwrap();
+        assert_eq!(reapplied.status, ApplicationStatus::Interviewing);
+        assert_eq!(reapplied.cover_letter.as_deref(), Some("Again"));
+
+        let history: Vec<_> = applications.get_status_history(&applied.id).await.unwrap()
+            .into_iter()
+            .map(|change| change.status)
+            .collect();
+        assert_eq!(history, vec![ApplicationStatus::Draft, ApplicationStatus::Applied, ApplicationStatus::Interviewing]);
+    }
+}
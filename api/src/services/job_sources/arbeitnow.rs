@@ -4,12 +4,14 @@
 //! API is free and requires no authentication.
 //! API URL: https://www.arbeitnow.com/api/job-board-api
 
+use super::http::{SourceHttpClient, SourcePolicy};
 use super::JobSourceProvider;
 use crate::models::{Job, JobSource};
 use anyhow::{Context, Result};
 use async_trait::async_trait;
 use chrono::{Utc, TimeZone};
 use serde::Deserialize;
+use std::time::Duration;
 use uuid::Uuid;
 use regex::Regex;
 
@@ -17,13 +19,26 @@ const ARBEITNOW_API_URL: &str = "https://www.arbeitnow.com/api/job-board-api";
 
 /// Client for the Arbeitnow job board API
 pub struct ArbeitnowClient {
-    client: reqwest::Client,
+    http: SourceHttpClient,
 }
 
 impl ArbeitnowClient {
     pub fn new() -> Self {
+        Self::with_policy(Self::default_policy())
+    }
+    
+    pub fn with_policy(policy: SourcePolicy) -> Self {
         Self {
-            client: reqwest::Client::new(),
+            http: SourceHttpClient::new("Arbeitnow", policy),
+        }
+    }
+    
+    /// The board changes throughout the day; one listing covers all searches
+    pub fn default_policy() -> SourcePolicy {
+        SourcePolicy {
+            min_interval: Duration::from_secs(60),
+            cache_ttl: Duration::from_secs(30 * 60),
+            ..SourcePolicy::default()
         }
     }
 }
@@ -63,15 +78,10 @@ impl JobSourceProvider for ArbeitnowClient {
         location: Option<&str>,
         limit: Option<u32>,
     ) -> Result<Vec<Job>> {
-        let response: ArbeitnowResponse = self.client
-            .get(ARBEITNOW_API_URL)
-            .header("User-Agent", "employment-barage/1.0")
-            .send()
-            .await
-            .context("Failed to fetch from Arbeitnow API")?
-            .json()
+        let response: ArbeitnowResponse = self.http
+            .get_json(ARBEITNOW_API_URL)
             .await
-            .context("Failed to parse Arbeitnow response")?;
+            .context("Failed to fetch from Arbeitnow API")?;
         
         let limit = limit.unwrap_or(50) as usize;
         
@@ -106,6 +116,11 @@ impl JobSourceProvider for ArbeitnowClient {
     fn source_name(&self) -> &'static str {
         "Arbeitnow"
     }
+    
+    /// The feed is cached for half an hour, so fetching more often finds nothing new
+    fn fetch_interval(&self) -> Duration {
+        Duration::from_secs(30 * 60)
+    }
 }
 
 fn convert_arbeitnow_job(aj: ArbeitnowJob) -> Job {
@@ -131,11 +146,13 @@ fn convert_arbeitnow_job(aj: ArbeitnowJob) -> Job {
         title: aj.title,
         company: aj.company_name,
         location,
+        department: None,
         description,
         requirements,
         salary_range: None, // Arbeitnow doesn't typically include salary
-        source: JobSource::Other("Arbeitnow".to_string()),
+        source: JobSource::Arbeitnow,
         source_url: aj.url,
+        source_job_id: Some(aj.slug),
         posted_date,
         scraped_at: Utc::now(),
     }
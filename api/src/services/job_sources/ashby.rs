@@ -0,0 +1,264 @@
+//! Ashby job board API client
+//!
+//! Companies hiring through Ashby publish their open positions through the
+//! public posting API. It is free and requires no authentication.
+//! API URL: https://api.ashbyhq.com/posting-api/job-board/{token}
+
+use super::boards::{boards_timeout, fetch_boards, html_to_text, remote_location, BOARD_FETCH_INTERVAL};
+use super::http::{SourceHttpClient, SourcePolicy};
+use super::{matches_search, JobSourceProvider};
+use crate::models::{CompanyBoard, Job, JobSource, SalaryPeriod, SalaryRange};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use std::time::Duration;
+use uuid::Uuid;
+
+const ASHBY_API_URL: &str = "https://api.ashbyhq.com/posting-api/job-board";
+
+/// Client for the Ashby job boards of the companies on the watchlist
+pub struct AshbyClient {
+    http: SourceHttpClient,
+    boards: Vec<CompanyBoard>,
+}
+
+impl AshbyClient {
+    pub fn new(boards: Vec<CompanyBoard>) -> Self {
+        Self::with_policy(boards, Self::default_policy())
+    }
+
+    pub fn with_policy(boards: Vec<CompanyBoard>, policy: SourcePolicy) -> Self {
+        Self {
+            http: SourceHttpClient::new("Ashby", policy),
+            boards,
+        }
+    }
+
+    pub fn default_policy() -> SourcePolicy {
+        SourcePolicy {
+            min_interval: Duration::from_millis(500),
+            cache_ttl: Duration::from_secs(60 * 60),
+            ..SourcePolicy::default()
+        }
+    }
+
+    async fn fetch_board(&self, board: &CompanyBoard) -> Result<Vec<Job>> {
+        let url = format!("{}/{}?includeCompensation=true", ASHBY_API_URL, board.token);
+        let response: AshbyResponse = self.http
+            .get_json(&url)
+            .await
+            .with_context(|| format!("Failed to fetch Ashby board '{}'", board.token))?;
+
+        let company = board.company_name();
+        Ok(response.jobs
+            .into_iter()
+            .filter(|aj| aj.is_listed)
+            .map(|aj| convert_ashby_job(aj, &company))
+            .collect())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct AshbyResponse {
+    jobs: Vec<AshbyJob>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AshbyJob {
+    id: String,
+    title: String,
+    #[serde(default)]
+    department: Option<String>,
+    #[serde(default)]
+    team: Option<String>,
+    #[serde(default)]
+    employment_type: Option<String>,
+    #[serde(default)]
+    location: String,
+    #[serde(default)]
+    secondary_locations: Vec<AshbySecondaryLocation>,
+    #[serde(default = "listed")]
+    is_listed: bool,
+    #[serde(default)]
+    is_remote: bool,
+    published_at: String,
+    job_url: String,
+    #[serde(default)]
+    description_html: Option<String>,
+    #[serde(default)]
+    description_plain: Option<String>,
+    /// Only present when the board shows pay on its postings
+    #[serde(default)]
+    compensation: Option<AshbyCompensation>,
+}
+
+fn listed() -> bool {
+    true
+}
+
+#[derive(Debug, Deserialize)]
+struct AshbySecondaryLocation {
+    location: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AshbyCompensation {
+    #[serde(default)]
+    summary_components: Vec<AshbyCompensationComponent>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AshbyCompensationComponent {
+    compensation_type: String,
+    interval: String,
+    #[serde(default)]
+    currency_code: Option<String>,
+    #[serde(default)]
+    min_value: Option<f64>,
+    #[serde(default)]
+    max_value: Option<f64>,
+}
+
+#[async_trait]
+impl JobSourceProvider for AshbyClient {
+    async fn fetch_jobs(
+        &self,
+        keywords: Option<&str>,
+        location: Option<&str>,
+        limit: Option<u32>,
+    ) -> Result<Vec<Job>> {
+        let jobs = fetch_boards("Ashby", &self.boards, |board| self.fetch_board(board)).await?;
+
+        Ok(jobs
+            .into_iter()
+            .filter(|job| matches_search(job, keywords, location))
+            .take(limit.map_or(usize::MAX, |l| l as usize))
+            .collect())
+    }
+
+    fn source_name(&self) -> &'static str {
+        "Ashby"
+    }
+
+    fn timeout(&self) -> Duration {
+        boards_timeout(&self.boards)
+    }
+
+    fn fetch_interval(&self) -> Duration {
+        BOARD_FETCH_INTERVAL
+    }
+}
+
+fn convert_ashby_job(aj: AshbyJob, company: &str) -> Job {
+    let locations: Vec<&str> = std::iter::once(aj.location.as_str())
+        .chain(aj.secondary_locations.iter().map(|l| l.location.as_str()))
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .collect();
+    let location = remote_location(locations.join(", "), aj.is_remote);
+
+    let department = aj.department
+        .filter(|d| !d.trim().is_empty())
+        .or(aj.team)
+        .filter(|d| !d.trim().is_empty());
+
+    let description = match aj.description_html {
+        Some(html) => html_to_text(&html),
+        None => aj.description_plain.unwrap_or_default(),
+    };
+
+    let requirements = aj.employment_type
+        .map(|kind| match kind.as_str() {
+            "FullTime" => "Full-time".to_string(),
+            "PartTime" => "Part-time".to_string(),
+            "Intern" => "Internship".to_string(),
+            _ => kind,
+        })
+        .into_iter()
+        .collect();
+
+    let salary_range = aj.compensation
+        .into_iter()
+        .flat_map(|c| c.summary_components)
+        .find(|c| c.compensation_type == "Salary")
+        .and_then(|salary| {
+            let period = match salary.interval.as_str() {
+                "1 YEAR" => SalaryPeriod::Annual,
+                "1 HOUR" => SalaryPeriod::Hourly,
+                _ => return None,
+            };
+            Some(SalaryRange {
+                min: salary.min_value? as u32,
+                max: salary.max_value? as u32,
+                currency: salary.currency_code.unwrap_or_else(|| "USD".to_string()),
+                period,
+            })
+        });
+
+    let posted_date = DateTime::parse_from_rfc3339(&aj.published_at)
+        .map(|date| date.with_timezone(&Utc))
+        .unwrap_or_else(|_| Utc::now());
+
+    Job {
+        id: Uuid::new_v4(),
+        title: aj.title,
+        company: company.to_string(),
+        location,
+        department,
+        description,
+        requirements,
+        salary_range,
+        source: JobSource::Ashby,
+        source_url: aj.job_url,
+        source_job_id: Some(aj.id),
+        posted_date,
+        scraped_at: Utc::now(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture_jobs() -> Vec<AshbyJob> {
+        let response: AshbyResponse =
+            serde_json::from_str(include_str!("../../../tests/fixtures/ashby_jobs.json")).unwrap();
+        response.jobs
+    }
+
+    #[test]
+    fn test_convert_ashby_job() {
+        let job = convert_ashby_job(fixture_jobs().remove(0), "Initech");
+        assert_eq!(job.title, "Founding Frontend Engineer");
+        assert_eq!(job.location, "New York, San Francisco");
+        assert_eq!(job.department.as_deref(), Some("Engineering"));
+        assert_eq!(job.requirements, vec!["Full-time"]);
+        assert_eq!(job.source_job_id.as_deref(), Some("8c2f9a1e-6b7d-4c3e-9f10-2a3b4c5d6e7f"));
+        assert_eq!(job.posted_date.to_rfc3339(), "2026-09-15T14:30:00+00:00");
+        assert_eq!(job.description, "Build our product in TypeScript & React.\n• Own the design system");
+        // The equity component is not a salary
+        assert_eq!(
+            job.salary_range,
+            Some(SalaryRange { min: 150000, max: 190000, currency: "USD".to_string(), period: SalaryPeriod::Annual })
+        );
+    }
+
+    #[test]
+    fn test_remote_job_uses_team() {
+        let job = convert_ashby_job(fixture_jobs().remove(1), "Initech");
+        assert_eq!(job.location, "Europe (Remote)");
+        assert_eq!(job.department.as_deref(), Some("Customer Success"));
+        assert_eq!(job.requirements, vec!["Part-time"]);
+        assert_eq!(job.salary_range, None);
+    }
+
+    #[test]
+    fn test_unlisted_jobs_are_skipped() {
+        let listed: Vec<String> = fixture_jobs().into_iter().filter(|aj| aj.is_listed).map(|aj| aj.title).collect();
+        assert_eq!(listed, vec!["Founding Frontend Engineer", "Support Specialist"]);
+    }
+}
@@ -0,0 +1,143 @@
+//! Helpers for clients of company job boards
+//!
+//! Applicant tracking systems (Greenhouse, Lever, Ashby, ...) publish each
+//! company's open positions as a board, identified by a token the user adds
+//! to their watchlist. Boards are fetched one after another and have no
+//! search of their own, so keywords and location are matched locally.
+
+use crate::models::{CompanyBoard, Job};
+use crate::services::html_text::read_blocks;
+use crate::services::resume_parser::BlockKind;
+use anyhow::{anyhow, Result};
+use std::future::Future;
+use std::time::Duration;
+
+use super::DEFAULT_SOURCE_TIMEOUT;
+
+/// Extra time a fetch may take for each board
+const TIMEOUT_PER_BOARD: Duration = Duration::from_secs(5);
+
+/// How often company boards are fetched in the background; boards change a
+/// few times a week
+pub const BOARD_FETCH_INTERVAL: Duration = Duration::from_secs(3 * 60 * 60);
+
+/// Fetch the jobs of every board
+///
+/// A board that fails is skipped with a warning; the fetch only fails when
+/// every board does.
+pub async fn fetch_boards<'a, F, Fut>(source: &str, boards: &'a [CompanyBoard], fetch_board: F) -> Result<Vec<Job>>
+where
+    F: Fn(&'a CompanyBoard) -> Fut,
+    Fut: Future<Output = Result<Vec<Job>>>,
+{
+    let mut jobs = Vec::new();
+    let mut last_error = None;
+    let mut fetched_any = false;
+
+    for board in boards {
+        match fetch_board(board).await {
+            Ok(board_jobs) => {
+                fetched_any = true;
+                jobs.extend(board_jobs);
+            }
+            Err(e) => {
+                tracing::warn!("Failed to fetch {} board '{}': {}", source, board.token, e);
+                last_error = Some(e);
+            }
+        }
+    }
+
+    match last_error {
+        Some(e) if !fetched_any => Err(e),
+        _ => Ok(jobs),
+    }
+}
+
+/// How long fetching all of `boards` may take
+pub fn boards_timeout(boards: &[CompanyBoard]) -> Duration {
+    DEFAULT_SOURCE_TIMEOUT + TIMEOUT_PER_BOARD * boards.len() as u32
+}
+
+/// `location` marked as remote, for boards that report remote work separately
+pub fn remote_location(location: String, remote: bool) -> String {
+    if !remote || location.to_lowercase().contains("remote") {
+        location
+    } else if location.is_empty() {
+        "Remote".to_string()
+    } else {
+        format!("{} (Remote)", location)
+    }
+}
+
+/// Plain text of an HTML job description, one paragraph or list item per line
+pub fn html_to_text(html: &str) -> String {
+    read_blocks(html)
+        .into_iter()
+        .filter(|block| !block.text.trim().is_empty())
+        .map(|block| match block.kind {
+            BlockKind::ListItem(_) => format!("• {}", block.text.trim()),
+            _ => block.text.trim().to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Error for a board token that can't be part of a URL
+pub fn check_token(token: &str) -> Result<()> {
+    let valid = !token.is_empty()
+        && token.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'));
+    if valid {
+        Ok(())
+    } else {
+        Err(anyhow!("Invalid board token '{}'", token))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::JobSource;
+
+    #[tokio::test]
+    async fn test_fetch_boards_skips_failing_boards() {
+        let boards = vec![
+            CompanyBoard::new(JobSource::Greenhouse, "acme"),
+            CompanyBoard::new(JobSource::Greenhouse, "gone"),
+        ];
+        let jobs = fetch_boards("Greenhouse", &boards, |board| {
+            let token = board.token.clone();
+            async move {
+                match token.as_str() {
+                    "gone" => Err(anyhow!("404 Not Found")),
+                    _ => Ok(Vec::new()),
+                }
+            }
+        })
+        .await;
+        assert!(jobs.is_ok());
+
+        let failing = fetch_boards("Greenhouse", &boards[1..], |_| async { Err(anyhow!("404 Not Found")) }).await;
+        assert!(failing.is_err());
+    }
+
+    #[test]
+    fn test_html_to_text() {
+        let text = html_to_text("<h2>About</h2><p>Rust &amp; Go</p><ul><li>Docker</li></ul>");
+        assert_eq!(text, "About\nRust & Go\n• Docker");
+    }
+
+    #[test]
+    fn test_remote_location() {
+        assert_eq!(remote_location("Toronto".to_string(), false), "Toronto");
+        assert_eq!(remote_location("Toronto".to_string(), true), "Toronto (Remote)");
+        assert_eq!(remote_location("Remote - US".to_string(), true), "Remote - US");
+        assert_eq!(remote_location(String::new(), true), "Remote");
+    }
+
+    #[test]
+    fn test_check_token() {
+        assert!(check_token("acme-corp_2.0").is_ok());
+        assert!(check_token("").is_err());
+        assert!(check_token("acme/../admin").is_err());
+    }
+}
@@ -0,0 +1,211 @@
+//! Greenhouse job board API client
+//!
+//! Companies hiring through Greenhouse publish their open positions on a
+//! public board. The board API is free and requires no authentication.
+//! API URL: https://boards-api.greenhouse.io/v1/boards/{token}/jobs
+
+use super::boards::{boards_timeout, fetch_boards, html_to_text, BOARD_FETCH_INTERVAL};
+use super::http::{SourceHttpClient, SourcePolicy};
+use super::{matches_search, JobSourceProvider};
+use crate::models::{CompanyBoard, Job, JobSource};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use std::time::Duration;
+use uuid::Uuid;
+
+const GREENHOUSE_API_URL: &str = "https://boards-api.greenhouse.io/v1/boards";
+
+/// Client for the Greenhouse boards of the companies on the watchlist
+pub struct GreenhouseClient {
+    http: SourceHttpClient,
+    boards: Vec<CompanyBoard>,
+}
+
+impl GreenhouseClient {
+    pub fn new(boards: Vec<CompanyBoard>) -> Self {
+        Self::with_policy(boards, Self::default_policy())
+    }
+
+    pub fn with_policy(boards: Vec<CompanyBoard>, policy: SourcePolicy) -> Self {
+        Self {
+            http: SourceHttpClient::new("Greenhouse", policy),
+            boards,
+        }
+    }
+
+    /// Boards are served from a CDN; a short pause between boards is enough
+    pub fn default_policy() -> SourcePolicy {
+        SourcePolicy {
+            min_interval: Duration::from_millis(250),
+            cache_ttl: Duration::from_secs(60 * 60),
+            ..SourcePolicy::default()
+        }
+    }
+
+    async fn fetch_board(&self, board: &CompanyBoard) -> Result<Vec<Job>> {
+        let url = format!("{}/{}/jobs?content=true", GREENHOUSE_API_URL, board.token);
+        let response: GreenhouseResponse = self.http
+            .get_json(&url)
+            .await
+            .with_context(|| format!("Failed to fetch Greenhouse board '{}'", board.token))?;
+
+        let company = board.company_name();
+        Ok(response.jobs
+            .into_iter()
+            .map(|gj| convert_greenhouse_job(gj, &company))
+            .collect())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct GreenhouseResponse {
+    jobs: Vec<GreenhouseJob>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GreenhouseJob {
+    id: u64,
+    title: String,
+    updated_at: String,
+    #[serde(default)]
+    first_published: Option<String>,
+    #[serde(default)]
+    location: Option<GreenhouseName>,
+    absolute_url: String,
+    /// HTML, escaped once more as HTML entities
+    #[serde(default)]
+    content: String,
+    #[serde(default)]
+    departments: Vec<GreenhouseName>,
+    #[serde(default)]
+    offices: Vec<GreenhouseName>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GreenhouseName {
+    name: String,
+}
+
+#[async_trait]
+impl JobSourceProvider for GreenhouseClient {
+    async fn fetch_jobs(
+        &self,
+        keywords: Option<&str>,
+        location: Option<&str>,
+        limit: Option<u32>,
+    ) -> Result<Vec<Job>> {
+        let jobs = fetch_boards("Greenhouse", &self.boards, |board| self.fetch_board(board)).await?;
+
+        Ok(jobs
+            .into_iter()
+            .filter(|job| matches_search(job, keywords, location))
+            .take(limit.map_or(usize::MAX, |l| l as usize))
+            .collect())
+    }
+
+    fn source_name(&self) -> &'static str {
+        "Greenhouse"
+    }
+
+    fn timeout(&self) -> Duration {
+        boards_timeout(&self.boards)
+    }
+
+    fn fetch_interval(&self) -> Duration {
+        BOARD_FETCH_INTERVAL
+    }
+}
+
+fn convert_greenhouse_job(gj: GreenhouseJob, company: &str) -> Job {
+    let posted_date = gj.first_published
+        .as_deref()
+        .and_then(|date| DateTime::parse_from_rfc3339(date).ok())
+        .or_else(|| DateTime::parse_from_rfc3339(&gj.updated_at).ok())
+        .map(|date| date.with_timezone(&Utc))
+        .unwrap_or_else(Utc::now);
+
+    // Jobs without a location name are placed by their offices
+    let location = match gj.location.map(|l| l.name).filter(|name| !name.trim().is_empty()) {
+        Some(name) => name,
+        None => join_names(&gj.offices),
+    };
+
+    let department = Some(join_names(&gj.departments)).filter(|d| !d.is_empty());
+
+    // The content is entity-escaped HTML; unescaping it gives the HTML itself
+    let html = scraper::Html::parse_fragment(&gj.content)
+        .root_element()
+        .text()
+        .collect::<String>();
+
+    Job {
+        id: Uuid::new_v4(),
+        title: gj.title,
+        company: company.to_string(),
+        location,
+        department,
+        description: html_to_text(&html),
+        requirements: Vec::new(),
+        salary_range: None, // Pay ranges are only shown on the job page
+        source: JobSource::Greenhouse,
+        source_url: gj.absolute_url,
+        source_job_id: Some(gj.id.to_string()),
+        posted_date,
+        scraped_at: Utc::now(),
+    }
+}
+
+fn join_names(names: &[GreenhouseName]) -> String {
+    names.iter().map(|n| n.name.trim()).filter(|n| !n.is_empty()).collect::<Vec<_>>().join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture_jobs() -> Vec<Job> {
+        let response: GreenhouseResponse =
+            serde_json::from_str(include_str!("../../../tests/fixtures/greenhouse_jobs.json")).unwrap();
+        let board = CompanyBoard::new(JobSource::Greenhouse, "acme");
+        response.jobs.into_iter().map(|gj| convert_greenhouse_job(gj, &board.company_name())).collect()
+    }
+
+    #[test]
+    fn test_convert_greenhouse_job() {
+        let jobs = fixture_jobs();
+        let job = &jobs[0];
+        assert_eq!(job.title, "Senior Backend Engineer (Rust)");
+        assert_eq!(job.company, "Acme");
+        assert_eq!(job.location, "Berlin, Germany");
+        assert_eq!(job.department.as_deref(), Some("Engineering"));
+        assert_eq!(job.source_job_id.as_deref(), Some("4012345"));
+        assert_eq!(job.source_url, "https://boards.greenhouse.io/acme/jobs/4012345");
+        assert_eq!(job.posted_date.to_rfc3339(), "2026-09-02T13:00:00+00:00");
+        assert_eq!(
+            job.description,
+            "About the role\nYou will build our payments platform in Rust & PostgreSQL.\n\
+             • 5+ years of backend experience\n• Kubernetes in production"
+        );
+    }
+
+    #[test]
+    fn test_location_falls_back_to_offices() {
+        let jobs = fixture_jobs();
+        let job = &jobs[1];
+        assert_eq!(job.location, "Remote - Europe, London");
+        assert!(job.is_remote());
+        assert_eq!(job.department, None);
+        assert_eq!(job.posted_date.to_rfc3339(), "2026-10-01T08:00:00+00:00");
+    }
+
+    #[test]
+    fn test_search_filters() {
+        let jobs = fixture_jobs();
+        assert!(matches_search(&jobs[0], Some("rust"), Some("berlin")));
+        assert!(matches_search(&jobs[0], Some("engineering"), None));
+        assert!(!matches_search(&jobs[0], None, Some("remote")));
+        assert!(matches_search(&jobs[1], None, Some("remote")));
+    }
+}
@@ -5,38 +5,52 @@
 //! 
 //! The thread is posted on the first weekday of each month by @whoishiring.
 
+use super::http::{SourceHttpClient, SourcePolicy};
 use super::JobSourceProvider;
 use crate::models::{Job, JobSource};
+use crate::services::skill_taxonomy::SkillTaxonomy;
 use anyhow::{Context, Result};
 use async_trait::async_trait;
 use chrono::{Utc, TimeZone, Datelike};
 use serde::Deserialize;
 use uuid::Uuid;
 use regex::Regex;
+use std::time::Duration;
 
 const HN_API_BASE: &str = "https://hacker-news.firebaseio.com/v0";
 const WHOISHIRING_USER: &str = "whoishiring";
 
 /// Client for fetching jobs from HN Who's Hiring threads
 pub struct HNWhoIsHiringClient {
-    client: reqwest::Client,
+    http: SourceHttpClient,
 }
 
 impl HNWhoIsHiringClient {
     pub fn new() -> Self {
+        Self::with_policy(Self::default_policy())
+    }
+    
+    pub fn with_policy(policy: SourcePolicy) -> Self {
         Self {
-            client: reqwest::Client::new(),
+            http: SourceHttpClient::new("HN Who's Hiring", policy),
+        }
+    }
+    
+    /// The HN API has no rate limit, but a thread takes hundreds of requests,
+    /// so comments are cached for an hour
+    pub fn default_policy() -> SourcePolicy {
+        SourcePolicy {
+            min_interval: Duration::ZERO,
+            cache_ttl: Duration::from_secs(60 * 60),
+            ..SourcePolicy::default()
         }
     }
     
     /// Get the latest "Who is hiring?" thread ID
     async fn get_latest_hiring_thread_id(&self) -> Result<u64> {
         let url = format!("{}/user/{}.json", HN_API_BASE, WHOISHIRING_USER);
-        let user: HNUser = self.client
-            .get(&url)
-            .send()
-            .await?
-            .json()
+        let user: HNUser = self.http
+            .get_json(&url)
             .await
             .context("Failed to fetch whoishiring user")?;
         
@@ -62,11 +76,8 @@ impl HNWhoIsHiringClient {
     /// Fetch a single HN item
     async fn get_item(&self, id: u64) -> Result<HNItem> {
         let url = format!("{}/item/{}.json", HN_API_BASE, id);
-        self.client
-            .get(&url)
-            .send()
-            .await?
-            .json()
+        self.http
+            .get_json(&url)
             .await
             .context("Failed to fetch HN item")
     }
@@ -176,6 +187,16 @@ impl JobSourceProvider for HNWhoIsHiringClient {
     fn source_name(&self) -> &'static str {
         "HN Who's Hiring"
     }
+    
+    /// Every comment in the thread is a separate request, so this source is slow
+    fn timeout(&self) -> Duration {
+        Duration::from_secs(90)
+    }
+    
+    /// Most comments arrive in the first days of the month-long thread
+    fn fetch_interval(&self) -> Duration {
+        Duration::from_secs(4 * 60 * 60)
+    }
 }
 
 /// Parse an HN comment into a Job struct
@@ -206,11 +227,13 @@ fn parse_hn_job_posting(item: &HNItem, _thread_id: u64) -> Option<Job> {
         title,
         company,
         location,
+        department: None,
         description: clean_text.clone(),
         requirements: extract_technologies(&clean_text),
         salary_range: None, // HN posts rarely have structured salary info
-        source: JobSource::Other("HN Who's Hiring".to_string()),
+        source: JobSource::HNWhoIsHiring,
         source_url,
+        source_job_id: Some(item.id.to_string()),
         posted_date,
         scraped_at: Utc::now(),
     })
@@ -281,22 +304,7 @@ fn detect_location(text: &str) -> String {
 
 /// Extract technology keywords from job description
 fn extract_technologies(text: &str) -> Vec<String> {
-    let techs = [
-        "Python", "JavaScript", "TypeScript", "Rust", "Go", "Java", "C++", "C#",
-        "Ruby", "PHP", "Scala", "Kotlin", "Swift", "React", "Vue", "Angular",
-        "Node.js", "Django", "Flask", "Rails", "Spring", "FastAPI",
-        "PostgreSQL", "MySQL", "MongoDB", "Redis", "Elasticsearch",
-        "Docker", "Kubernetes", "AWS", "GCP", "Azure", "Terraform",
-        "GraphQL", "REST", "gRPC", "Kafka", "RabbitMQ",
-        "Machine Learning", "ML", "AI", "Deep Learning", "NLP",
-        "iOS", "Android", "React Native", "Flutter",
-    ];
-    
-    let text_lower = text.to_lowercase();
-    techs.iter()
-        .filter(|&tech| text_lower.contains(&tech.to_lowercase()))
-        .map(|&s| s.to_string())
-        .collect()
+    SkillTaxonomy::builtin().skill_names(text)
 }
 
 /// Clean HN HTML entities and formatting
@@ -357,4 +365,10 @@ mod tests {
         assert!(techs.contains(&"React".to_string()));
         assert!(techs.contains(&"AWS".to_string()));
     }
+
+    #[test]
+    fn test_extract_technologies_matches_whole_names() {
+        let techs = extract_technologies("Google alumni maintain our React Native app, C++ and Node.js backends");
+        assert_eq!(techs, vec!["React Native", "C++", "Node.js"]);
+    }
 }
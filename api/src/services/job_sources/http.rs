@@ -0,0 +1,501 @@
+//! Rate-limited, cached HTTP access shared by all job sources
+//!
+//! Free job APIs ask to be called sparingly (Remotive about four times a
+//! day), while searches may trigger a fetch at any time. Every source
+//! therefore makes its requests through a [`SourceHttpClient`], which:
+//!
+//! - keeps responses in an on-disk cache and serves them while they are
+//!   younger than the source's TTL; stale entries are revalidated with
+//!   `If-None-Match` / `If-Modified-Since`
+//! - spaces requests to a source at least `min_interval` apart, shared by
+//!   every client of that source in the process; the time of the last
+//!   request is kept in the cache directory so the interval also holds
+//!   across restarts
+//! - retries rate-limited (429) and failed (5xx) requests with exponential
+//!   backoff, honouring `Retry-After`
+//! - falls back to a stale cached response when the source is rate limited
+//!   or unreachable
+//!
+//! Each source's limits can be changed in the environment, see
+//! [`SourcePolicy::with_env_overrides`].
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Duration;
+
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, Utc};
+use reqwest::header::{ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED, RETRY_AFTER, USER_AGENT};
+use reqwest::StatusCode;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tokio::time::Instant;
+
+const USER_AGENT_VALUE: &str = "employment-barage/1.0";
+
+/// File in a source's cache directory holding the time of its last request
+const LAST_REQUEST_FILE: &str = "last_request";
+
+/// How often a source may be called and how long its responses are reused
+#[derive(Debug, Clone, PartialEq)]
+pub struct SourcePolicy {
+    /// Minimum time between two requests to the source
+    pub min_interval: Duration,
+    /// How long a cached response is used without asking the source
+    pub cache_ttl: Duration,
+    /// Longest a request waits for its turn before giving up
+    pub max_wait: Duration,
+    /// Retries after a 429 or 5xx response or a connection error
+    pub max_retries: u32,
+}
+
+impl Default for SourcePolicy {
+    fn default() -> Self {
+        Self {
+            min_interval: Duration::from_secs(1),
+            cache_ttl: Duration::from_secs(30 * 60),
+            max_wait: Duration::from_secs(10),
+            max_retries: 2,
+        }
+    }
+}
+
+impl SourcePolicy {
+    /// `self` with the limits set in the environment for `source`
+    ///
+    /// Each limit has a variable named after the source, e.g.
+    /// `JOB_SOURCE_REMOTIVE_MIN_INTERVAL_SECS=3600` or
+    /// `JOB_SOURCE_HN_WHOS_HIRING_CACHE_TTL_SECS=600`. The limits are
+    /// `MIN_INTERVAL_SECS`, `CACHE_TTL_SECS`, `MAX_WAIT_SECS` and `MAX_RETRIES`.
+    pub fn with_env_overrides(self, source: &str) -> Self {
+        self.with_overrides(source, |name| std::env::var(name).ok())
+    }
+
+    /// `self` with the limits that `var` returns for the variables of `source`
+    fn with_overrides(mut self, source: &str, var: impl Fn(&str) -> Option<String>) -> Self {
+        let prefix = env_prefix(source);
+        let value = |limit: &str| -> Option<u64> {
+            let name = format!("{}_{}", prefix, limit);
+            let value = var(&name)?;
+            match value.trim().parse() {
+                Ok(value) => Some(value),
+                Err(_) => {
+                    tracing::warn!("Ignoring {}={}: expected a whole number", name, value);
+                    None
+                }
+            }
+        };
+
+        if let Some(secs) = value("MIN_INTERVAL_SECS") {
+            self.min_interval = Duration::from_secs(secs);
+        }
+        if let Some(secs) = value("CACHE_TTL_SECS") {
+            self.cache_ttl = Duration::from_secs(secs);
+        }
+        if let Some(secs) = value("MAX_WAIT_SECS") {
+            self.max_wait = Duration::from_secs(secs);
+        }
+        if let Some(retries) = value("MAX_RETRIES") {
+            self.max_retries = u32::try_from(retries).unwrap_or(u32::MAX);
+        }
+        self
+    }
+}
+
+/// Prefix of the environment variables of `source`, e.g. `JOB_SOURCE_HN_WHOS_HIRING`
+fn env_prefix(source: &str) -> String {
+    let name: String = source
+        .chars()
+        .filter(|&c| c != '\'')
+        .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_uppercase() } else { ' ' })
+        .collect();
+    format!("JOB_SOURCE_{}", name.split_whitespace().collect::<Vec<_>>().join("_"))
+}
+
+/// A cached response, stored as JSON in the cache directory
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CacheEntry {
+    url: String,
+    etag: Option<String>,
+    last_modified: Option<String>,
+    fetched_at: DateTime<Utc>,
+    body: String,
+}
+
+impl CacheEntry {
+    fn is_fresh(&self, ttl: Duration) -> bool {
+        let age = Utc::now().signed_duration_since(self.fetched_at);
+        age.to_std().map_or(true, |age| age < ttl)
+    }
+}
+
+/// HTTP client for one job source, see the module documentation
+#[derive(Clone)]
+pub struct SourceHttpClient {
+    source: &'static str,
+    client: reqwest::Client,
+    policy: SourcePolicy,
+    cache_dir: Option<PathBuf>,
+    limiter: Arc<RateLimiter>,
+}
+
+impl SourceHttpClient {
+    /// Client for `source`, caching under the user's cache directory
+    ///
+    /// Limits set in the environment take precedence over `policy`.
+    pub fn new(source: &'static str, policy: SourcePolicy) -> Self {
+        Self {
+            source,
+            client: reqwest::Client::new(),
+            policy: policy.with_env_overrides(source),
+            cache_dir: Some(default_cache_dir().join(cache_key(source))),
+            limiter: RateLimiter::for_source(source),
+        }
+    }
+
+    /// Cache responses in `dir` instead, or not at all with `None`
+    pub fn with_cache_dir(mut self, dir: Option<PathBuf>) -> Self {
+        self.cache_dir = dir;
+        self
+    }
+
+    pub fn policy(&self) -> &SourcePolicy {
+        &self.policy
+    }
+
+    /// GET `url` and parse the body as JSON
+    pub async fn get_json<T: DeserializeOwned>(&self, url: &str) -> Result<T> {
+        let body = self.get_text(url).await?;
+        serde_json::from_str(&body).with_context(|| format!("Failed to parse response from {}", url))
+    }
+
+    /// GET `url`, from the cache where possible
+    pub async fn get_text(&self, url: &str) -> Result<String> {
+        let cached = self.read_cache(url);
+        if let Some(entry) = &cached {
+            if entry.is_fresh(self.policy.cache_ttl) {
+                return Ok(entry.body.clone());
+            }
+        }
+
+        match self.fetch(url, cached.as_ref()).await {
+            Ok(entry) => {
+                self.write_cache(&entry);
+                Ok(entry.body)
+            }
+            Err(e) => match cached {
+                Some(entry) => {
+                    tracing::warn!("Using cached {} response for {}: {}", self.source, url, e);
+                    Ok(entry.body)
+                }
+                None => Err(e),
+            },
+        }
+    }
+
+    /// Request `url`, revalidating `cached` and retrying failures
+    async fn fetch(&self, url: &str, cached: Option<&CacheEntry>) -> Result<CacheEntry> {
+        let mut attempt = 0;
+        loop {
+            // Requests made before a restart count as well
+            if let Some(last) = self.read_last_request() {
+                let elapsed = Utc::now().signed_duration_since(last).to_std().unwrap_or(Duration::ZERO);
+                if let Some(wait) = self.policy.min_interval.checked_sub(elapsed) {
+                    self.limiter.back_off(wait);
+                }
+            }
+            self.limiter
+                .acquire(self.policy.min_interval, self.policy.max_wait)
+                .await
+                .map_err(|wait| anyhow!("{} is rate limited for another {}s", self.source, wait.as_secs()))?;
+            self.write_last_request(Utc::now());
+
+            let mut request = self.client.get(url).header(USER_AGENT, USER_AGENT_VALUE);
+            if let Some(entry) = cached {
+                if let Some(etag) = &entry.etag {
+                    request = request.header(IF_NONE_MATCH, etag);
+                }
+                if let Some(last_modified) = &entry.last_modified {
+                    request = request.header(IF_MODIFIED_SINCE, last_modified);
+                }
+            }
+
+            let retry_after = match request.send().await {
+                Ok(response) if response.status() == StatusCode::NOT_MODIFIED && cached.is_some() => {
+                    let mut entry = cached.cloned().unwrap();
+                    entry.fetched_at = Utc::now();
+                    return Ok(entry);
+                }
+                Ok(response) if response.status().is_success() => {
+                    let header = |name| {
+                        response.headers().get(name).and_then(|v| v.to_str().ok()).map(str::to_string)
+                    };
+                    return Ok(CacheEntry {
+                        url: url.to_string(),
+                        etag: header(ETAG),
+                        last_modified: header(LAST_MODIFIED),
+                        fetched_at: Utc::now(),
+                        body: response.text().await?,
+                    });
+                }
+                Ok(response)
+                    if response.status() == StatusCode::TOO_MANY_REQUESTS
+                        || response.status().is_server_error() =>
+                {
+                    if attempt >= self.policy.max_retries {
+                        return Err(anyhow!("{} responded with {}", self.source, response.status()));
+                    }
+                    response
+                        .headers()
+                        .get(RETRY_AFTER)
+                        .and_then(|v| v.to_str().ok())
+                        .and_then(|v| v.parse::<u64>().ok())
+                        .map(Duration::from_secs)
+                }
+                Ok(response) => {
+                    return Err(anyhow!("{} responded with {}", self.source, response.status()));
+                }
+                Err(e) if attempt < self.policy.max_retries => {
+                    tracing::debug!("Request to {} failed, retrying: {}", self.source, e);
+                    None
+                }
+                Err(e) => return Err(e).with_context(|| format!("Failed to fetch from {}", self.source)),
+            };
+
+            let delay = retry_after.unwrap_or_else(|| Duration::from_secs(1 << attempt));
+            self.limiter.back_off(delay);
+            attempt += 1;
+        }
+    }
+
+    fn cache_path(&self, url: &str) -> Option<PathBuf> {
+        self.cache_dir.as_ref().map(|dir| dir.join(format!("{}.json", cache_key(url))))
+    }
+
+    fn read_cache(&self, url: &str) -> Option<CacheEntry> {
+        let data = std::fs::read_to_string(self.cache_path(url)?).ok()?;
+        serde_json::from_str::<CacheEntry>(&data).ok().filter(|entry| entry.url == url)
+    }
+
+    /// When the source was last requested, by this run or an earlier one
+    fn read_last_request(&self) -> Option<DateTime<Utc>> {
+        let data = std::fs::read_to_string(self.cache_dir.as_ref()?.join(LAST_REQUEST_FILE)).ok()?;
+        DateTime::parse_from_rfc3339(data.trim()).ok().map(|at| at.with_timezone(&Utc))
+    }
+
+    fn write_last_request(&self, at: DateTime<Utc>) {
+        let Some(dir) = &self.cache_dir else {
+            return;
+        };
+        let result = std::fs::create_dir_all(dir).and_then(|_| std::fs::write(dir.join(LAST_REQUEST_FILE), at.to_rfc3339()));
+        if let Err(e) = result {
+            tracing::warn!("Failed to record {} request time: {}", self.source, e);
+        }
+    }
+
+    /// Cache failures only cost a later request, so they are logged and ignored
+    fn write_cache(&self, entry: &CacheEntry) {
+        let Some(path) = self.cache_path(&entry.url) else {
+            return;
+        };
+        let result = path
+            .parent()
+            .map_or(Ok(()), std::fs::create_dir_all)
+            .and_then(|_| std::fs::write(&path, serde_json::to_string(entry).unwrap_or_default()));
+        if let Err(e) = result {
+            tracing::warn!("Failed to cache {} response: {}", self.source, e);
+        }
+    }
+}
+
+/// Directory for cached job source responses
+pub fn default_cache_dir() -> PathBuf {
+    dirs::cache_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("employment-barage")
+        .join("http")
+}
+
+/// File-name safe key for `text`
+fn cache_key(text: &str) -> String {
+    format!("{:x}", Sha256::digest(text.as_bytes()))[..32].to_string()
+}
+
+/// Spaces out the requests to one source
+#[derive(Debug)]
+struct RateLimiter {
+    /// Earliest time the next request may be made
+    next_request: Mutex<Instant>,
+}
+
+impl RateLimiter {
+    fn new() -> Self {
+        Self { next_request: Mutex::new(Instant::now()) }
+    }
+
+    /// The limiter shared by all clients of `source`
+    fn for_source(source: &'static str) -> Arc<Self> {
+        static LIMITERS: OnceLock<Mutex<HashMap<&'static str, Arc<RateLimiter>>>> = OnceLock::new();
+        let mut limiters = LIMITERS.get_or_init(Default::default).lock().unwrap();
+        limiters.entry(source).or_insert_with(|| Arc::new(RateLimiter::new())).clone()
+    }
+
+    /// Wait for this request's turn, or return how long it would have to
+    /// wait if that is longer than `max_wait`
+    async fn acquire(&self, min_interval: Duration, max_wait: Duration) -> Result<(), Duration> {
+        let turn = {
+            let mut next = self.next_request.lock().unwrap();
+            let now = Instant::now();
+            let turn = (*next).max(now);
+            if turn - now > max_wait {
+                return Err(turn - now);
+            }
+            *next = turn + min_interval;
+            turn
+        };
+        tokio::time::sleep_until(turn).await;
+        Ok(())
+    }
+
+    /// Make no request for `delay`, e.g. after being told to slow down
+    fn back_off(&self, delay: Duration) {
+        let mut next = self.next_request.lock().unwrap();
+        *next = (*next).max(Instant::now() + delay);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    /// Serve `responses` in turn, recording each request's headers
+    async fn serve(responses: Vec<&'static str>) -> (String, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/jobs", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let recorded = requests.clone();
+        tokio::spawn(async move {
+            for response in responses {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut buf = vec![0; 4096];
+                let n = socket.read(&mut buf).await.unwrap();
+                recorded.lock().unwrap().push(String::from_utf8_lossy(&buf[..n]).to_lowercase());
+                socket.write_all(response.as_bytes()).await.unwrap();
+                socket.shutdown().await.unwrap();
+            }
+        });
+        (url, requests)
+    }
+
+    fn temp_dir() -> PathBuf {
+        std::env::temp_dir().join(format!("job-source-cache-{}", uuid::Uuid::new_v4()))
+    }
+
+    fn policy(cache_ttl: Duration) -> SourcePolicy {
+        SourcePolicy {
+            min_interval: Duration::ZERO,
+            cache_ttl,
+            max_wait: Duration::from_secs(5),
+            max_retries: 2,
+        }
+    }
+
+    const OK: &str = "HTTP/1.1 200 OK\r\nETag: \"v1\"\r\nContent-Length: 13\r\nConnection: close\r\n\r\n{\"jobs\": [1]}";
+    const NOT_MODIFIED: &str = "HTTP/1.1 304 Not Modified\r\nConnection: close\r\n\r\n";
+    const TOO_MANY: &str = "HTTP/1.1 429 Too Many Requests\r\nRetry-After: 0\r\nContent-Length: 0\r\nConnection: close\r\n\r\n";
+
+    #[tokio::test]
+    async fn test_fresh_responses_come_from_the_cache() {
+        let (url, requests) = serve(vec![OK]).await;
+        let dir = temp_dir();
+        let client = SourceHttpClient::new("test-fresh", policy(Duration::from_secs(60))).with_cache_dir(Some(dir.clone()));
+
+        let first: serde_json::Value = client.get_json(&url).await.unwrap();
+        let second: serde_json::Value = client.get_json(&url).await.unwrap();
+        assert_eq!(first, second);
+        assert_eq!(requests.lock().unwrap().len(), 1);
+
+        std::fs::remove_dir_all(dir).ok();
+    }
+
+    #[tokio::test]
+    async fn test_stale_responses_are_revalidated() {
+        let (url, requests) = serve(vec![OK, NOT_MODIFIED]).await;
+        let dir = temp_dir();
+        let client = SourceHttpClient::new("test-stale", policy(Duration::ZERO)).with_cache_dir(Some(dir.clone()));
+
+        assert_eq!(client.get_text(&url).await.unwrap(), "{\"jobs\": [1]}");
+        assert_eq!(client.get_text(&url).await.unwrap(), "{\"jobs\": [1]}");
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[1].contains("if-none-match: \"v1\""));
+
+        std::fs::remove_dir_all(dir).ok();
+    }
+
+    #[tokio::test]
+    async fn test_rate_limited_requests_are_retried() {
+        let (url, requests) = serve(vec![TOO_MANY, OK]).await;
+        let client = SourceHttpClient::new("test-retry", policy(Duration::ZERO)).with_cache_dir(None);
+
+        assert_eq!(client.get_text(&url).await.unwrap(), "{\"jobs\": [1]}");
+        assert_eq!(requests.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_min_interval_holds_across_restarts() {
+        let dir = temp_dir();
+        let mut policy = policy(Duration::ZERO);
+        policy.min_interval = Duration::from_secs(60 * 60);
+        policy.max_wait = Duration::from_secs(1);
+
+        // An earlier run made a request a minute ago
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join(LAST_REQUEST_FILE), (Utc::now() - chrono::Duration::minutes(1)).to_rfc3339()).unwrap();
+
+        let client = SourceHttpClient::new("test-restart", policy).with_cache_dir(Some(dir.clone()));
+        let error = client.get_text("http://127.0.0.1:9/jobs").await.unwrap_err();
+        assert!(error.to_string().contains("rate limited"), "{}", error);
+
+        std::fs::remove_dir_all(dir).ok();
+    }
+
+    #[test]
+    fn test_policy_overrides() {
+        let vars = [
+            ("JOB_SOURCE_HN_WHOS_HIRING_MIN_INTERVAL_SECS", "120"),
+            ("JOB_SOURCE_HN_WHOS_HIRING_MAX_RETRIES", "5"),
+            ("JOB_SOURCE_HN_WHOS_HIRING_CACHE_TTL_SECS", "soon"),
+        ];
+        let var = |name: &str| vars.iter().find(|(n, _)| *n == name).map(|(_, v)| v.to_string());
+
+        let policy = SourcePolicy::default().with_overrides("HN Who's Hiring", var);
+        assert_eq!(policy.min_interval, Duration::from_secs(120));
+        assert_eq!(policy.max_retries, 5);
+        // Invalid values are ignored
+        assert_eq!(policy.cache_ttl, SourcePolicy::default().cache_ttl);
+        assert_eq!(SourcePolicy::default().with_overrides("Remotive", var), SourcePolicy::default());
+    }
+
+    #[tokio::test]
+    async fn test_requests_are_spaced_out() {
+        let limiter = RateLimiter::new();
+        let interval = Duration::from_millis(50);
+
+        let started = Instant::now();
+        for _ in 0..3 {
+            limiter.acquire(interval, Duration::from_secs(1)).await.unwrap();
+        }
+        assert!(started.elapsed() >= interval * 2);
+
+        // A turn further away than the caller will wait is refused
+        limiter.back_off(Duration::from_secs(60));
+        let wait = limiter.acquire(interval, Duration::from_secs(1)).await.unwrap_err();
+        assert!(wait > Duration::from_secs(50));
+    }
+}
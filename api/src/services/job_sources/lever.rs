@@ -0,0 +1,240 @@
+//! Lever postings API client
+//!
+//! Companies hiring through Lever publish their open positions through the
+//! public postings API. It is free and requires no authentication.
+//! API URL: https://api.lever.co/v0/postings/{token}?mode=json
+
+use super::boards::{boards_timeout, fetch_boards, html_to_text, remote_location, BOARD_FETCH_INTERVAL};
+use super::http::{SourceHttpClient, SourcePolicy};
+use super::{matches_search, JobSourceProvider};
+use crate::models::{CompanyBoard, Job, JobSource, SalaryPeriod, SalaryRange};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use std::time::Duration;
+use uuid::Uuid;
+
+const LEVER_API_URL: &str = "https://api.lever.co/v0/postings";
+
+/// Client for the Lever postings of the companies on the watchlist
+pub struct LeverClient {
+    http: SourceHttpClient,
+    boards: Vec<CompanyBoard>,
+}
+
+impl LeverClient {
+    pub fn new(boards: Vec<CompanyBoard>) -> Self {
+        Self::with_policy(boards, Self::default_policy())
+    }
+
+    pub fn with_policy(boards: Vec<CompanyBoard>, policy: SourcePolicy) -> Self {
+        Self {
+            http: SourceHttpClient::new("Lever", policy),
+            boards,
+        }
+    }
+
+    /// Lever allows bursts but asks clients to stay well under 10 requests a second
+    pub fn default_policy() -> SourcePolicy {
+        SourcePolicy {
+            min_interval: Duration::from_millis(500),
+            cache_ttl: Duration::from_secs(60 * 60),
+            ..SourcePolicy::default()
+        }
+    }
+
+    async fn fetch_board(&self, board: &CompanyBoard) -> Result<Vec<Job>> {
+        let url = format!("{}/{}?mode=json", LEVER_API_URL, board.token);
+        let postings: Vec<LeverPosting> = self.http
+            .get_json(&url)
+            .await
+            .with_context(|| format!("Failed to fetch Lever postings of '{}'", board.token))?;
+
+        let company = board.company_name();
+        Ok(postings
+            .into_iter()
+            .map(|posting| convert_lever_posting(posting, &company))
+            .collect())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LeverPosting {
+    id: String,
+    text: String,
+    hosted_url: String,
+    /// Milliseconds since the epoch
+    created_at: i64,
+    #[serde(default)]
+    categories: LeverCategories,
+    #[serde(default)]
+    description_plain: String,
+    #[serde(default)]
+    lists: Vec<LeverList>,
+    #[serde(default)]
+    additional_plain: String,
+    #[serde(default)]
+    workplace_type: Option<String>,
+    #[serde(default)]
+    salary_range: Option<LeverSalaryRange>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LeverCategories {
+    #[serde(default)]
+    commitment: Option<String>,
+    #[serde(default)]
+    department: Option<String>,
+    #[serde(default)]
+    location: Option<String>,
+    #[serde(default)]
+    team: Option<String>,
+    #[serde(default)]
+    all_locations: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LeverList {
+    text: String,
+    /// HTML list items
+    content: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct LeverSalaryRange {
+    currency: String,
+    interval: String,
+    min: f64,
+    max: f64,
+}
+
+#[async_trait]
+impl JobSourceProvider for LeverClient {
+    async fn fetch_jobs(
+        &self,
+        keywords: Option<&str>,
+        location: Option<&str>,
+        limit: Option<u32>,
+    ) -> Result<Vec<Job>> {
+        let jobs = fetch_boards("Lever", &self.boards, |board| self.fetch_board(board)).await?;
+
+        Ok(jobs
+            .into_iter()
+            .filter(|job| matches_search(job, keywords, location))
+            .take(limit.map_or(usize::MAX, |l| l as usize))
+            .collect())
+    }
+
+    fn source_name(&self) -> &'static str {
+        "Lever"
+    }
+
+    fn timeout(&self) -> Duration {
+        boards_timeout(&self.boards)
+    }
+
+    fn fetch_interval(&self) -> Duration {
+        BOARD_FETCH_INTERVAL
+    }
+}
+
+fn convert_lever_posting(posting: LeverPosting, company: &str) -> Job {
+    let categories = posting.categories;
+
+    let location = categories.location
+        .filter(|l| !l.trim().is_empty())
+        .unwrap_or_else(|| categories.all_locations.join(", "));
+    let location = remote_location(location, posting.workplace_type.as_deref() == Some("remote"));
+
+    let department = categories.department
+        .or(categories.team)
+        .filter(|d| !d.trim().is_empty());
+
+    let mut description = vec![posting.description_plain.trim().to_string()];
+    for list in posting.lists {
+        description.push(list.text.trim().to_string());
+        description.push(html_to_text(&format!("<ul>{}</ul>", list.content)));
+    }
+    description.push(posting.additional_plain.trim().to_string());
+    description.retain(|part| !part.is_empty());
+
+    let salary_range = posting.salary_range.and_then(|salary| {
+        let period = match salary.interval.as_str() {
+            "per-year-salary" => SalaryPeriod::Annual,
+            "per-hour-wage" => SalaryPeriod::Hourly,
+            _ => return None,
+        };
+        Some(SalaryRange {
+            min: salary.min as u32,
+            max: salary.max as u32,
+            currency: salary.currency,
+            period,
+        })
+    });
+
+    Job {
+        id: Uuid::new_v4(),
+        title: posting.text,
+        company: company.to_string(),
+        location,
+        department,
+        description: description.join("\n"),
+        requirements: categories.commitment.into_iter().collect(),
+        salary_range,
+        source: JobSource::Lever,
+        source_url: posting.hosted_url,
+        source_job_id: Some(posting.id),
+        posted_date: DateTime::from_timestamp_millis(posting.created_at).unwrap_or_else(Utc::now),
+        scraped_at: Utc::now(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture_jobs() -> Vec<Job> {
+        let postings: Vec<LeverPosting> =
+            serde_json::from_str(include_str!("../../../tests/fixtures/lever_postings.json")).unwrap();
+        let board = CompanyBoard::new(JobSource::Lever, "globex");
+        postings.into_iter().map(|posting| convert_lever_posting(posting, &board.company_name())).collect()
+    }
+
+    #[test]
+    fn test_convert_lever_posting() {
+        let jobs = fixture_jobs();
+        let job = &jobs[0];
+        assert_eq!(job.title, "Platform Engineer");
+        assert_eq!(job.company, "Globex");
+        assert_eq!(job.location, "Toronto");
+        assert_eq!(job.department.as_deref(), Some("Engineering"));
+        assert_eq!(job.requirements, vec!["Full-time"]);
+        assert_eq!(job.source_job_id.as_deref(), Some("5f7c1a2b-3c4d-4e5f-8a9b-0c1d2e3f4a5b"));
+        assert_eq!(job.posted_date.timestamp_millis(), 1790000000000);
+        assert_eq!(
+            job.salary_range,
+            Some(SalaryRange { min: 140000, max: 175000, currency: "CAD".to_string(), period: SalaryPeriod::Annual })
+        );
+        assert_eq!(
+            job.description,
+            "Globex is hiring a platform engineer to run our Kubernetes clusters.\n\
+             What you'll need\n• Go or Rust\n• Terraform & AWS\n\
+             We offer a learning budget and flexible hours."
+        );
+    }
+
+    #[test]
+    fn test_remote_posting_without_location() {
+        let jobs = fixture_jobs();
+        let job = &jobs[1];
+        assert_eq!(job.location, "Remote");
+        assert!(job.is_remote());
+        // Without a department, the team is used
+        assert_eq!(job.department.as_deref(), Some("Data"));
+        assert_eq!(job.salary_range.as_ref().map(|s| &s.period), Some(&SalaryPeriod::Hourly));
+        assert_eq!(job.description, "Help us clean up our data pipelines.");
+    }
+}
@@ -1,15 +1,33 @@
 //! Job source implementations for fetching jobs from various platforms
 //!
 //! This module provides scrapers/API clients for various job sources.
-//! Each source implements the `JobSourceProvider` trait.
+//! Each source implements the `JobSourceProvider` trait and makes its
+//! requests through [`http::SourceHttpClient`], which rate limits and
+//! caches them.
 
+pub mod http;
 pub mod remotive;
 pub mod hn_who_is_hiring;
 pub mod arbeitnow;
+pub mod boards;
+pub mod greenhouse;
+pub mod lever;
+pub mod ashby;
+pub mod workable;
+pub mod smartrecruiters;
 
-use crate::models::Job;
+use crate::models::{CompanyBoard, Job, JobSource, SourceFetchReport};
+use crate::services::skill_taxonomy::SkillTaxonomy;
 use anyhow::Result;
 use async_trait::async_trait;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// How long a source may take to answer before it is given up on
+pub const DEFAULT_SOURCE_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// How often a source is fetched in the background
+pub const DEFAULT_FETCH_INTERVAL: Duration = Duration::from_secs(60 * 60);
 
 /// Trait for job source providers
 #[async_trait]
@@ -29,11 +47,32 @@ pub trait JobSourceProvider: Send + Sync {
     
     /// Get the source name
     fn source_name(&self) -> &'static str;
+    
+    /// How long a fetch from this source may take
+    fn timeout(&self) -> Duration {
+        DEFAULT_SOURCE_TIMEOUT
+    }
+    
+    /// How often the job scheduler fetches from this source
+    fn fetch_interval(&self) -> Duration {
+        DEFAULT_FETCH_INTERVAL
+    }
+}
+
+/// Jobs fetched from all sources, with a report for each source
+#[derive(Debug, Default)]
+pub struct AggregatedJobs {
+    pub jobs: Vec<Job>,
+    pub reports: Vec<SourceFetchReport>,
 }
 
 /// Aggregate job fetcher that pulls from multiple sources
+///
+/// The requirements of every fetched job are mapped onto the skill
+/// taxonomy, so that jobs from all sources use the same skill names.
 pub struct JobAggregator {
     sources: Vec<Box<dyn JobSourceProvider>>,
+    taxonomy: Arc<SkillTaxonomy>,
 }
 
 impl JobAggregator {
@@ -45,36 +84,257 @@ impl JobAggregator {
             Box::new(arbeitnow::ArbeitnowClient::new()),
         ];
         
-        Self { sources }
+        Self { sources, taxonomy: SkillTaxonomy::builtin() }
+    }
+    
+    /// Fetch from `sources` instead of the default ones
+    pub fn with_sources(mut self, sources: Vec<Box<dyn JobSourceProvider>>) -> Self {
+        self.sources = sources;
+        self
+    }
+    
+    /// Use `taxonomy`, e.g. one including the user's custom skills,
+    /// to tag job requirements
+    pub fn with_taxonomy(mut self, taxonomy: Arc<SkillTaxonomy>) -> Self {
+        self.taxonomy = taxonomy;
+        self
+    }
+    
+    /// Also fetch the company boards on the user's watchlist, with one
+    /// client for each applicant tracking system that has boards
+    pub fn with_company_boards(mut self, boards: &[CompanyBoard]) -> Self {
+        for source in CompanyBoard::SOURCES {
+            let source_boards: Vec<CompanyBoard> = boards.iter().filter(|b| b.source == source).cloned().collect();
+            if source_boards.is_empty() {
+                continue;
+            }
+            let client: Box<dyn JobSourceProvider> = match source {
+                JobSource::Greenhouse => Box::new(greenhouse::GreenhouseClient::new(source_boards)),
+                JobSource::Lever => Box::new(lever::LeverClient::new(source_boards)),
+                JobSource::Ashby => Box::new(ashby::AshbyClient::new(source_boards)),
+                JobSource::Workable => Box::new(workable::WorkableClient::new(source_boards)),
+                JobSource::SmartRecruiters => Box::new(smartrecruiters::SmartRecruitersClient::new(source_boards)),
+                _ => continue,
+            };
+            self.sources.push(client);
+        }
+        self
     }
     
-    /// Fetch jobs from all sources
+    /// The sources jobs are fetched from
+    pub fn sources(&self) -> &[Box<dyn JobSourceProvider>] {
+        &self.sources
+    }
+    
+    /// Fetch jobs from all sources at once
+    ///
+    /// Each source is given its own [`JobSourceProvider::timeout`]; a source
+    /// that fails or times out is reported and the others' jobs are kept.
     pub async fn fetch_all(
         &self,
         keywords: Option<&str>,
         location: Option<&str>,
         limit_per_source: Option<u32>,
-    ) -> Result<Vec<Job>> {
-        let mut all_jobs = Vec::new();
+    ) -> AggregatedJobs {
+        self.fetch(self.sources.iter(), keywords, location, limit_per_source).await
+    }
+    
+    /// Fetch jobs from the source named `source` only
+    pub async fn fetch_from(
+        &self,
+        source: &str,
+        keywords: Option<&str>,
+        location: Option<&str>,
+        limit: Option<u32>,
+    ) -> AggregatedJobs {
+        let sources = self.sources.iter().filter(|s| s.source_name() == source);
+        self.fetch(sources, keywords, location, limit).await
+    }
+    
+    async fn fetch<'a>(
+        &self,
+        sources: impl Iterator<Item = &'a Box<dyn JobSourceProvider>>,
+        keywords: Option<&str>,
+        location: Option<&str>,
+        limit_per_source: Option<u32>,
+    ) -> AggregatedJobs {
+        let fetches = sources.map(|source| async move {
+            let started = Instant::now();
+            let result = tokio::time::timeout(
+                source.timeout(),
+                source.fetch_jobs(keywords, location, limit_per_source),
+            )
+            .await
+            .unwrap_or_else(|_| Err(anyhow::anyhow!("Timed out after {}s", source.timeout().as_secs_f32())));
+            (source.source_name(), started.elapsed(), result)
+        });
         
-        for source in &self.sources {
-            match source.fetch_jobs(keywords, location, limit_per_source).await {
+        let mut aggregated = AggregatedJobs::default();
+        for (name, duration, result) in futures::future::join_all(fetches).await {
+            let mut report = SourceFetchReport {
+                source: name.to_string(),
+                jobs: 0,
+                duration_ms: duration.as_millis() as u64,
+                error: None,
+            };
+            match result {
                 Ok(jobs) => {
-                    tracing::info!("Fetched {} jobs from {}", jobs.len(), source.source_name());
-                    all_jobs.extend(jobs);
+                    tracing::info!("Fetched {} jobs from {} in {:?}", jobs.len(), name, duration);
+                    report.jobs = jobs.len() as u32;
+                    aggregated.jobs.extend(jobs.into_iter().map(|job| tag_requirements(job, &self.taxonomy)));
                 }
                 Err(e) => {
-                    tracing::warn!("Failed to fetch from {}: {}", source.source_name(), e);
+                    tracing::warn!("Failed to fetch from {}: {}", name, e);
+                    report.error = Some(e.to_string());
                 }
             }
+            aggregated.reports.push(report);
         }
         
-        Ok(all_jobs)
+        aggregated
     }
 }
 
+/// The default sources and the user's company boards, tagging requirements
+/// with the user's skill taxonomy
+pub async fn load_job_aggregator(db: &crate::db::Database) -> Result<JobAggregator> {
+    use crate::db::{CompanyBoardRepository, SqliteCompanyBoardRepository};
+    use crate::services::skill_taxonomy::load_skill_taxonomy;
+    
+    let taxonomy = load_skill_taxonomy(db).await?;
+    let boards = SqliteCompanyBoardRepository::new(db.clone()).get_all().await?;
+    Ok(JobAggregator::new()
+        .with_taxonomy(taxonomy)
+        .with_company_boards(&boards))
+}
+
+/// Replace a job's requirements with canonical skill names
+///
+/// Tags supplied by the source are kept, under their canonical name where
+/// the taxonomy knows them, and skills mentioned in the title or
+/// description are added.
+fn tag_requirements(mut job: Job, taxonomy: &SkillTaxonomy) -> Job {
+    let mut requirements = job.requirements.clone();
+    requirements.extend(taxonomy.skill_names(&job.title));
+    requirements.extend(taxonomy.skill_names(&job.description));
+    job.requirements = taxonomy.normalize(&requirements);
+    job
+}
+
+/// Whether a job matches the keywords and location of a search, for
+/// sources that are searched locally
+///
+/// Every keyword must appear in the title, description or department.
+fn matches_search(job: &Job, keywords: Option<&str>, location: Option<&str>) -> bool {
+    let matches_keywords = keywords.is_none_or(|kw| {
+        let text = [job.title.as_str(), job.description.as_str(), job.department.as_deref().unwrap_or("")]
+            .join(" ")
+            .to_lowercase();
+        kw.to_lowercase().split_whitespace().all(|term| text.contains(term))
+    });
+    let matches_location = location.is_none_or(|loc| {
+        let loc = loc.to_lowercase();
+        job.location.to_lowercase().contains(&loc) || (loc == "remote" && job.is_remote())
+    });
+    matches_keywords && matches_location
+}
+
 impl Default for JobAggregator {
     fn default() -> Self {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::job;
+
+    #[test]
+    fn test_tag_requirements() {
+        let job = Job {
+            description: "We run k8s on AWS with Postgres.".to_string(),
+            requirements: vec!["golang".to_string(), "kubernetes".to_string(), "Full Time".to_string()],
+            ..job("Senior Golang Engineer", "Acme", "Remote", JobSource::Remotive)
+        };
+
+        let job = tag_requirements(job, &SkillTaxonomy::builtin());
+        assert_eq!(job.requirements, vec!["Go", "Kubernetes", "Full Time", "AWS", "PostgreSQL"]);
+    }
+
+    #[test]
+    fn test_every_keyword_must_match() {
+        let job = Job {
+            description: "Build our payments backend in Rust.".to_string(),
+            ..job("Senior Engineer", "Acme", "Worldwide", JobSource::Remotive)
+        };
+
+        assert!(matches_search(&job, Some("senior rust"), None));
+        assert!(matches_search(&job, Some("  Rust   Payments "), Some("worldwide")));
+        assert!(!matches_search(&job, Some("senior python"), None));
+        assert!(!matches_search(&job, Some("senior rust"), Some("berlin")));
+    }
+
+    /// A source that answers after `delay` with `jobs` jobs, or fails
+    struct FakeSource {
+        name: &'static str,
+        delay: Duration,
+        jobs: Option<usize>,
+    }
+
+    #[async_trait]
+    impl JobSourceProvider for FakeSource {
+        async fn fetch_jobs(&self, _: Option<&str>, _: Option<&str>, _: Option<u32>) -> Result<Vec<Job>> {
+            tokio::time::sleep(self.delay).await;
+            let count = self.jobs.ok_or_else(|| anyhow::anyhow!("Service unavailable"))?;
+            Ok((0..count)
+                .map(|i| job(&format!("{} job {}", self.name, i), "Acme", "Remote", JobSource::Other(self.name.to_string())))
+                .collect())
+        }
+
+        fn source_name(&self) -> &'static str {
+            self.name
+        }
+
+        fn timeout(&self) -> Duration {
+            Duration::from_millis(500)
+        }
+    }
+
+    #[test]
+    fn test_with_company_boards() {
+        let names = |aggregator: &JobAggregator| -> Vec<&'static str> {
+            aggregator.sources().iter().map(|s| s.source_name()).collect()
+        };
+        assert_eq!(names(&JobAggregator::new().with_company_boards(&[])), vec!["Remotive", "HN Who's Hiring", "Arbeitnow"]);
+        
+        let aggregator = JobAggregator::new().with_company_boards(&[
+            CompanyBoard::new(JobSource::SmartRecruiters, "HooliGroup"),
+            CompanyBoard::new(JobSource::Lever, "globex"),
+            CompanyBoard::new(JobSource::Lever, "initech"),
+        ]);
+        assert_eq!(names(&aggregator), vec!["Remotive", "HN Who's Hiring", "Arbeitnow", "Lever", "SmartRecruiters"]);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_all_reports_each_source() {
+        let aggregator = JobAggregator::new().with_sources(vec![
+            Box::new(FakeSource { name: "fast", delay: Duration::ZERO, jobs: Some(2) }),
+            Box::new(FakeSource { name: "broken", delay: Duration::ZERO, jobs: None }),
+            Box::new(FakeSource { name: "slow", delay: Duration::from_secs(60), jobs: Some(1) }),
+            Box::new(FakeSource { name: "steady", delay: Duration::from_millis(100), jobs: Some(1) }),
+        ]);
+
+        let started = Instant::now();
+        let result = aggregator.fetch_all(None, None, None).await;
+        // Sources are fetched together, so the slow one only costs its timeout
+        assert!(started.elapsed() < Duration::from_secs(5));
+
+        assert_eq!(result.jobs.len(), 3);
+        let reports: Vec<_> = result.reports.iter().map(|r| (r.source.as_str(), r.jobs, r.error.is_some())).collect();
+        assert_eq!(reports, vec![("fast", 2, false), ("broken", 0, true), ("slow", 0, true), ("steady", 1, false)]);
+        assert_eq!(result.reports[1].error.as_deref(), Some("Service unavailable"));
+        assert!(result.reports[2].error.as_deref().unwrap().starts_with("Timed out"));
+        assert!(result.reports[3].duration_ms >= 100);
+    }
+}
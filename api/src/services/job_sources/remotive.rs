@@ -4,25 +4,41 @@
 //! API Documentation: https://remotive.com/api-documentation
 //! No API key required (rate limited to ~4 requests/day recommended)
 
-use super::JobSourceProvider;
+use super::http::{SourceHttpClient, SourcePolicy};
+use super::{matches_search, JobSourceProvider};
 use crate::models::{Job, JobSource, SalaryRange, SalaryPeriod};
 use anyhow::{Context, Result};
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use serde::Deserialize;
+use std::time::Duration;
 use uuid::Uuid;
 
 const REMOTIVE_API_URL: &str = "https://remotive.com/api/remote-jobs";
 
 /// Client for the Remotive job board API
 pub struct RemotiveClient {
-    client: reqwest::Client,
+    http: SourceHttpClient,
 }
 
 impl RemotiveClient {
     pub fn new() -> Self {
+        Self::with_policy(Self::default_policy())
+    }
+    
+    pub fn with_policy(policy: SourcePolicy) -> Self {
         Self {
-            client: reqwest::Client::new(),
+            http: SourceHttpClient::new("Remotive", policy),
+        }
+    }
+    
+    /// Remotive asks for no more than about four requests a day
+    pub fn default_policy() -> SourcePolicy {
+        SourcePolicy {
+            min_interval: Duration::from_secs(6 * 60 * 60),
+            cache_ttl: Duration::from_secs(6 * 60 * 60),
+            max_retries: 0,
+            ..SourcePolicy::default()
         }
     }
 }
@@ -64,33 +80,21 @@ impl JobSourceProvider for RemotiveClient {
     async fn fetch_jobs(
         &self,
         keywords: Option<&str>,
-        _location: Option<&str>,
+        location: Option<&str>,
         limit: Option<u32>,
     ) -> Result<Vec<Job>> {
-        let mut url = reqwest::Url::parse(REMOTIVE_API_URL)?;
-        
-        // Remotive supports category and search filters
-        if let Some(kw) = keywords {
-            url.query_pairs_mut().append_pair("search", kw);
-        }
-        
-        if let Some(lim) = limit {
-            url.query_pairs_mut().append_pair("limit", &lim.to_string());
-        }
-        
-        let response: RemotiveResponse = self.client
-            .get(url)
-            .header("User-Agent", "employment-barage/1.0")
-            .send()
+        // Every search is answered from the one cached feed and filtered here:
+        // with a few requests a day, a request per keyword would soon be refused
+        let response: RemotiveResponse = self.http
+            .get_json(REMOTIVE_API_URL)
             .await
-            .context("Failed to fetch from Remotive API")?
-            .json()
-            .await
-            .context("Failed to parse Remotive response")?;
+            .context("Failed to fetch from Remotive API")?;
         
         let jobs = response.jobs
             .into_iter()
             .map(|rj| convert_remotive_job(rj))
+            .filter(|job| matches_search(job, keywords, location))
+            .take(limit.map_or(usize::MAX, |l| l as usize))
             .collect();
         
         Ok(jobs)
@@ -99,6 +103,11 @@ impl JobSourceProvider for RemotiveClient {
     fn source_name(&self) -> &'static str {
         "Remotive"
     }
+    
+    /// Remotive asks for no more than a few requests a day
+    fn fetch_interval(&self) -> Duration {
+        Duration::from_secs(6 * 60 * 60)
+    }
 }
 
 fn convert_remotive_job(rj: RemotiveJob) -> Job {
@@ -121,11 +130,13 @@ fn convert_remotive_job(rj: RemotiveJob) -> Job {
         } else {
             format!("Remote - {}", rj.candidate_required_location)
         },
+        department: None,
         description: clean_html(&rj.description),
         requirements,
         salary_range,
-        source: JobSource::Other("Remotive".to_string()),
+        source: JobSource::Remotive,
         source_url: rj.url,
+        source_job_id: Some(rj.id.to_string()),
         posted_date,
         scraped_at: Utc::now(),
     }
@@ -0,0 +1,241 @@
+//! SmartRecruiters posting API client
+//!
+//! Companies hiring through SmartRecruiters publish their open positions
+//! through the public posting API. It is free and requires no
+//! authentication.
+//! API URL: https://api.smartrecruiters.com/v1/companies/{token}/postings
+//!
+//! The posting list has no job descriptions; fetching them would cost a
+//! request per posting, so jobs are matched on their title and department.
+
+use super::boards::{boards_timeout, fetch_boards, remote_location, BOARD_FETCH_INTERVAL};
+use super::http::{SourceHttpClient, SourcePolicy};
+use super::{matches_search, JobSourceProvider};
+use crate::models::{CompanyBoard, Job, JobSource};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use std::time::Duration;
+use uuid::Uuid;
+
+const SMARTRECRUITERS_API_URL: &str = "https://api.smartrecruiters.com/v1/companies";
+
+/// Postings per page, the most the API returns at once
+const PAGE_SIZE: u32 = 100;
+
+/// Pages fetched per company at most
+const MAX_PAGES: u32 = 5;
+
+/// Client for the SmartRecruiters postings of the companies on the watchlist
+pub struct SmartRecruitersClient {
+    http: SourceHttpClient,
+    boards: Vec<CompanyBoard>,
+}
+
+impl SmartRecruitersClient {
+    pub fn new(boards: Vec<CompanyBoard>) -> Self {
+        Self::with_policy(boards, Self::default_policy())
+    }
+
+    pub fn with_policy(boards: Vec<CompanyBoard>, policy: SourcePolicy) -> Self {
+        Self {
+            http: SourceHttpClient::new("SmartRecruiters", policy),
+            boards,
+        }
+    }
+
+    pub fn default_policy() -> SourcePolicy {
+        SourcePolicy {
+            min_interval: Duration::from_millis(250),
+            cache_ttl: Duration::from_secs(60 * 60),
+            ..SourcePolicy::default()
+        }
+    }
+
+    async fn fetch_board(&self, board: &CompanyBoard) -> Result<Vec<Job>> {
+        let mut jobs = Vec::new();
+
+        for page in 0..MAX_PAGES {
+            let offset = page * PAGE_SIZE;
+            let url = format!(
+                "{}/{}/postings?limit={}&offset={}",
+                SMARTRECRUITERS_API_URL, board.token, PAGE_SIZE, offset
+            );
+            let response: SmartRecruitersResponse = self.http
+                .get_json(&url)
+                .await
+                .with_context(|| format!("Failed to fetch SmartRecruiters postings of '{}'", board.token))?;
+
+            let count = response.content.len() as u32;
+            jobs.extend(response.content.into_iter().map(|posting| convert_posting(posting, board)));
+
+            if count < PAGE_SIZE || offset + count >= response.total_found {
+                break;
+            }
+        }
+
+        Ok(jobs)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SmartRecruitersResponse {
+    total_found: u32,
+    content: Vec<SmartRecruitersPosting>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SmartRecruitersPosting {
+    id: String,
+    name: String,
+    company: SmartRecruitersCompany,
+    released_date: String,
+    #[serde(default)]
+    location: SmartRecruitersLocation,
+    #[serde(default)]
+    department: Option<SmartRecruitersLabel>,
+    #[serde(default)]
+    type_of_employment: Option<SmartRecruitersLabel>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SmartRecruitersCompany {
+    identifier: String,
+    #[serde(default)]
+    name: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SmartRecruitersLocation {
+    #[serde(default)]
+    city: Option<String>,
+    #[serde(default)]
+    region: Option<String>,
+    /// Lowercase ISO country code, e.g. "us"
+    #[serde(default)]
+    country: Option<String>,
+    #[serde(default)]
+    remote: bool,
+    #[serde(default)]
+    full_location: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SmartRecruitersLabel {
+    #[serde(default)]
+    label: Option<String>,
+}
+
+#[async_trait]
+impl JobSourceProvider for SmartRecruitersClient {
+    async fn fetch_jobs(
+        &self,
+        keywords: Option<&str>,
+        location: Option<&str>,
+        limit: Option<u32>,
+    ) -> Result<Vec<Job>> {
+        let jobs = fetch_boards("SmartRecruiters", &self.boards, |board| self.fetch_board(board)).await?;
+
+        Ok(jobs
+            .into_iter()
+            .filter(|job| matches_search(job, keywords, location))
+            .take(limit.map_or(usize::MAX, |l| l as usize))
+            .collect())
+    }
+
+    fn source_name(&self) -> &'static str {
+        "SmartRecruiters"
+    }
+
+    /// Large companies take several pages each
+    fn timeout(&self) -> Duration {
+        boards_timeout(&self.boards) * 2
+    }
+
+    fn fetch_interval(&self) -> Duration {
+        BOARD_FETCH_INTERVAL
+    }
+}
+
+fn convert_posting(posting: SmartRecruitersPosting, board: &CompanyBoard) -> Job {
+    // The company's own name beats one guessed from its identifier
+    let company = match (&board.company, posting.company.name) {
+        (None, Some(name)) if !name.trim().is_empty() => name.trim().to_string(),
+        _ => board.company_name(),
+    };
+
+    let place = posting.location;
+    let location = match place.full_location.filter(|l| !l.trim().is_empty()) {
+        Some(full) => full,
+        None => [place.city, place.region, place.country.map(|c| c.to_uppercase())]
+            .into_iter()
+            .flatten()
+            .filter(|part| !part.trim().is_empty())
+            .collect::<Vec<_>>()
+            .join(", "),
+    };
+    let location = remote_location(location, place.remote);
+
+    let label = |field: Option<SmartRecruitersLabel>| field.and_then(|f| f.label).filter(|l| !l.trim().is_empty());
+
+    let posted_date = DateTime::parse_from_rfc3339(&posting.released_date)
+        .map(|date| date.with_timezone(&Utc))
+        .unwrap_or_else(|_| Utc::now());
+
+    Job {
+        id: Uuid::new_v4(),
+        source_url: format!("https://jobs.smartrecruiters.com/{}/{}", posting.company.identifier, posting.id),
+        title: posting.name,
+        company,
+        location,
+        department: label(posting.department),
+        description: String::new(),
+        requirements: label(posting.type_of_employment).into_iter().collect(),
+        salary_range: None,
+        source: JobSource::SmartRecruiters,
+        source_job_id: Some(posting.id),
+        posted_date,
+        scraped_at: Utc::now(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture_jobs(board: &CompanyBoard) -> Vec<Job> {
+        let response: SmartRecruitersResponse =
+            serde_json::from_str(include_str!("../../../tests/fixtures/smartrecruiters_postings.json")).unwrap();
+        assert_eq!(response.total_found, 2);
+        response.content.into_iter().map(|posting| convert_posting(posting, board)).collect()
+    }
+
+    #[test]
+    fn test_convert_posting() {
+        let jobs = fixture_jobs(&CompanyBoard::new(JobSource::SmartRecruiters, "HooliGroup"));
+        let job = &jobs[0];
+        assert_eq!(job.title, "Senior Data Engineer");
+        assert_eq!(job.company, "Hooli Group");
+        assert_eq!(job.location, "Austin, TX, United States");
+        assert_eq!(job.department.as_deref(), Some("Data Platform"));
+        assert_eq!(job.requirements, vec!["Full-time"]);
+        assert_eq!(job.source_url, "https://jobs.smartrecruiters.com/HooliGroup/744000098765432");
+        assert_eq!(job.source_job_id.as_deref(), Some("744000098765432"));
+        assert_eq!(job.posted_date.to_rfc3339(), "2026-09-25T10:15:30+00:00");
+    }
+
+    #[test]
+    fn test_remote_posting_without_department() {
+        let board = CompanyBoard { company: Some("Hooli".to_string()), ..CompanyBoard::new(JobSource::SmartRecruiters, "HooliGroup") };
+        let jobs = fixture_jobs(&board);
+        let job = &jobs[1];
+        assert_eq!(job.company, "Hooli");
+        assert_eq!(job.location, "DE (Remote)");
+        assert_eq!(job.department, None);
+        assert_eq!(job.requirements, vec!["Contract"]);
+    }
+}
@@ -0,0 +1,225 @@
+//! Workable careers page API client
+//!
+//! Companies hiring through Workable publish their open positions on a
+//! careers page backed by a public widget API. It is free and requires no
+//! authentication.
+//! API URL: https://apply.workable.com/api/v1/widget/accounts/{token}
+
+use super::boards::{boards_timeout, fetch_boards, html_to_text, remote_location, BOARD_FETCH_INTERVAL};
+use super::http::{SourceHttpClient, SourcePolicy};
+use super::{matches_search, JobSourceProvider};
+use crate::models::{CompanyBoard, Job, JobSource};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{NaiveDate, Utc};
+use serde::Deserialize;
+use std::time::Duration;
+use uuid::Uuid;
+
+const WORKABLE_API_URL: &str = "https://apply.workable.com/api/v1/widget/accounts";
+
+/// Client for the Workable careers pages of the companies on the watchlist
+pub struct WorkableClient {
+    http: SourceHttpClient,
+    boards: Vec<CompanyBoard>,
+}
+
+impl WorkableClient {
+    pub fn new(boards: Vec<CompanyBoard>) -> Self {
+        Self::with_policy(boards, Self::default_policy())
+    }
+
+    pub fn with_policy(boards: Vec<CompanyBoard>, policy: SourcePolicy) -> Self {
+        Self {
+            http: SourceHttpClient::new("Workable", policy),
+            boards,
+        }
+    }
+
+    /// The widget API answers bursts with 429s, so accounts are fetched slowly
+    pub fn default_policy() -> SourcePolicy {
+        SourcePolicy {
+            min_interval: Duration::from_secs(1),
+            cache_ttl: Duration::from_secs(60 * 60),
+            ..SourcePolicy::default()
+        }
+    }
+
+    async fn fetch_board(&self, board: &CompanyBoard) -> Result<Vec<Job>> {
+        let url = format!("{}/{}?details=true", WORKABLE_API_URL, board.token);
+        let response: WorkableResponse = self.http
+            .get_json(&url)
+            .await
+            .with_context(|| format!("Failed to fetch Workable account '{}'", board.token))?;
+
+        // The account's own name beats one guessed from its token
+        let company = match (&board.company, response.name) {
+            (None, Some(name)) if !name.trim().is_empty() => name.trim().to_string(),
+            _ => board.company_name(),
+        };
+        Ok(response.jobs
+            .into_iter()
+            .map(|wj| convert_workable_job(wj, &company))
+            .collect())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct WorkableResponse {
+    #[serde(default)]
+    name: Option<String>,
+    jobs: Vec<WorkableJob>,
+}
+
+#[derive(Debug, Deserialize)]
+struct WorkableJob {
+    title: String,
+    shortcode: String,
+    #[serde(default)]
+    employment_type: Option<String>,
+    #[serde(default)]
+    telecommuting: bool,
+    #[serde(default)]
+    department: Option<String>,
+    url: String,
+    /// Date only, e.g. "2026-09-20"
+    #[serde(default)]
+    published_on: Option<String>,
+    #[serde(default)]
+    created_at: Option<String>,
+    #[serde(default)]
+    city: Option<String>,
+    #[serde(default)]
+    state: Option<String>,
+    #[serde(default)]
+    country: Option<String>,
+    #[serde(default)]
+    locations: Vec<WorkableLocation>,
+    /// HTML, only included with `details=true`
+    #[serde(default)]
+    description: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct WorkableLocation {
+    #[serde(default)]
+    city: Option<String>,
+    #[serde(default)]
+    region: Option<String>,
+    #[serde(default)]
+    country: Option<String>,
+    #[serde(default)]
+    hidden: bool,
+}
+
+#[async_trait]
+impl JobSourceProvider for WorkableClient {
+    async fn fetch_jobs(
+        &self,
+        keywords: Option<&str>,
+        location: Option<&str>,
+        limit: Option<u32>,
+    ) -> Result<Vec<Job>> {
+        let jobs = fetch_boards("Workable", &self.boards, |board| self.fetch_board(board)).await?;
+
+        Ok(jobs
+            .into_iter()
+            .filter(|job| matches_search(job, keywords, location))
+            .take(limit.map_or(usize::MAX, |l| l as usize))
+            .collect())
+    }
+
+    fn source_name(&self) -> &'static str {
+        "Workable"
+    }
+
+    fn timeout(&self) -> Duration {
+        boards_timeout(&self.boards)
+    }
+
+    fn fetch_interval(&self) -> Duration {
+        BOARD_FETCH_INTERVAL
+    }
+}
+
+fn convert_workable_job(wj: WorkableJob, company: &str) -> Job {
+    let mut places: Vec<String> = wj.locations
+        .iter()
+        .filter(|l| !l.hidden)
+        .map(|l| place_name(&[&l.city, &l.region, &l.country]))
+        .filter(|place| !place.is_empty())
+        .collect();
+    if places.is_empty() {
+        places.push(place_name(&[&wj.city, &wj.state, &wj.country]));
+    }
+    let location = remote_location(places.join("; "), wj.telecommuting);
+
+    let posted_date = [&wj.published_on, &wj.created_at]
+        .into_iter()
+        .flatten()
+        .find_map(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok())
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|date| date.and_utc())
+        .unwrap_or_else(Utc::now);
+
+    Job {
+        id: Uuid::new_v4(),
+        title: wj.title,
+        company: company.to_string(),
+        location,
+        department: wj.department.filter(|d| !d.trim().is_empty()),
+        description: html_to_text(&wj.description),
+        requirements: wj.employment_type.filter(|t| !t.trim().is_empty()).into_iter().collect(),
+        salary_range: None, // Workable doesn't publish pay through the widget API
+        source: JobSource::Workable,
+        source_url: wj.url,
+        source_job_id: Some(wj.shortcode),
+        posted_date,
+        scraped_at: Utc::now(),
+    }
+}
+
+/// "City, Region, Country" from the parts that are set
+fn place_name(parts: &[&Option<String>]) -> String {
+    parts
+        .iter()
+        .filter_map(|part| part.as_deref().map(str::trim))
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture_jobs() -> Vec<Job> {
+        let response: WorkableResponse =
+            serde_json::from_str(include_str!("../../../tests/fixtures/workable_jobs.json")).unwrap();
+        assert_eq!(response.name.as_deref(), Some("Umbrella Labs"));
+        response.jobs.into_iter().map(|wj| convert_workable_job(wj, "Umbrella Labs")).collect()
+    }
+
+    #[test]
+    fn test_convert_workable_job() {
+        let jobs = fixture_jobs();
+        let job = &jobs[0];
+        assert_eq!(job.title, "Machine Learning Engineer");
+        assert_eq!(job.location, "Amsterdam, North Holland, Netherlands");
+        assert_eq!(job.department.as_deref(), Some("Research"));
+        assert_eq!(job.requirements, vec!["Full-time"]);
+        assert_eq!(job.source_job_id.as_deref(), Some("A1B2C3D4E5"));
+        assert_eq!(job.source_url, "https://apply.workable.com/j/A1B2C3D4E5");
+        assert_eq!(job.posted_date.to_rfc3339(), "2026-09-20T00:00:00+00:00");
+        assert_eq!(job.description, "Train models with Python and PyTorch.\n• 3+ years of ML");
+    }
+
+    #[test]
+    fn test_remote_job_without_location() {
+        let jobs = fixture_jobs();
+        let job = &jobs[1];
+        assert_eq!(job.location, "Remote");
+        assert_eq!(job.department, None);
+        assert_eq!(job.requirements, vec!["Contract"]);
+    }
+}
@@ -0,0 +1,654 @@
+//! JSON Resume support
+//!
+//! Types for the JSON Resume schema (https://jsonresume.org/schema) and the
+//! mapping between JSON Resume documents and our profile models, in both
+//! directions. Because the format is already structured, every field is
+//! mapped directly; nothing is guessed from free text.
+
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use uuid::Uuid;
+
+use crate::models::{Education, Experience, FullProfile, Profile, Project, Skill, SkillCategory, SkillLevel};
+use crate::services::resume_parser::{ParseConfidence, ParsedResume};
+use crate::services::skill_taxonomy::SkillTaxonomy;
+
+/// A JSON Resume document
+///
+/// Only the sections that have a counterpart in our models are read;
+/// anything else in the document is ignored. Empty fields are left out when
+/// writing, as the schema does not allow `null`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase", default)]
+pub struct JsonResume {
+    pub basics: Basics,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub work: Vec<WorkEntry>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub education: Vec<EducationEntry>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub skills: Vec<SkillEntry>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub awards: Vec<AwardEntry>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub languages: Vec<LanguageEntry>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub projects: Vec<ProjectEntry>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Basics {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<Location>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub profiles: Vec<SocialProfile>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Location {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub postal_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SocialProfile {
+    pub network: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase", default)]
+pub struct WorkEntry {
+    /// Company name (`company` in older versions of the schema)
+    #[serde(alias = "company")]
+    pub name: String,
+    pub position: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub highlights: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase", default)]
+pub struct EducationEntry {
+    pub institution: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    pub area: String,
+    pub study_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub score: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub courses: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SkillEntry {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub level: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub keywords: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase", default)]
+pub struct AwardEntry {
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub awarder: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase", default)]
+pub struct LanguageEntry {
+    pub language: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fluency: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ProjectEntry {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub highlights: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub keywords: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+impl JsonResume {
+    /// Map the document onto our models
+    ///
+    /// A work entry without an end date is the current position. Skill
+    /// keywords become skills of their own with the level of their group,
+    /// and spoken languages become skills in the `Language` category. Skills
+    /// known to `taxonomy` are given their canonical name and category.
+    pub fn to_parsed_resume(&self, taxonomy: &SkillTaxonomy) -> Result<ParsedResume> {
+        let now = Utc::now();
+        let profile_id = Uuid::new_v4();
+        let basics = &self.basics;
+
+        if basics.name.trim().is_empty() {
+            return Err(anyhow!("JSON Resume has no basics.name"));
+        }
+        // Every field of the schema is optional; without an email the
+        // profile's is left empty for the user to fill in
+        let email = basics.email.clone().unwrap_or_default().trim().to_string();
+
+        let profile = Profile {
+            id: profile_id,
+            user_id: Uuid::new_v4(), // Will be set by the caller
+            name: basics.name.clone(),
+            headline: basics.label.clone(),
+            summary: basics.summary.clone(),
+            phone: basics.phone.clone(),
+            email,
+            location: basics.location.as_ref().and_then(Location::display),
+            linkedin_url: basics.profile_url("linkedin", "https://www.linkedin.com/in/"),
+            github_url: basics.profile_url("github", "https://github.com/"),
+            portfolio_url: basics.url.clone(),
+            created_at: now,
+            updated_at: now,
+        };
+
+        let experiences: Vec<Experience> = self
+            .work
+            .iter()
+            .map(|work| {
+                let end_date = work.end_date.as_deref().and_then(parse_date);
+                Experience {
+                    id: Uuid::new_v4(),
+                    profile_id,
+                    company: work.name.clone(),
+                    position: work.position.clone(),
+                    location: work.location.clone(),
+                    start_date: work.start_date.as_deref().and_then(parse_date).unwrap_or(now),
+                    end_date,
+                    current: end_date.is_none(),
+                    description: work.summary.clone().unwrap_or_default(),
+                    achievements: work.highlights.clone(),
+                    created_at: now,
+                    updated_at: now,
+                }
+            })
+            .collect();
+
+        let education: Vec<Education> = self
+            .education
+            .iter()
+            .map(|entry| Education {
+                id: Uuid::new_v4(),
+                profile_id,
+                institution: entry.institution.clone(),
+                degree: entry.study_type.clone(),
+                field: entry.area.clone(),
+                location: None,
+                start_date: entry.start_date.as_deref().and_then(parse_date).unwrap_or(now),
+                end_date: entry.end_date.as_deref().and_then(parse_date),
+                // Scores are written as "3.8" or "3.8/4.0"
+                gpa: entry.score.as_deref().and_then(|s| s.split('/').next()?.trim().parse().ok()),
+                // Our honors are written as awards from the institution
+                honors: self
+                    .awards
+                    .iter()
+                    .filter(|a| a.awarder.as_deref() == Some(entry.institution.as_str()))
+                    .map(|a| a.title.clone())
+                    .collect(),
+                created_at: now,
+                updated_at: now,
+            })
+            .collect();
+
+        let mut skills = Vec::new();
+        let mut seen = HashSet::new();
+        let mut add_skill = |name: &str, category: SkillCategory, proficiency: SkillLevel| {
+            let name = name.trim();
+            if !name.is_empty() && seen.insert(name.to_lowercase()) {
+                skills.push(Skill {
+                    id: Uuid::new_v4(),
+                    profile_id,
+                    name: name.to_string(),
+                    category,
+                    proficiency,
+                    years_experience: None,
+                    created_at: now,
+                    updated_at: now,
+                });
+            }
+        };
+        for entry in &self.skills {
+            let level = entry.level.as_deref().map(skill_level).unwrap_or(SkillLevel::Intermediate);
+            add_skill(taxonomy.canonical_name(&entry.name), taxonomy.category(&entry.name), level.clone());
+            for keyword in &entry.keywords {
+                add_skill(taxonomy.canonical_name(keyword), taxonomy.category(keyword), level.clone());
+            }
+        }
+        for entry in &self.languages {
+            let level = entry.fluency.as_deref().map(language_level).unwrap_or(SkillLevel::Intermediate);
+            add_skill(&entry.language, SkillCategory::Language, level);
+        }
+
+        let projects: Vec<Project> = self
+            .projects
+            .iter()
+            .map(|entry| {
+                let is_github = entry.url.as_deref().is_some_and(|u| u.contains("github.com"));
+                Project {
+                    id: Uuid::new_v4(),
+                    profile_id,
+                    name: entry.name.clone(),
+                    description: entry.description.clone().unwrap_or_default(),
+                    technologies: entry.keywords.clone(),
+                    url: entry.url.clone().filter(|_| !is_github),
+                    github_url: entry.url.clone().filter(|_| is_github),
+                    start_date: entry.start_date.as_deref().and_then(parse_date).unwrap_or(now),
+                    end_date: entry.end_date.as_deref().and_then(parse_date),
+                    highlights: entry.highlights.clone(),
+                    created_at: now,
+                    updated_at: now,
+                }
+            })
+            .collect();
+
+        // Everything was read from named fields, so nothing needs a second look
+        let mut confidence = ParseConfidence::default();
+        let present = [
+            ("name", true),
+            ("email", !profile.email.is_empty()),
+            ("phone", profile.phone.is_some()),
+            ("location", profile.location.is_some()),
+            ("linkedin_url", profile.linkedin_url.is_some()),
+            ("github_url", profile.github_url.is_some()),
+            ("portfolio_url", profile.portfolio_url.is_some()),
+        ];
+        for (field, _) in present.iter().filter(|(_, present)| *present) {
+            confidence.set_field_without_source(field, 1.0);
+        }
+        let ids = experiences.iter().map(|e| e.id)
+            .chain(education.iter().map(|e| e.id))
+            .chain(skills.iter().map(|s| s.id))
+            .chain(projects.iter().map(|p| p.id));
+        for id in ids {
+            confidence.set_entry_without_source(id, 1.0);
+        }
+
+        Ok(ParsedResume { profile, experiences, education, skills, projects, confidence })
+    }
+
+    /// Write a profile as a JSON Resume document
+    ///
+    /// Education honors become awards from the institution, and skills in the
+    /// `Language` category are listed as spoken languages.
+    pub fn from_full_profile(full: &FullProfile) -> Self {
+        let profile = &full.profile;
+
+        let mut profiles = Vec::new();
+        if let Some(url) = &profile.github_url {
+            profiles.push(SocialProfile::from_url("GitHub", url));
+        }
+        if let Some(url) = &profile.linkedin_url {
+            profiles.push(SocialProfile::from_url("LinkedIn", url));
+        }
+
+        let basics = Basics {
+            name: profile.name.clone(),
+            label: profile.headline.clone(),
+            email: Some(profile.email.clone()),
+            phone: profile.phone.clone(),
+            url: profile.portfolio_url.clone(),
+            summary: profile.summary.clone(),
+            location: profile.location.as_deref().map(Location::from_display),
+            profiles,
+        };
+
+        let work = full
+            .experiences
+            .iter()
+            .map(|exp| WorkEntry {
+                name: exp.company.clone(),
+                position: exp.position.clone(),
+                location: exp.location.clone(),
+                url: None,
+                start_date: Some(format_date(&exp.start_date)),
+                end_date: exp.end_date.filter(|_| !exp.current).as_ref().map(format_date),
+                summary: Some(exp.description.clone()).filter(|d| !d.is_empty()),
+                highlights: exp.achievements.clone(),
+            })
+            .collect();
+
+        let education = full
+            .education
+            .iter()
+            .map(|edu| EducationEntry {
+                institution: edu.institution.clone(),
+                url: None,
+                area: edu.field.clone(),
+                study_type: edu.degree.clone(),
+                start_date: Some(format_date(&edu.start_date)),
+                end_date: edu.end_date.as_ref().map(format_date),
+                score: edu.gpa.map(|gpa| gpa.to_string()),
+                courses: Vec::new(),
+            })
+            .collect();
+
+        let awards = full
+            .education
+            .iter()
+            .flat_map(|edu| {
+                edu.honors.iter().map(|honor| AwardEntry {
+                    title: honor.clone(),
+                    date: edu.end_date.as_ref().map(format_date),
+                    awarder: Some(edu.institution.clone()),
+                    summary: None,
+                })
+            })
+            .collect();
+
+        let (languages, skills): (Vec<&Skill>, Vec<&Skill>) =
+            full.skills.iter().partition(|s| s.category == SkillCategory::Language);
+
+        let projects = full
+            .projects
+            .iter()
+            .map(|project| ProjectEntry {
+                name: project.name.clone(),
+                description: Some(project.description.clone()).filter(|d| !d.is_empty()),
+                highlights: project.highlights.clone(),
+                keywords: project.technologies.clone(),
+                start_date: Some(format_date(&project.start_date)),
+                end_date: project.end_date.as_ref().map(format_date),
+                url: project.url.clone().or_else(|| project.github_url.clone()),
+            })
+            .collect();
+
+        JsonResume {
+            basics,
+            work,
+            education,
+            skills: skills
+                .into_iter()
+                .map(|skill| SkillEntry {
+                    name: skill.name.clone(),
+                    level: Some(format!("{:?}", skill.proficiency)),
+                    keywords: Vec::new(),
+                })
+                .collect(),
+            awards,
+            languages: languages
+                .into_iter()
+                .map(|skill| LanguageEntry {
+                    language: skill.name.clone(),
+                    fluency: Some(language_fluency(&skill.proficiency).to_string()),
+                })
+                .collect(),
+            projects,
+        }
+    }
+}
+
+impl Basics {
+    /// URL of a social profile, built from the username if no URL is given
+    fn profile_url(&self, network: &str, base_url: &str) -> Option<String> {
+        let profile = self.profiles.iter().find(|p| p.network.eq_ignore_ascii_case(network))?;
+        profile
+            .url
+            .clone()
+            .or_else(|| profile.username.as_ref().map(|u| format!("{}{}", base_url, u)))
+    }
+}
+
+impl SocialProfile {
+    fn from_url(network: &str, url: &str) -> Self {
+        let username = url.trim_end_matches('/').rsplit('/').next().filter(|u| !u.contains('.'));
+        SocialProfile {
+            network: network.to_string(),
+            username: username.map(str::to_string),
+            url: Some(url.to_string()),
+        }
+    }
+}
+
+impl Location {
+    /// Inverse of [`Location::display`]
+    fn from_display(location: &str) -> Self {
+        let mut parts = location.split(',').map(|p| p.trim().to_string()).filter(|p| !p.is_empty());
+        Location {
+            city: parts.next(),
+            region: parts.next(),
+            country_code: parts.next(),
+            ..Default::default()
+        }
+    }
+
+    /// "City, Region, CC" from whichever parts are present
+    fn display(&self) -> Option<String> {
+        let parts: Vec<&str> = [&self.city, &self.region, &self.country_code]
+            .into_iter()
+            .filter_map(|p| p.as_deref().map(str::trim).filter(|p| !p.is_empty()))
+            .collect();
+        if parts.is_empty() {
+            None
+        } else {
+            Some(parts.join(", "))
+        }
+    }
+}
+
+/// Parse a JSON Resume date: `YYYY-MM-DD`, `YYYY-MM` or `YYYY`
+fn parse_date(date: &str) -> Option<DateTime<Utc>> {
+    let date = date.trim();
+    let full = match date.len() {
+        4 => format!("{}-01-01", date),
+        7 => format!("{}-01", date),
+        _ => date.get(..10)?.to_string(),
+    };
+    let naive = NaiveDate::parse_from_str(&full, "%Y-%m-%d").ok()?.and_hms_opt(0, 0, 0)?;
+    Some(Utc.from_utc_datetime(&naive))
+}
+
+fn format_date(date: &DateTime<Utc>) -> String {
+    date.format("%Y-%m-%d").to_string()
+}
+
+fn skill_level(level: &str) -> SkillLevel {
+    match level.trim().to_lowercase().as_str() {
+        "beginner" | "novice" | "basic" => SkillLevel::Beginner,
+        "advanced" => SkillLevel::Advanced,
+        "expert" | "master" => SkillLevel::Expert,
+        _ => SkillLevel::Intermediate,
+    }
+}
+
+fn language_level(fluency: &str) -> SkillLevel {
+    let fluency = fluency.to_lowercase();
+    if fluency.contains("native") || fluency.contains("bilingual") || fluency.contains("fluent") {
+        SkillLevel::Expert
+    } else if fluency.contains("professional") || fluency.contains("advanced") {
+        SkillLevel::Advanced
+    } else if fluency.contains("elementary") || fluency.contains("beginner") || fluency.contains("basic") {
+        SkillLevel::Beginner
+    } else {
+        SkillLevel::Intermediate
+    }
+}
+
+fn language_fluency(level: &SkillLevel) -> &'static str {
+    match level {
+        SkillLevel::Beginner => "Elementary proficiency",
+        SkillLevel::Intermediate => "Limited working proficiency",
+        SkillLevel::Advanced => "Professional working proficiency",
+        SkillLevel::Expert => "Native or bilingual proficiency",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Datelike;
+
+    fn fixture() -> ParsedResume {
+        let resume: JsonResume = serde_json::from_str(include_str!("../../tests/fixtures/resume.json")).unwrap();
+        resume.to_parsed_resume(&SkillTaxonomy::builtin()).unwrap()
+    }
+
+    #[test]
+    fn test_basics_are_mapped() {
+        let profile = fixture().profile;
+        assert_eq!(profile.name, "Sam Rivera");
+        assert_eq!(profile.headline.as_deref(), Some("Site Reliability Engineer"));
+        assert_eq!(profile.email, "sam.rivera@example.com");
+        assert_eq!(profile.location.as_deref(), Some("Austin, Texas, US"));
+        assert_eq!(profile.github_url.as_deref(), Some("https://github.com/samrivera"));
+        // Built from the username since the profile has no URL
+        assert_eq!(profile.linkedin_url.as_deref(), Some("https://www.linkedin.com/in/sam-rivera"));
+        assert_eq!(profile.portfolio_url.as_deref(), Some("https://samrivera.dev"));
+    }
+
+    #[test]
+    fn test_work_is_mapped() {
+        let experiences = fixture().experiences;
+        assert_eq!(experiences.len(), 2);
+
+        let current = &experiences[0];
+        assert_eq!(current.company, "Initrode");
+        assert_eq!(current.position, "Senior SRE");
+        assert!(current.current);
+        assert_eq!(current.start_date.year(), 2021);
+        assert_eq!(current.start_date.month(), 3);
+        assert_eq!(current.achievements.len(), 2);
+
+        // The pre-1.0 schema called the employer "company"
+        let previous = &experiences[1];
+        assert_eq!(previous.company, "Umbrella Corp");
+        assert!(!previous.current);
+        assert_eq!(previous.end_date.unwrap().year(), 2021);
+    }
+
+    #[test]
+    fn test_everything_is_fully_confident() {
+        let parsed = fixture();
+        assert_eq!(parsed.confidence.profile_field("email").unwrap().confidence, 1.0);
+        assert!(parsed.confidence.profile_field("email").unwrap().source.is_none());
+        for skill in &parsed.skills {
+            assert_eq!(parsed.confidence.entry(&skill.id).unwrap().confidence, 1.0);
+        }
+    }
+
+    #[test]
+    fn test_education_skills_and_projects_are_mapped() {
+        let parsed = fixture();
+
+        assert_eq!(parsed.education[0].degree, "Bachelor");
+        assert_eq!(parsed.education[0].field, "Computer Engineering");
+        assert_eq!(parsed.education[0].gpa, Some(3.7));
+
+        let skill = |name: &str| parsed.skills.iter().find(|s| s.name == name).unwrap();
+        assert_eq!(skill("Kubernetes").proficiency, SkillLevel::Expert);
+        assert_eq!(skill("Kubernetes").category, SkillCategory::Tool);
+        assert_eq!(skill("Spanish").category, SkillCategory::Language);
+        assert_eq!(skill("Spanish").proficiency, SkillLevel::Expert);
+        // "Go" appears both as a keyword and on its own
+        assert_eq!(parsed.skills.iter().filter(|s| s.name == "Go").count(), 1);
+
+        assert_eq!(parsed.projects[0].github_url.as_deref(), Some("https://github.com/samrivera/canary"));
+        assert_eq!(parsed.projects[0].url, None);
+        assert_eq!(parsed.projects[0].technologies, vec!["Go", "Prometheus"]);
+    }
+
+    #[test]
+    fn test_export_round_trip() {
+        let full = fixture().into_full_profile();
+        assert_eq!(full.education[0].honors, vec!["Dean's List".to_string()]);
+
+        let exported = JsonResume::from_full_profile(&full);
+        let json = serde_json::to_string(&exported).unwrap();
+        assert!(!json.contains("null"));
+        assert_eq!(exported.basics.profiles[1].username.as_deref(), Some("sam-rivera"));
+        assert_eq!(exported.work[0].end_date, None);
+        assert_eq!(exported.work[1].end_date.as_deref(), Some("2021-02-28"));
+        assert_eq!(exported.languages.len(), 2);
+
+        let reimported: JsonResume = serde_json::from_str(&json).unwrap();
+        let parsed = reimported.to_parsed_resume(&SkillTaxonomy::builtin()).unwrap();
+        assert_eq!(parsed.profile.location, full.profile.location);
+        assert_eq!(parsed.profile.linkedin_url, full.profile.linkedin_url);
+        assert_eq!(parsed.experiences[0].start_date, full.experiences[0].start_date);
+        assert!(parsed.experiences[0].current);
+        assert_eq!(parsed.education[0].honors, full.education[0].honors);
+        assert_eq!(parsed.education[0].gpa, Some(3.7));
+
+        let levels = |skills: &[Skill]| skills.iter().map(|s| (s.name.clone(), s.proficiency.clone())).collect::<Vec<_>>();
+        assert_eq!(levels(&parsed.skills), levels(&full.skills));
+    }
+
+    #[test]
+    fn test_missing_email_is_imported_as_empty() {
+        let resume: JsonResume = serde_json::from_str(r#"{"basics": {"name": "No Email"}}"#).unwrap();
+        let parsed = resume.to_parsed_resume(&SkillTaxonomy::builtin()).unwrap();
+        assert_eq!(parsed.profile.name, "No Email");
+        assert_eq!(parsed.profile.email, "");
+        assert!(parsed.confidence.profile_field("email").is_none());
+    }
+
+    #[test]
+    fn test_parse_date() {
+        assert_eq!(parse_date("2020").unwrap().month(), 1);
+        assert_eq!(parse_date("2020-06").unwrap().month(), 6);
+        assert_eq!(parse_date("2020-06-15").unwrap().day(), 15);
+        assert_eq!(parse_date("Present"), None);
+    }
+}
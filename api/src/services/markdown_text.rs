@@ -0,0 +1,99 @@
+//! Markdown document reading
+//!
+//! Turns a Markdown resume into headings, list items and paragraphs for the
+//! resume parser. Inline formatting (emphasis, links, code) is reduced to
+//! its text.
+
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+
+use crate::services::resume_parser::{BlockKind, BlockWriter, DocumentBlock};
+
+/// Read the blocks of a Markdown document in order
+pub fn read_blocks(markdown: &str) -> Vec<DocumentBlock> {
+    let mut writer = BlockWriter::default();
+    let mut list_depth: u8 = 0;
+
+    for event in Parser::new_ext(markdown, Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH) {
+        match event {
+            Event::Start(Tag::Heading { level, .. }) => writer.open(BlockKind::Heading(level as u8)),
+            Event::Start(Tag::List(_)) => {
+                writer.flush();
+                list_depth += 1;
+            }
+            Event::Start(Tag::Item) => writer.open(BlockKind::ListItem(list_depth.saturating_sub(1))),
+            // Paragraphs inside a loose list item belong to the item
+            Event::Start(Tag::Paragraph | Tag::BlockQuote | Tag::CodeBlock(_) | Tag::TableHead | Tag::TableRow) => {
+                let kind = match writer.current() {
+                    Some(item @ BlockKind::ListItem(_)) => item,
+                    _ => BlockKind::Paragraph,
+                };
+                writer.open(kind);
+            }
+            Event::End(TagEnd::List(_)) => {
+                writer.flush();
+                list_depth = list_depth.saturating_sub(1);
+            }
+            Event::End(
+                TagEnd::Heading(_)
+                | TagEnd::Item
+                | TagEnd::Paragraph
+                | TagEnd::BlockQuote
+                | TagEnd::CodeBlock
+                | TagEnd::TableHead
+                | TagEnd::TableRow,
+            ) => writer.close(),
+            Event::End(TagEnd::TableCell) => writer.push_text(" | "),
+            Event::Text(text) | Event::Code(text) => writer.push_text(&text),
+            Event::SoftBreak | Event::HardBreak => writer.push_text(" "),
+            _ => {}
+        }
+    }
+
+    writer.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture() -> Vec<DocumentBlock> {
+        read_blocks(include_str!("../../tests/fixtures/resume.md"))
+    }
+
+    #[test]
+    fn test_headings() {
+        let blocks = fixture();
+        assert_eq!(blocks[0], DocumentBlock { kind: BlockKind::Heading(1), text: "Alex Chen".to_string() });
+        assert!(blocks.contains(&DocumentBlock { kind: BlockKind::Heading(2), text: "Experience".to_string() }));
+        assert!(blocks.contains(&DocumentBlock {
+            kind: BlockKind::Heading(3),
+            text: "Northwind Traders, Backend Engineer".to_string()
+        }));
+    }
+
+    #[test]
+    fn test_inline_formatting_is_reduced_to_text() {
+        let blocks = fixture();
+        assert!(blocks.iter().any(|b| b.text == "alex.chen@example.com | https://github.com/alexchen"));
+    }
+
+    #[test]
+    fn test_nested_lists() {
+        let blocks = read_blocks("- Led the **billing** rewrite\n  - Cut costs by 30%\n- Wrote `docs`\n");
+        assert_eq!(
+            blocks,
+            vec![
+                DocumentBlock { kind: BlockKind::ListItem(0), text: "Led the billing rewrite".to_string() },
+                DocumentBlock { kind: BlockKind::ListItem(1), text: "Cut costs by 30%".to_string() },
+                DocumentBlock { kind: BlockKind::ListItem(0), text: "Wrote docs".to_string() },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_loose_list_items_stay_list_items() {
+        let blocks = read_blocks("- First item\n\n- Second item\n");
+        assert!(blocks.iter().all(|b| b.kind == BlockKind::ListItem(0)));
+        assert_eq!(blocks.len(), 2);
+    }
+}
@@ -0,0 +1,538 @@
+//! Merging an imported resume into an existing profile
+//!
+//! A second resume usually repeats much of what is already stored. Instead
+//! of adding it all again, the import is compared with the profile: jobs at
+//! the same company with overlapping dates, education at the same
+//! institution with overlapping dates, and skills and projects with the same
+//! name (ignoring case) are treated as duplicates and merged into the
+//! existing entry. The result is a diff the user can accept change by
+//! change before anything is written.
+
+use chrono::{DateTime, Utc};
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::models::*;
+use crate::resume_parser::ParsedResume;
+use crate::services::organisation::same_organisation;
+
+/// Proposed changes to a profile from an imported resume
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ProfileMerge {
+    pub profile_id: Uuid,
+    pub fields: Vec<FieldChange>,
+    pub experiences: Vec<MergeChange<Experience>>,
+    pub education: Vec<MergeChange<Education>>,
+    pub skills: Vec<MergeChange<Skill>>,
+    pub projects: Vec<MergeChange<Project>>,
+    /// Imported entries that are already in the profile as they are
+    pub unchanged: usize,
+}
+
+/// A profile field the import has a different value for
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FieldChange {
+    /// Field name, as in [`Profile`]
+    pub field: String,
+    pub current: Option<String>,
+    pub proposed: String,
+    pub accepted: bool,
+}
+
+/// An entry the import adds, or an existing entry it adds to
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MergeChange<T> {
+    /// The duplicate already in the profile, if there is one
+    pub existing: Option<T>,
+    /// What will be saved: the imported entry, or the existing one with the
+    /// import merged in
+    pub proposed: T,
+    /// What the merge changes in the existing entry, for display
+    pub notes: Vec<String>,
+    pub accepted: bool,
+}
+
+impl<T> MergeChange<T> {
+    fn add(entry: T) -> Self {
+        MergeChange { existing: None, proposed: entry, notes: Vec::new(), accepted: true }
+    }
+
+    pub fn is_new(&self) -> bool {
+        self.existing.is_none()
+    }
+}
+
+impl ProfileMerge {
+    /// Whether there is anything to apply
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+            && self.experiences.is_empty()
+            && self.education.is_empty()
+            && self.skills.is_empty()
+            && self.projects.is_empty()
+    }
+
+    /// Set the accepted profile fields on a profile
+    pub fn apply_fields(&self, profile: &mut Profile) {
+        for change in self.fields.iter().filter(|c| c.accepted) {
+            let value = change.proposed.clone();
+            match change.field.as_str() {
+                "name" => profile.name = value,
+                "email" => profile.email = value,
+                "headline" => profile.headline = Some(value),
+                "summary" => profile.summary = Some(value),
+                "phone" => profile.phone = Some(value),
+                "location" => profile.location = Some(value),
+                "linkedin_url" => profile.linkedin_url = Some(value),
+                "github_url" => profile.github_url = Some(value),
+                "portfolio_url" => profile.portfolio_url = Some(value),
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Compare an imported resume with the stored profile
+pub fn propose_merge(current: &FullProfile, imported: &ParsedResume) -> ProfileMerge {
+    let mut merge = ProfileMerge {
+        profile_id: current.profile.id,
+        fields: profile_changes(&current.profile, &imported.profile),
+        experiences: Vec::new(),
+        education: Vec::new(),
+        skills: Vec::new(),
+        projects: Vec::new(),
+        unchanged: 0,
+    };
+
+    for experience in &imported.experiences {
+        let duplicate = current.experiences.iter().find(|e| {
+            same_organisation(&e.company, &experience.company)
+                && overlaps(e.start_date, e.end_date, experience.start_date, experience.end_date)
+        });
+        match duplicate {
+            Some(existing) => merge.unchanged += push_update(&mut merge.experiences, existing, merge_experience(existing, experience)),
+            None => merge.experiences.push(MergeChange::add(experience.clone())),
+        }
+    }
+
+    for education in &imported.education {
+        let duplicate = current.education.iter().find(|e| {
+            same_organisation(&e.institution, &education.institution)
+                && overlaps(e.start_date, e.end_date, education.start_date, education.end_date)
+        });
+        match duplicate {
+            Some(existing) => merge.unchanged += push_update(&mut merge.education, existing, merge_education(existing, education)),
+            None => merge.education.push(MergeChange::add(education.clone())),
+        }
+    }
+
+    for skill in &imported.skills {
+        // Parsers can list a skill twice, so check what is already proposed too
+        if merge.skills.iter().any(|c| c.is_new() && same_text(&c.proposed.name, &skill.name)) {
+            continue;
+        }
+        match current.skills.iter().find(|s| same_text(&s.name, &skill.name)) {
+            Some(existing) => merge.unchanged += push_update(&mut merge.skills, existing, merge_skill(existing, skill)),
+            None => merge.skills.push(MergeChange::add(skill.clone())),
+        }
+    }
+
+    for project in &imported.projects {
+        match current.projects.iter().find(|p| same_text(&p.name, &project.name)) {
+            Some(existing) => merge.unchanged += push_update(&mut merge.projects, existing, merge_project(existing, project)),
+            None => merge.projects.push(MergeChange::add(project.clone())),
+        }
+    }
+
+    merge
+}
+
+/// Propose an update to a duplicate, returning 1 if the import adds nothing
+fn push_update<T: Clone>(changes: &mut Vec<MergeChange<T>>, existing: &T, (merged, notes): (T, Vec<String>)) -> usize {
+    if notes.is_empty() {
+        return 1;
+    }
+    changes.push(MergeChange { existing: Some(existing.clone()), proposed: merged, notes, accepted: true });
+    0
+}
+
+fn profile_changes(current: &Profile, imported: &Profile) -> Vec<FieldChange> {
+    let fields = [
+        ("name", Some(&current.name), Some(&imported.name)),
+        ("email", Some(&current.email), Some(&imported.email)),
+        ("headline", current.headline.as_ref(), imported.headline.as_ref()),
+        ("summary", current.summary.as_ref(), imported.summary.as_ref()),
+        ("phone", current.phone.as_ref(), imported.phone.as_ref()),
+        ("location", current.location.as_ref(), imported.location.as_ref()),
+        ("linkedin_url", current.linkedin_url.as_ref(), imported.linkedin_url.as_ref()),
+        ("github_url", current.github_url.as_ref(), imported.github_url.as_ref()),
+        ("portfolio_url", current.portfolio_url.as_ref(), imported.portfolio_url.as_ref()),
+    ];
+
+    fields
+        .into_iter()
+        .filter_map(|(field, current, proposed)| {
+            let proposed = proposed.map(|p| p.trim()).filter(|p| !p.is_empty())?;
+            let current = current.map(|c| c.trim()).filter(|c| !c.is_empty());
+            if current.is_some_and(|c| same_text(c, proposed)) {
+                return None;
+            }
+            Some(FieldChange {
+                field: field.to_string(),
+                current: current.map(str::to_string),
+                proposed: proposed.to_string(),
+                // Filling a gap is safe; replacing what the user entered is not
+                accepted: current.is_none(),
+            })
+        })
+        .collect()
+}
+
+fn merge_experience(existing: &Experience, imported: &Experience) -> (Experience, Vec<String>) {
+    let mut merged = existing.clone();
+    let mut notes = Vec::new();
+
+    if merged.position.trim().is_empty() && !imported.position.trim().is_empty() {
+        merged.position = imported.position.clone();
+        notes.push("Adds the position".to_string());
+    }
+    if merged.location.is_none() && imported.location.is_some() {
+        merged.location = imported.location.clone();
+        notes.push("Adds the location".to_string());
+    }
+    // A newer resume may show that a job has since ended
+    if merged.current && !imported.current {
+        if let Some(end) = imported.end_date {
+            merged.current = false;
+            merged.end_date = Some(end);
+            notes.push(format!("Ends the job in {}", end.format("%b %Y")));
+        }
+    }
+    if merged.description.trim().is_empty() && !imported.description.trim().is_empty() {
+        merged.description = imported.description.clone();
+        notes.push("Adds a description".to_string());
+    }
+    let added = add_missing(&mut merged.achievements, &imported.achievements);
+    if added > 0 {
+        notes.push(plural(added, "achievement"));
+    }
+
+    touch(&mut merged.updated_at, &notes);
+    (merged, notes)
+}
+
+fn merge_education(existing: &Education, imported: &Education) -> (Education, Vec<String>) {
+    let mut merged = existing.clone();
+    let mut notes = Vec::new();
+
+    if merged.degree.trim().is_empty() && !imported.degree.trim().is_empty() {
+        merged.degree = imported.degree.clone();
+        notes.push("Adds the degree".to_string());
+    }
+    if merged.field.trim().is_empty() && !imported.field.trim().is_empty() {
+        merged.field = imported.field.clone();
+        notes.push("Adds the field of study".to_string());
+    }
+    if merged.location.is_none() && imported.location.is_some() {
+        merged.location = imported.location.clone();
+        notes.push("Adds the location".to_string());
+    }
+    if merged.end_date.is_none() && imported.end_date.is_some() {
+        merged.end_date = imported.end_date;
+        notes.push("Adds the end date".to_string());
+    }
+    if merged.gpa.is_none() && imported.gpa.is_some() {
+        merged.gpa = imported.gpa;
+        notes.push("Adds the GPA".to_string());
+    }
+    let added = add_missing(&mut merged.honors, &imported.honors);
+    if added > 0 {
+        notes.push(plural(added, "honor"));
+    }
+
+    touch(&mut merged.updated_at, &notes);
+    (merged, notes)
+}
+
+fn merge_skill(existing: &Skill, imported: &Skill) -> (Skill, Vec<String>) {
+    let mut merged = existing.clone();
+    let mut notes = Vec::new();
+
+    if level_rank(&imported.proficiency) > level_rank(&merged.proficiency) {
+        merged.proficiency = imported.proficiency.clone();
+        notes.push(format!("Raises proficiency to {:?}", imported.proficiency));
+    }
+    if imported.years_experience > merged.years_experience {
+        merged.years_experience = imported.years_experience;
+        notes.push(format!("Raises experience to {} years", imported.years_experience.unwrap_or_default()));
+    }
+
+    touch(&mut merged.updated_at, &notes);
+    (merged, notes)
+}
+
+fn merge_project(existing: &Project, imported: &Project) -> (Project, Vec<String>) {
+    let mut merged = existing.clone();
+    let mut notes = Vec::new();
+
+    if merged.description.trim().is_empty() && !imported.description.trim().is_empty() {
+        merged.description = imported.description.clone();
+        notes.push("Adds a description".to_string());
+    }
+    if merged.url.is_none() && imported.url.is_some() {
+        merged.url = imported.url.clone();
+        notes.push("Adds the URL".to_string());
+    }
+    if merged.github_url.is_none() && imported.github_url.is_some() {
+        merged.github_url = imported.github_url.clone();
+        notes.push("Adds the GitHub URL".to_string());
+    }
+    let added = add_missing(&mut merged.technologies, &imported.technologies);
+    if added > 0 {
+        notes.push(plural(added, "technology"));
+    }
+    let added = add_missing(&mut merged.highlights, &imported.highlights);
+    if added > 0 {
+        notes.push(plural(added, "highlight"));
+    }
+
+    touch(&mut merged.updated_at, &notes);
+    (merged, notes)
+}
+
+/// Append the items not already in the list, returning how many were added
+fn add_missing(items: &mut Vec<String>, imported: &[String]) -> usize {
+    let before = items.len();
+    for item in imported {
+        if !items.iter().any(|i| same_text(i, item)) {
+            items.push(item.clone());
+        }
+    }
+    items.len() - before
+}
+
+fn touch(updated_at: &mut DateTime<Utc>, notes: &[String]) {
+    if !notes.is_empty() {
+        *updated_at = Utc::now();
+    }
+}
+
+fn plural(count: usize, noun: &str) -> String {
+    match (count, noun.strip_suffix('y')) {
+        (1, _) => format!("Adds 1 {}", noun),
+        (_, Some(stem)) => format!("Adds {} {}ies", count, stem),
+        _ => format!("Adds {} {}s", count, noun),
+    }
+}
+
+fn level_rank(level: &SkillLevel) -> u8 {
+    match level {
+        SkillLevel::Beginner => 0,
+        SkillLevel::Intermediate => 1,
+        SkillLevel::Advanced => 2,
+        SkillLevel::Expert => 3,
+    }
+}
+
+fn same_text(a: &str, b: &str) -> bool {
+    a.trim().to_lowercase() == b.trim().to_lowercase()
+}
+
+/// Whether two periods overlap; a missing end means the period is ongoing
+fn overlaps(
+    a_start: DateTime<Utc>,
+    a_end: Option<DateTime<Utc>>,
+    b_start: DateTime<Utc>,
+    b_end: Option<DateTime<Utc>>,
+) -> bool {
+    let now = Utc::now();
+    a_start <= b_end.unwrap_or(now) && b_start <= a_end.unwrap_or(now)
+}
+
+/// Compare an uploaded resume with the user's profile
+///
+/// Returns `None` when the user has no profile yet, in which case the resume
+/// can be saved as it is with [`crate::save_reviewed_resume`].
+#[server(PreviewResumeMerge)]
+pub async fn preview_resume_merge(user_id: String, resume: ParsedResume) -> Result<Option<ProfileMerge>, ServerFnError> {
+    let current = crate::get_full_profile(user_id).await?;
+    Ok(current.map(|current| propose_merge(&current, &resume)))
+}
+
+/// Apply the accepted changes of a merge to the user's profile
+#[server(ApplyResumeMerge)]
+pub async fn apply_resume_merge(user_id: String, merge: ProfileMerge) -> Result<FullProfile, ServerFnError> {
+    use crate::db::{
+        get_database,
+        SqliteProfileRepository, ProfileRepository,
+        SqliteExperienceRepository, ExperienceRepository,
+        SqliteEducationRepository, EducationRepository,
+        SqliteSkillRepository, SkillRepository,
+        SqliteProjectRepository, ProjectRepository,
+    };
+
+    let user_uuid = Uuid::parse_str(&user_id)
+        .map_err(|e| ServerFnError::new(format!("Invalid user ID: {}", e)))?;
+
+    let db = get_database();
+    let profile_repo = SqliteProfileRepository::new(db.clone());
+    let mut profile = profile_repo.get_by_user_id(&user_uuid).await
+        .map_err(|e| ServerFnError::new(e.to_string()))?
+        .filter(|p| p.id == merge.profile_id)
+        .ok_or_else(|| ServerFnError::new("The merge is for a different profile".to_string()))?;
+
+    if merge.fields.iter().any(|c| c.accepted) {
+        merge.apply_fields(&mut profile);
+        profile.updated_at = Utc::now();
+        profile_repo.update(&profile).await
+            .map_err(|e| ServerFnError::new(e.to_string()))?;
+    }
+
+    let exp_repo = SqliteExperienceRepository::new(db.clone());
+    for change in merge.experiences.into_iter().filter(|c| c.accepted) {
+        let mut experience = change.proposed;
+        experience.profile_id = profile.id;
+        let result = match change.existing {
+            Some(_) => exp_repo.update(&experience).await,
+            None => exp_repo.create(&experience).await,
+        };
+        result.map_err(|e| ServerFnError::new(e.to_string()))?;
+    }
+
+    let edu_repo = SqliteEducationRepository::new(db.clone());
+    for change in merge.education.into_iter().filter(|c| c.accepted) {
+        let mut education = change.proposed;
+        education.profile_id = profile.id;
+        let result = match change.existing {
+            Some(_) => edu_repo.update(&education).await,
+            None => edu_repo.create(&education).await,
+        };
+        result.map_err(|e| ServerFnError::new(e.to_string()))?;
+    }
+
+    let skill_repo = SqliteSkillRepository::new(db.clone());
+    for change in merge.skills.into_iter().filter(|c| c.accepted) {
+        let mut skill = change.proposed;
+        skill.profile_id = profile.id;
+        let result = match change.existing {
+            Some(_) => skill_repo.update(&skill).await,
+            None => skill_repo.create(&skill).await,
+        };
+        result.map_err(|e| ServerFnError::new(e.to_string()))?;
+    }
+
+    let project_repo = SqliteProjectRepository::new(db.clone());
+    for change in merge.projects.into_iter().filter(|c| c.accepted) {
+        let mut project = change.proposed;
+        project.profile_id = profile.id;
+        let result = match change.existing {
+            Some(_) => project_repo.update(&project).await,
+            None => project_repo.create(&project).await,
+        };
+        result.map_err(|e| ServerFnError::new(e.to_string()))?;
+    }
+
+    crate::get_full_profile(user_id)
+        .await?
+        .ok_or_else(|| ServerFnError::new("No profile found".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{date, experience, full_profile, profile, skill};
+
+    fn current() -> FullProfile {
+        let mut acme = experience("Acme Corp", date(2019, 1), None);
+        acme.achievements.push("Led the payments team".to_string());
+        full_profile(
+            vec![skill("Rust", SkillLevel::Advanced), skill("SQL", SkillLevel::Beginner)],
+            vec![acme, experience("Initech", date(2015, 6), Some(date(2018, 12)))],
+        )
+    }
+
+    fn imported(experiences: Vec<Experience>, skills: Vec<Skill>) -> ParsedResume {
+        let mut profile = profile("Jordan Lee", "jordan@example.com");
+        profile.phone = Some("555-0100".to_string());
+        ParsedResume {
+            profile,
+            experiences,
+            education: Vec::new(),
+            skills,
+            projects: Vec::new(),
+            confidence: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_same_company_with_overlapping_dates_is_merged() {
+        let mut acme = experience("ACME, Inc.", date(2019, 1), Some(date(2023, 6)));
+        acme.current = false;
+        acme.achievements = vec!["led the payments team".to_string(), "Cut fraud by 40%".to_string()];
+        let merge = propose_merge(&current(), &imported(vec![acme], Vec::new()));
+
+        assert_eq!(merge.experiences.len(), 1);
+        let change = &merge.experiences[0];
+        assert!(!change.is_new());
+        assert!(!change.proposed.current);
+        assert_eq!(change.proposed.end_date, Some(date(2023, 6)));
+        assert_eq!(change.proposed.achievements, vec!["Led the payments team", "Cut fraud by 40%"]);
+        assert_eq!(change.notes, vec!["Ends the job in Jun 2023", "Adds 1 achievement"]);
+    }
+
+    #[test]
+    fn test_same_company_at_another_time_is_added() {
+        let merge = propose_merge(&current(), &imported(vec![experience("Initech", date(2010, 1), Some(date(2012, 1)))], Vec::new()));
+        assert_eq!(merge.experiences.len(), 1);
+        assert!(merge.experiences[0].is_new());
+    }
+
+    #[test]
+    fn test_duplicates_that_add_nothing_are_unchanged() {
+        let merge = propose_merge(
+            &current(),
+            &imported(
+                vec![experience("Initech", date(2016, 1), Some(date(2018, 1)))],
+                vec![skill("rust", SkillLevel::Intermediate)],
+            ),
+        );
+        assert!(merge.experiences.is_empty());
+        assert!(merge.skills.is_empty());
+        assert_eq!(merge.unchanged, 2);
+    }
+
+    #[test]
+    fn test_skills_match_case_insensitively() {
+        let merge = propose_merge(
+            &current(),
+            &imported(
+                Vec::new(),
+                vec![skill("sql", SkillLevel::Expert), skill("Go", SkillLevel::Intermediate), skill("go", SkillLevel::Beginner)],
+            ),
+        );
+        assert_eq!(merge.skills.len(), 2);
+        assert_eq!(merge.skills[0].proposed.name, "SQL");
+        assert_eq!(merge.skills[0].proposed.proficiency, SkillLevel::Expert);
+        assert!(merge.skills[1].is_new());
+    }
+
+    #[test]
+    fn test_profile_fields() {
+        let mut resume = imported(Vec::new(), Vec::new());
+        resume.profile.email = "jordan.lee@example.org".to_string();
+        let merge = propose_merge(&current(), &resume);
+
+        // Gaps are filled by default, existing values are only replaced on request
+        let email = merge.fields.iter().find(|c| c.field == "email").unwrap();
+        assert!(!email.accepted);
+        let phone = merge.fields.iter().find(|c| c.field == "phone").unwrap();
+        assert!(phone.accepted);
+        assert!(!merge.fields.iter().any(|c| c.field == "name"));
+
+        let mut profile = current().profile;
+        merge.apply_fields(&mut profile);
+        assert_eq!(profile.email, "jordan@example.com");
+        assert_eq!(profile.phone.as_deref(), Some("555-0100"));
+    }
+}
@@ -1,7 +1,14 @@
 pub mod job_service;
+pub mod saved_search_service;
 pub mod profile_service;
+pub mod merge_service;
+pub mod organisation;
 pub mod resume_service;
 pub mod resume_parser;
+pub mod skill_taxonomy;
+pub mod job_matcher;
+pub mod json_resume;
+pub mod europass;
 
 #[cfg(not(target_arch = "wasm32"))]
 pub mod ai_service;
@@ -9,20 +16,46 @@ pub mod ai_service;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod job_sources;
 
+#[cfg(not(target_arch = "wasm32"))]
+pub mod job_scheduler;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub mod pdf_text;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub mod docx_text;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub mod markdown_text;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub mod html_text;
+
 // Re-export server functions and types for easy importing
 pub use job_service::{
-    search_jobs, get_saved_jobs, save_job, unsave_job,
+    search_jobs, get_job, get_saved_jobs, save_job, unsave_job,
     generate_cover_letter, apply_to_job, get_user_applications, 
-    update_application_status, CoverLetterTone,
-    fetch_external_jobs, FetchJobsResult
+    update_application_status, get_application_timeline, CoverLetterTone,
+    fetch_external_jobs, FetchJobsResult, get_fetch_schedule, FetchSchedule,
+    get_company_boards, add_company_board, remove_company_board
 };
 pub use profile_service::*;
+pub use saved_search_service::{
+    save_search, get_saved_searches, get_saved_search_new_jobs, mark_saved_search_seen,
+    delete_saved_search
+};
+pub use merge_service::*;
 pub use resume_service::*;
 pub use resume_parser::*;
+pub use skill_taxonomy::*;
+pub use job_matcher::*;
 
 // Job sources are server-side only
 #[cfg(not(target_arch = "wasm32"))]
 pub use job_sources::{JobSourceProvider, JobAggregator};
 
+#[cfg(not(target_arch = "wasm32"))]
+pub use job_scheduler::start_job_scheduler;
+
 // AI service is available but not re-exported at top level to avoid conflicts
 // Use api::services::ai_service::* when needed
\ No newline at end of file
@@ -0,0 +1,46 @@
+//! Comparing the names of companies and schools
+//!
+//! The same organisation is written many ways ("Acme Corp", "ACME, Inc.",
+//! "Acme GmbH"). Both resume merging and job deduplication compare names
+//! after dropping case, punctuation and legal suffixes.
+
+/// Words that don't tell two organisations apart ("Acme Corp" is "Acme, Inc.")
+const ORGANISATION_SUFFIXES: [&str; 12] = [
+    "inc", "incorporated", "llc", "ltd", "limited", "corp", "corporation", "co", "company", "gmbh", "ag", "se",
+];
+
+/// Organisation name in lowercase without punctuation or legal suffixes
+pub fn normalize_organisation(name: &str) -> String {
+    name.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty() && !ORGANISATION_SUFFIXES.contains(word))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Whether two names are of the same organisation; names that are only a
+/// suffix match nothing
+pub fn same_organisation(a: &str, b: &str) -> bool {
+    let a = normalize_organisation(a);
+    !a.is_empty() && a == normalize_organisation(b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_organisation() {
+        assert_eq!(normalize_organisation("Acme, Inc."), "acme");
+        assert_eq!(normalize_organisation("Deutsche Bahn AG"), "deutsche bahn");
+        assert_eq!(normalize_organisation("Inc."), "");
+    }
+
+    #[test]
+    fn test_same_organisation() {
+        assert!(same_organisation("Acme Corp", "ACME, Inc."));
+        assert!(same_organisation("Globex Corporation", "globex"));
+        assert!(!same_organisation("Acme", "Acme Labs"));
+        assert!(!same_organisation("Inc.", "LLC"));
+    }
+}
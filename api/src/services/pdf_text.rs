@@ -0,0 +1,366 @@
+//! PDF text extraction with layout analysis
+//!
+//! `pdf-extract` decodes the glyphs on each page (fonts, encodings, ToUnicode
+//! maps); this module puts them back into reading order. Text is ordered by
+//! its position on the page rather than by the order it appears in the
+//! content stream, and pages with a vertical gutter - such as a sidebar next
+//! to the main column - are read one column at a time.
+
+use anyhow::{anyhow, Result};
+use pdf_extract::{Document, MediaBox, OutputDev, OutputError, Transform};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+/// Extract the text of a PDF in reading order, one line per line of text
+///
+/// Pages are separated by a blank line.
+pub fn extract_text(pdf_data: &[u8]) -> Result<String> {
+    let doc = Document::load_mem(pdf_data).map_err(|e| anyhow!("Could not read PDF: {}", e))?;
+
+    let mut collector = LayoutCollector::default();
+    // pdf-extract panics on some malformed documents; treat that as a bad upload
+    catch_unwind(AssertUnwindSafe(|| pdf_extract::output_doc(&doc, &mut collector)))
+        .map_err(|_| anyhow!("Could not extract text from PDF: unsupported document structure"))?
+        .map_err(|e| anyhow!("Could not extract text from PDF: {}", e))?;
+
+    let text = collector.pages.join("\n\n");
+    if text.trim().is_empty() {
+        return Err(anyhow!("PDF contains no extractable text (it may be a scanned image)"));
+    }
+    Ok(text)
+}
+
+/// A run of glyphs on one baseline with no space between them
+#[derive(Debug, Clone)]
+struct Fragment {
+    x0: f64,
+    x1: f64,
+    /// Baseline, measured downwards from the top of the page
+    y: f64,
+    size: f64,
+    text: String,
+}
+
+/// Collects positioned text from pdf-extract and lays it out page by page
+#[derive(Default)]
+struct LayoutCollector {
+    flip: Option<Transform>,
+    page_width: f64,
+    fragments: Vec<Fragment>,
+    /// Whether the next glyph starts a new fragment
+    break_pending: bool,
+    pages: Vec<String>,
+}
+
+impl OutputDev for LayoutCollector {
+    fn begin_page(&mut self, _page_num: u32, media_box: &MediaBox, _art_box: Option<(f64, f64, f64, f64)>) -> Result<(), OutputError> {
+        // Flip the y axis so that y grows down the page
+        self.flip = Some(Transform::row_major(1., 0., 0., -1., 0., media_box.ury - media_box.lly));
+        self.page_width = media_box.urx - media_box.llx;
+        self.fragments.clear();
+        self.break_pending = true;
+        Ok(())
+    }
+
+    fn end_page(&mut self) -> Result<(), OutputError> {
+        let fragments = std::mem::take(&mut self.fragments);
+        self.pages.push(layout_page(fragments, self.page_width));
+        Ok(())
+    }
+
+    fn output_character(&mut self, trm: &Transform, width: f64, _spacing: f64, font_size: f64, char: &str) -> Result<(), OutputError> {
+        let position = match &self.flip {
+            Some(flip) => trm.post_transform(flip),
+            None => *trm,
+        };
+        let (x, y) = (position.m31, position.m32);
+
+        // Effective font size after the text matrix has been applied
+        let vx = font_size * (trm.m11 + trm.m21);
+        let vy = font_size * (trm.m12 + trm.m22);
+        let size = (vx * vy).abs().sqrt().max(1.0);
+        let advance = width * size;
+
+        if char.trim().is_empty() {
+            self.break_pending = true;
+            return Ok(());
+        }
+
+        if !self.break_pending {
+            if let Some(last) = self.fragments.last_mut() {
+                let same_baseline = (last.y - y).abs() < last.size * 0.3;
+                let adjacent = x >= last.x1 - last.size * 0.5 && x - last.x1 < last.size * 0.15;
+                if same_baseline && adjacent {
+                    last.text.push_str(char);
+                    last.x1 = last.x1.max(x + advance);
+                    return Ok(());
+                }
+            }
+        }
+
+        self.fragments.push(Fragment {
+            x0: x,
+            x1: x + advance,
+            y,
+            size,
+            text: char.to_string(),
+        });
+        self.break_pending = false;
+        Ok(())
+    }
+
+    fn begin_word(&mut self) -> Result<(), OutputError> {
+        Ok(())
+    }
+
+    fn end_word(&mut self) -> Result<(), OutputError> {
+        Ok(())
+    }
+
+    fn end_line(&mut self) -> Result<(), OutputError> {
+        self.break_pending = true;
+        Ok(())
+    }
+}
+
+/// Horizontal gap (in multiples of the font size) that separates two blocks
+/// of text on the same baseline, rather than two words of one sentence
+const SEGMENT_GAP: f64 = 2.0;
+
+/// Arrange the fragments of one page into lines of text
+fn layout_page(fragments: Vec<Fragment>, page_width: f64) -> String {
+    let segments = build_segments(fragments);
+    if segments.is_empty() {
+        return String::new();
+    }
+
+    let lines = match find_gutter(&segments, page_width) {
+        Some(gutter) => read_columns(segments, gutter),
+        None => to_lines(&segments),
+    };
+
+    lines.join("\n")
+}
+
+/// Group fragments into segments: runs of words on a shared baseline,
+/// split wherever there is a gap wide enough to be a column or tab stop
+fn build_segments(mut fragments: Vec<Fragment>) -> Vec<Fragment> {
+    fragments.sort_by(|a, b| a.y.total_cmp(&b.y));
+
+    let mut rows: Vec<Vec<Fragment>> = Vec::new();
+    for fragment in fragments {
+        match rows.last_mut() {
+            Some(row) if (row[0].y - fragment.y).abs() < row[0].size.max(fragment.size) * 0.3 => row.push(fragment),
+            _ => rows.push(vec![fragment]),
+        }
+    }
+
+    let mut segments = Vec::new();
+    for mut row in rows {
+        row.sort_by(|a, b| a.x0.total_cmp(&b.x0));
+
+        let mut current: Option<Fragment> = None;
+        for fragment in row {
+            current = Some(match current.take() {
+                Some(mut segment) if fragment.x0 - segment.x1 < segment.size * SEGMENT_GAP => {
+                    if fragment.x0 - segment.x1 > segment.size * 0.1 {
+                        segment.text.push(' ');
+                    }
+                    segment.text.push_str(&fragment.text);
+                    segment.x1 = segment.x1.max(fragment.x1);
+                    segment.size = segment.size.max(fragment.size);
+                    segment
+                }
+                Some(segment) => {
+                    segments.push(segment);
+                    fragment
+                }
+                None => fragment,
+            });
+        }
+        segments.extend(current);
+    }
+
+    segments
+}
+
+/// Find the x position of a vertical gutter between two text columns
+///
+/// A gutter is a vertical strip that almost no text crosses (headings that
+/// span the page are allowed), with left-aligned text on both sides that
+/// mostly sits on different baselines. The last condition keeps tab-aligned
+/// rows like "Acme Corp      Jan 2020 - Present" together.
+fn find_gutter(segments: &[Fragment], page_width: f64) -> Option<f64> {
+    let max_crossing = (segments.len() / 10).max(2);
+    let mut best: Option<(usize, f64)> = None;
+
+    let mut x = page_width * 0.15;
+    while x < page_width * 0.85 {
+        let crossing = segments.iter().filter(|s| s.x0 < x && s.x1 > x).count();
+        if crossing <= max_crossing && best.is_none_or(|(c, _)| crossing < c) && is_column_split(segments, x) {
+            best = Some((crossing, x));
+        }
+        x += 2.0;
+    }
+
+    best.map(|(_, x)| x)
+}
+
+fn is_column_split(segments: &[Fragment], x: f64) -> bool {
+    let left: Vec<&Fragment> = segments.iter().filter(|s| s.x1 <= x).collect();
+    let right: Vec<&Fragment> = segments.iter().filter(|s| s.x0 >= x).collect();
+    if left.len() < 3 || right.len() < 3 {
+        return false;
+    }
+
+    // Text in a column starts at a common left edge
+    let right_edge = right.iter().map(|s| s.x0).fold(f64::INFINITY, f64::min);
+    let aligned = right.iter().filter(|s| s.x0 - right_edge < 3.0).count();
+    if (aligned as f64) < right.len() as f64 * 0.6 {
+        return false;
+    }
+
+    // Columns flow independently, so most of their lines do not share a baseline
+    let (smaller, other) = if left.len() < right.len() { (&left, &right) } else { (&right, &left) };
+    let paired = smaller
+        .iter()
+        .filter(|s| other.iter().any(|o| (o.y - s.y).abs() < s.size * 0.3))
+        .count();
+    (paired as f64) < smaller.len() as f64 * 0.8
+}
+
+/// Read a two-column page: text above and below page-wide headings is
+/// split into bands, and each band is read left column first
+fn read_columns(mut segments: Vec<Fragment>, gutter: f64) -> Vec<String> {
+    segments.sort_by(|a, b| a.y.total_cmp(&b.y));
+
+    let mut lines = Vec::new();
+    let mut left = Vec::new();
+    let mut right = Vec::new();
+
+    let flush = |left: &mut Vec<Fragment>, right: &mut Vec<Fragment>, lines: &mut Vec<String>| {
+        for column in [left, right] {
+            if column.is_empty() {
+                continue;
+            }
+            if !lines.is_empty() {
+                lines.push(String::new());
+            }
+            lines.extend(to_lines(column));
+            column.clear();
+        }
+    };
+
+    for segment in segments {
+        if segment.x1 <= gutter {
+            left.push(segment);
+        } else if segment.x0 >= gutter {
+            right.push(segment);
+        } else {
+            flush(&mut left, &mut right, &mut lines);
+            if !lines.is_empty() {
+                lines.push(String::new());
+            }
+            lines.extend(to_lines(&[segment]));
+        }
+    }
+    flush(&mut left, &mut right, &mut lines);
+
+    lines
+}
+
+/// Join segments into lines, top to bottom, keeping large vertical gaps as
+/// blank lines so that section breaks survive
+fn to_lines(segments: &[Fragment]) -> Vec<String> {
+    let mut segments = segments.to_vec();
+    segments.sort_by(|a, b| a.y.total_cmp(&b.y).then(a.x0.total_cmp(&b.x0)));
+
+    let mut lines: Vec<String> = Vec::new();
+    let mut last: Option<(f64, f64)> = None;
+
+    for segment in segments {
+        match last {
+            Some((y, size)) if (segment.y - y).abs() < size.max(segment.size) * 0.3 => {
+                if let Some(line) = lines.last_mut() {
+                    line.push(' ');
+                    line.push_str(&segment.text);
+                }
+                continue;
+            }
+            Some((y, size)) if segment.y - y > size.max(segment.size) * 1.8 => {
+                lines.push(String::new());
+            }
+            _ => {}
+        }
+        lines.push(segment.text.clone());
+        last = Some((segment.y, segment.size));
+    }
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn non_empty_lines(text: &str) -> Vec<&str> {
+        text.lines().map(str::trim).filter(|l| !l.is_empty()).collect()
+    }
+
+    #[test]
+    fn test_single_column_in_position_order() {
+        let text = extract_text(include_bytes!("../../tests/fixtures/resume_single_column.pdf")).unwrap();
+        let lines = non_empty_lines(&text);
+
+        // The name is drawn last but sits at the top of the page
+        assert_eq!(lines[0], "Jane Doe");
+        assert_eq!(lines[1], "jane.doe@example.com | (555) 123-4567 | San Francisco, CA");
+        assert!(lines.contains(&"Acme Corp, Senior Software Engineer"));
+
+        // Page two follows page one
+        let experience = lines.iter().position(|l| *l == "Experience").unwrap();
+        let education = lines.iter().position(|l| *l == "Education").unwrap();
+        assert!(experience < education);
+        assert_eq!(lines.last(), Some(&"Rust, Python, Go, PostgreSQL, Docker, Kubernetes, AWS"));
+    }
+
+    #[test]
+    fn test_two_columns_are_not_interleaved() {
+        let text = extract_text(include_bytes!("../../tests/fixtures/resume_two_column.pdf")).unwrap();
+        let lines = non_empty_lines(&text);
+
+        assert_eq!(lines[0], "John Smith");
+        assert_eq!(lines[1], "Full Stack Developer");
+
+        // The sidebar is read in full before the main column
+        let sidebar = ["CONTACT", "john.smith@example.com", "+1 (555) 987-6543", "Austin, TX", "SKILLS", "TypeScript", "React", "Node.js", "MongoDB"];
+        assert_eq!(&lines[2..11], &sidebar);
+        assert_eq!(lines[11], "EXPERIENCE");
+        assert_eq!(lines[12], "Initech, Frontend Engineer");
+        assert_eq!(lines[13], "Mar 2019 - Present");
+    }
+
+    #[test]
+    fn test_tab_aligned_rows_stay_together() {
+        let fragment = |x0: f64, x1: f64, y: f64, text: &str| Fragment { x0, x1, y, size: 10.0, text: text.to_string() };
+        let fragments = vec![
+            fragment(50.0, 150.0, 100.0, "Acme Corp"),
+            fragment(450.0, 560.0, 100.0, "2020 - Present"),
+            fragment(50.0, 300.0, 114.0, "Led the billing migration"),
+            fragment(50.0, 150.0, 142.0, "Globex Inc"),
+            fragment(450.0, 560.0, 142.0, "2016 - 2019"),
+            fragment(50.0, 300.0, 156.0, "Built data pipelines"),
+            fragment(50.0, 150.0, 184.0, "Initech"),
+            fragment(450.0, 560.0, 184.0, "2014 - 2016"),
+        ];
+
+        let text = layout_page(fragments, 612.0);
+        let lines = non_empty_lines(&text);
+        assert_eq!(lines[0], "Acme Corp 2020 - Present");
+        assert_eq!(lines[2], "Globex Inc 2016 - 2019");
+    }
+
+    #[test]
+    fn test_invalid_pdf_is_an_error() {
+        assert!(extract_text(b"not a pdf").is_err());
+    }
+}
@@ -8,23 +8,92 @@ use crate::models::*;
 use crate::resume_parser::ParsedResume;
 use uuid::Uuid;
 use chrono::Utc;
+use serde::{Deserialize, Serialize};
 
 /// Upload and parse a resume file
 #[server(UploadResume)]
 pub async fn upload_resume(file_data: Vec<u8>, file_name: String, _user_id: String) -> Result<ParsedResume, ServerFnError> {
     use crate::resume_parser::ResumeParser;
+    use crate::db::get_database;
+    use crate::services::skill_taxonomy::load_skill_taxonomy;
     
-    let parser = ResumeParser::new().map_err(|e| ServerFnError::new(e.to_string()))?;
+    let taxonomy = load_skill_taxonomy(get_database())
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    let parser = ResumeParser::with_taxonomy(taxonomy).map_err(|e| ServerFnError::new(e.to_string()))?;
     
-    let parsed = if file_name.ends_with(".pdf") {
-        parser.parse_pdf(&file_data).await
-    } else if file_name.ends_with(".docx") {
-        parser.parse_docx(&file_data).await
-    } else {
-        return Err(ServerFnError::new("Unsupported file format".to_string()));
+    parser.parse_file(&file_name, &file_data)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// Save the parts of a parsed resume the user accepted on the review screen
+///
+/// The resume's profile is only used when the user has no profile yet;
+/// otherwise the resume is merged into the existing profile, filling in
+/// empty fields and adding to duplicate entries instead of repeating them.
+#[server(SaveReviewedResume)]
+pub async fn save_reviewed_resume(user_id: String, resume: ParsedResume) -> Result<FullProfile, ServerFnError> {
+    use crate::db::{
+        get_database,
+        SqliteProfileRepository, ProfileRepository,
+        SqliteExperienceRepository, ExperienceRepository,
+        SqliteEducationRepository, EducationRepository,
+        SqliteSkillRepository, SkillRepository,
+        SqliteProjectRepository, ProjectRepository,
     };
-    
-    parsed.map_err(|e| ServerFnError::new(e.to_string()))
+    use crate::services::merge_service::{apply_resume_merge, propose_merge};
+
+    if let Some(current) = get_full_profile(user_id.clone()).await? {
+        return apply_resume_merge(user_id, propose_merge(&current, &resume)).await;
+    }
+
+    let user_uuid = Uuid::parse_str(&user_id)
+        .map_err(|e| ServerFnError::new(format!("Invalid user ID: {}", e)))?;
+
+    let db = get_database();
+    let profile_repo = SqliteProfileRepository::new(db.clone());
+    let profile = Profile {
+        id: Uuid::new_v4(),
+        user_id: user_uuid,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+        ..resume.profile
+    };
+    let profile = profile_repo.create(&profile).await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+
+    let exp_repo = SqliteExperienceRepository::new(db.clone());
+    for mut experience in resume.experiences {
+        experience.profile_id = profile.id;
+        exp_repo.create(&experience).await
+            .map_err(|e| ServerFnError::new(e.to_string()))?;
+    }
+
+    let edu_repo = SqliteEducationRepository::new(db.clone());
+    for mut education in resume.education {
+        education.profile_id = profile.id;
+        edu_repo.create(&education).await
+            .map_err(|e| ServerFnError::new(e.to_string()))?;
+    }
+
+    let skill_repo = SqliteSkillRepository::new(db.clone());
+    for mut skill in resume.skills {
+        skill.profile_id = profile.id;
+        skill_repo.create(&skill).await
+            .map_err(|e| ServerFnError::new(e.to_string()))?;
+    }
+
+    let project_repo = SqliteProjectRepository::new(db.clone());
+    for mut project in resume.projects {
+        project.profile_id = profile.id;
+        project_repo.create(&project).await
+            .map_err(|e| ServerFnError::new(e.to_string()))?;
+    }
+
+    get_full_profile(user_id)
+        .await?
+        .ok_or_else(|| ServerFnError::new("Profile was not saved".to_string()))
 }
 
 /// Get user profile by user ID
@@ -253,65 +322,218 @@ pub async fn delete_skill(skill_id: String) -> Result<(), ServerFnError> {
         .map_err(|e| ServerFnError::new(e.to_string()))
 }
 
-/// Get user projects (placeholder for now)
+/// Get the skills the user has added to the skill taxonomy
+#[server(GetCustomSkills)]
+pub async fn get_custom_skills() -> Result<Vec<SkillDefinition>, ServerFnError> {
+    use crate::db::{get_database, SqliteCustomSkillRepository, CustomSkillRepository};
+    
+    let db = get_database();
+    let repo = SqliteCustomSkillRepository::new(db.clone());
+    
+    repo.get_all()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// Add a skill to the skill taxonomy, or update one added before
+///
+/// Custom skills are recognised in uploaded resumes and fetched jobs
+/// alongside the built-in ones, and take precedence over them.
+#[server(SaveCustomSkill)]
+pub async fn save_custom_skill(skill: SkillDefinition) -> Result<SkillDefinition, ServerFnError> {
+    use crate::db::{get_database, SqliteCustomSkillRepository, CustomSkillRepository};
+    
+    if skill.name.trim().is_empty() {
+        return Err(ServerFnError::new("Skill name is required"));
+    }
+    
+    let db = get_database();
+    let repo = SqliteCustomSkillRepository::new(db.clone());
+    
+    repo.upsert(&skill)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// Remove a skill the user added to the skill taxonomy
+#[server(DeleteCustomSkill)]
+pub async fn delete_custom_skill(name: String) -> Result<(), ServerFnError> {
+    use crate::db::{get_database, SqliteCustomSkillRepository, CustomSkillRepository};
+    
+    let db = get_database();
+    let repo = SqliteCustomSkillRepository::new(db.clone());
+    
+    repo.delete(&name)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// Get user projects
 #[server(GetProjects)]
-pub async fn get_projects(_profile_id: String) -> Result<Vec<Project>, ServerFnError> {
-    // TODO: Implement project repository
-    Ok(Vec::new())
+pub async fn get_projects(profile_id: String) -> Result<Vec<Project>, ServerFnError> {
+    use crate::db::{get_database, SqliteProjectRepository, ProjectRepository};
+    
+    let profile_uuid = Uuid::parse_str(&profile_id)
+        .map_err(|e| ServerFnError::new(format!("Invalid profile ID: {}", e)))?;
+    
+    let db = get_database();
+    let repo = SqliteProjectRepository::new(db.clone());
+    
+    repo.get_by_profile_id(&profile_uuid)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
 }
 
-/// Save project (placeholder for now)
+/// Save or update project
 #[server(SaveProject)]
 pub async fn save_project(project: Project) -> Result<Project, ServerFnError> {
-    // TODO: Implement project repository
-    Ok(project)
+    use crate::db::{get_database, SqliteProjectRepository, ProjectRepository};
+    
+    let db = get_database();
+    let repo = SqliteProjectRepository::new(db.clone());
+    
+    // Check if project exists
+    let existing = repo.get_by_id(&project.id).await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    
+    if existing.is_some() {
+        repo.update(&project)
+            .await
+            .map_err(|e| ServerFnError::new(e.to_string()))
+    } else {
+        repo.create(&project)
+            .await
+            .map_err(|e| ServerFnError::new(e.to_string()))
+    }
+}
+
+/// Delete project
+#[server(DeleteProject)]
+pub async fn delete_project(project_id: String) -> Result<(), ServerFnError> {
+    use crate::db::{get_database, SqliteProjectRepository, ProjectRepository};
+    
+    let project_uuid = Uuid::parse_str(&project_id)
+        .map_err(|e| ServerFnError::new(format!("Invalid project ID: {}", e)))?;
+    
+    let db = get_database();
+    let repo = SqliteProjectRepository::new(db.clone());
+    
+    repo.delete(&project_uuid)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
 }
 
 /// Get full profile with all related data (for AI cover letter generation)
 #[server(GetFullProfile)]
 pub async fn get_full_profile(user_id: String) -> Result<Option<FullProfile>, ServerFnError> {
+    use crate::db::get_database;
+    
+    let user_uuid = Uuid::parse_str(&user_id)
+        .map_err(|e| ServerFnError::new(format!("Invalid user ID: {}", e)))?;
+    
+    load_full_profile(get_database(), &user_uuid)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// Load a user's profile together with its experiences, education, skills and projects
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn load_full_profile(
+    db: &crate::db::Database,
+    user_id: &Uuid,
+) -> anyhow::Result<Option<FullProfile>> {
     use crate::db::{
-        get_database, 
         SqliteProfileRepository, ProfileRepository,
         SqliteExperienceRepository, ExperienceRepository,
         SqliteEducationRepository, EducationRepository,
         SqliteSkillRepository, SkillRepository,
+        SqliteProjectRepository, ProjectRepository,
     };
     
-    let user_uuid = Uuid::parse_str(&user_id)
-        .map_err(|e| ServerFnError::new(format!("Invalid user ID: {}", e)))?;
-    
-    let db = get_database();
-    
     // Get profile
     let profile_repo = SqliteProfileRepository::new(db.clone());
-    let profile = match profile_repo.get_by_user_id(&user_uuid).await
-        .map_err(|e| ServerFnError::new(e.to_string()))? {
+    let profile = match profile_repo.get_by_user_id(user_id).await? {
         Some(p) => p,
         None => return Ok(None),
     };
     
     // Get related data
-    let exp_repo = SqliteExperienceRepository::new(db.clone());
-    let experiences = exp_repo.get_by_profile_id(&profile.id).await
-        .map_err(|e| ServerFnError::new(e.to_string()))?;
-    
-    let edu_repo = SqliteEducationRepository::new(db.clone());
-    let education = edu_repo.get_by_profile_id(&profile.id).await
-        .map_err(|e| ServerFnError::new(e.to_string()))?;
-    
-    let skill_repo = SqliteSkillRepository::new(db.clone());
-    let skills = skill_repo.get_by_profile_id(&profile.id).await
-        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    let experiences = SqliteExperienceRepository::new(db.clone())
+        .get_by_profile_id(&profile.id)
+        .await?;
+    let education = SqliteEducationRepository::new(db.clone())
+        .get_by_profile_id(&profile.id)
+        .await?;
+    let skills = SqliteSkillRepository::new(db.clone())
+        .get_by_profile_id(&profile.id)
+        .await?;
+    let projects = SqliteProjectRepository::new(db.clone())
+        .get_by_profile_id(&profile.id)
+        .await?;
     
     Ok(Some(FullProfile {
         profile,
         skills,
         experiences,
         education,
+        projects,
     }))
 }
 
+/// A generated file for the user to download
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ExportedFile {
+    pub file_name: String,
+    pub mime_type: String,
+    pub content: String,
+}
+
+impl ExportedFile {
+    /// An export of the resume of `name`, saved as e.g. "jane-doe-resume.json"
+    /// for "Jane Doe"
+    pub fn resume(name: &str, extension: &str, mime_type: &str, content: String) -> Self {
+        let slug = name
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|part| !part.is_empty())
+            .map(str::to_lowercase)
+            .collect::<Vec<_>>()
+            .join("-");
+        let file_name = if slug.is_empty() {
+            format!("resume.{}", extension)
+        } else {
+            format!("{}-resume.{}", slug, extension)
+        };
+        Self { file_name, mime_type: mime_type.to_string(), content }
+    }
+}
+
+/// Export the user's full profile as a JSON Resume document
+#[server(ExportJsonResume)]
+pub async fn export_json_resume(user_id: String) -> Result<ExportedFile, ServerFnError> {
+    use crate::services::json_resume::JsonResume;
+
+    let full = get_full_profile(user_id)
+        .await?
+        .ok_or_else(|| ServerFnError::new("No profile found".to_string()))?;
+
+    let content = serde_json::to_string_pretty(&JsonResume::from_full_profile(&full))
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+
+    Ok(ExportedFile::resume(&full.profile.name, "json", "application/json", content))
+}
+
+/// Export the user's full profile as a Europass CV (XML)
+#[server(ExportEuropass)]
+pub async fn export_europass(user_id: String) -> Result<ExportedFile, ServerFnError> {
+    use crate::services::europass::to_europass_xml;
+
+    let full = get_full_profile(user_id)
+        .await?
+        .ok_or_else(|| ServerFnError::new("No profile found".to_string()))?;
+
+    Ok(ExportedFile::resume(&full.profile.name, "xml", "application/xml", to_europass_xml(&full)))
+}
+
 /// Create a new empty profile for a user
 #[server(CreateEmptyProfile)]
 pub async fn create_empty_profile(user_id: String, name: String, email: String) -> Result<Profile, ServerFnError> {
@@ -2,68 +2,186 @@ use anyhow::{anyhow, Result};
 use chrono::{DateTime, Utc};
 use regex::Regex;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
 use uuid::Uuid;
 
-use crate::models::{Profile, Experience, Education, Skill, SkillCategory, SkillLevel, Project};
+use crate::models::{Profile, Experience, Education, Skill, SkillLevel, Project};
+use crate::services::json_resume::JsonResume;
+use crate::services::skill_taxonomy::SkillTaxonomy;
 
 pub struct ResumeParser {
     email_regex: Regex,
     phone_regex: Regex,
     date_regex: Regex,
+    range_separator_regex: Regex,
+    degree_regex: Regex,
+    gpa_regex: Regex,
     url_regex: Regex,
+    taxonomy: Arc<SkillTaxonomy>,
 }
 
 impl ResumeParser {
+    /// Create a parser that recognises the skills of the built-in taxonomy
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new() -> Result<Self> {
+        Self::with_taxonomy(SkillTaxonomy::builtin())
+    }
+
+    /// Create a parser that recognises the skills of `taxonomy`
+    pub fn with_taxonomy(taxonomy: Arc<SkillTaxonomy>) -> Result<Self> {
         Ok(Self {
             email_regex: Regex::new(r"\b[A-Za-z0-9._%+-]+@[A-Za-z0-9.-]+\.[A-Z|a-z]{2,}\b")?,
             phone_regex: Regex::new(r"(\+?1?[-.\s]?)?\(?([0-9]{3})\)?[-.\s]?([0-9]{3})[-.\s]?([0-9]{4})")?,
             date_regex: Regex::new(r"(?i)(jan|feb|mar|apr|may|jun|jul|aug|sep|oct|nov|dec)[a-z]*[\s,]*(\d{4})|(\d{1,2})[\/\-](\d{4})|(\d{4})")?,
+            range_separator_regex: Regex::new(r"(?i)^\s*(?:-|\x{2013}|\x{2014}|\bto\b|\buntil\b)\s*(?:(present|current|now|today)\b)?")?,
+            degree_regex: Regex::new(r"(?i)^(ph\.?\s?d\.?|doctor(?:ate)?|master(?:'s)?|bachelor(?:'s)?|associate(?:'s)?|m\.?b\.?a\.?|m\.?eng\.?|b\.?eng\.?|m\.?sc?\.?|b\.?sc?\.?|m\.?a\.?|b\.?a\.?|a\.?a\.?s?\.?|diploma|certificate)(?:\s|$)")?,
+            gpa_regex: Regex::new(r"(?i)\bgpa\b[:\s]*(\d\.\d{1,2})|(\d\.\d{1,2})\s*/\s*4(?:\.0)?\b")?,
             url_regex: Regex::new(r"https?://[^\s]+")?,
+            taxonomy,
         })
     }
 
+    /// Parse a resume file, choosing the format from its extension
+    pub async fn parse_file(&self, file_name: &str, data: &[u8]) -> Result<ParsedResume> {
+        let extension = std::path::Path::new(file_name)
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(str::to_lowercase)
+            .unwrap_or_default();
+
+        match extension.as_str() {
+            "pdf" => self.parse_pdf(data).await,
+            "docx" => self.parse_docx(data).await,
+            "txt" => self.parse_txt(data).await,
+            "md" | "markdown" => self.parse_markdown(data).await,
+            "html" | "htm" => self.parse_html(data).await,
+            "json" => self.parse_json_resume(data).await,
+            _ => Err(anyhow!("Unsupported file format: {}", file_name)),
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn parse_pdf(&self, pdf_data: &[u8]) -> Result<ParsedResume> {
+        let text = crate::services::pdf_text::extract_text(pdf_data)?;
+        self.parse_text(&text).await
+    }
+
+    #[cfg(target_arch = "wasm32")]
     pub async fn parse_pdf(&self, _pdf_data: &[u8]) -> Result<ParsedResume> {
-        // Temporarily disabled - would use pdf_extract::extract_text_from_mem
-        let text = "Sample extracted text from PDF";
-        self.parse_text(text).await
+        Err(anyhow!("PDF parsing is only available on the server"))
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn parse_docx(&self, docx_data: &[u8]) -> Result<ParsedResume> {
+        let blocks = crate::services::docx_text::read_blocks(docx_data)?;
+        self.parse_blocks(&blocks).await
     }
 
+    #[cfg(target_arch = "wasm32")]
     pub async fn parse_docx(&self, _docx_data: &[u8]) -> Result<ParsedResume> {
-        // Temporarily disabled - would use docx_rs::read_docx
-        let text = "Sample extracted text from DOCX";
-        self.parse_text(text).await
+        Err(anyhow!("DOCX parsing is only available on the server"))
+    }
+
+    pub async fn parse_txt(&self, text_data: &[u8]) -> Result<ParsedResume> {
+        self.parse_blocks(&text_blocks(&decode_text(text_data))).await
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn parse_markdown(&self, markdown_data: &[u8]) -> Result<ParsedResume> {
+        let blocks = crate::services::markdown_text::read_blocks(&decode_text(markdown_data));
+        self.parse_blocks(&blocks).await
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    pub async fn parse_markdown(&self, _markdown_data: &[u8]) -> Result<ParsedResume> {
+        Err(anyhow!("Markdown parsing is only available on the server"))
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn parse_html(&self, html_data: &[u8]) -> Result<ParsedResume> {
+        let blocks = crate::services::html_text::read_blocks(&decode_text(html_data));
+        self.parse_blocks(&blocks).await
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    pub async fn parse_html(&self, _html_data: &[u8]) -> Result<ParsedResume> {
+        Err(anyhow!("HTML parsing is only available on the server"))
     }
 
-    // DOCX parsing functions temporarily removed to resolve getrandom conflicts
+    /// Import a resume in the JSON Resume format (https://jsonresume.org)
+    ///
+    /// Fields are mapped directly rather than inferred from text.
+    pub async fn parse_json_resume(&self, json_data: &[u8]) -> Result<ParsedResume> {
+        let resume: JsonResume = serde_json::from_slice(json_data)
+            .map_err(|e| anyhow!("Invalid JSON Resume: {}", e))?;
+        resume.to_parsed_resume(&self.taxonomy)
+    }
 
     async fn parse_text(&self, text: &str) -> Result<ParsedResume> {
         let sections = self.identify_sections(text);
-        
+        self.parse_sections(text, &sections)
+    }
+
+    /// Parse a document whose structure (headings, lists) is known
+    ///
+    /// Sections are taken from the headings rather than guessed from
+    /// keywords, and list items under a job become its achievements.
+    /// Documents without headings fall back to plain text parsing.
+    pub async fn parse_blocks(&self, blocks: &[DocumentBlock]) -> Result<ParsedResume> {
+        let text = blocks.iter().map(|b| b.text.as_str()).collect::<Vec<_>>().join("\n");
+
+        let section_blocks = section_ranges(blocks);
+        if section_blocks.is_empty() {
+            return self.parse_text(&text).await;
+        }
+
+        // Byte offset of each block in the joined text
+        let mut offsets = Vec::with_capacity(blocks.len() + 1);
+        let mut offset = 0;
+        for block in blocks {
+            offsets.push(offset);
+            offset += block.text.len() + 1;
+        }
+        offsets.push(text.len());
+
+        let sections = section_blocks
+            .iter()
+            .map(|(name, (start, end))| (name.to_string(), (offsets[*start], offsets[*end].min(text.len()))))
+            .collect();
+
+        let mut parsed = self.parse_sections(&text, &sections)?;
+        if let Some((start, end)) = section_blocks.get("experience") {
+            for exp in &parsed.experiences {
+                parsed.confidence.entries.remove(&exp.id);
+            }
+            parsed.experiences = self.extract_structured_experiences(
+                &text,
+                &blocks[start + 1..*end],
+                &offsets[start + 1..*end],
+                &mut parsed.confidence,
+            );
+        }
+        Ok(parsed)
+    }
+
+    fn parse_sections(&self, text: &str, sections: &HashMap<String, (usize, usize)>) -> Result<ParsedResume> {
+        let mut confidence = ParseConfidence::default();
         Ok(ParsedResume {
-            profile: self.extract_profile(text, &sections)?,
-            experiences: self.extract_experiences(text, &sections)?,
-            education: self.extract_education(text, &sections)?,
-            skills: self.extract_skills(text, &sections)?,
-            projects: self.extract_projects(text, &sections)?,
+            profile: self.extract_profile(text, sections, &mut confidence)?,
+            experiences: self.extract_experiences(text, sections, &mut confidence)?,
+            education: self.extract_education(text, sections, &mut confidence)?,
+            skills: self.extract_skills(text, sections, &mut confidence)?,
+            projects: self.extract_projects(text, sections)?,
+            confidence,
         })
     }
 
     fn identify_sections(&self, text: &str) -> HashMap<String, (usize, usize)> {
         let mut sections = HashMap::new();
         let lines: Vec<&str> = text.lines().collect();
-        
-        let section_headers = [
-            ("contact", vec!["contact", "personal information", "details"]),
-            ("summary", vec!["summary", "objective", "profile", "about"]),
-            ("experience", vec!["experience", "work experience", "employment", "professional experience", "work history"]),
-            ("education", vec!["education", "academic background", "qualifications"]),
-            ("skills", vec!["skills", "technical skills", "competencies", "proficiencies"]),
-            ("projects", vec!["projects", "personal projects", "portfolio"]),
-        ];
-
-        for (section_name, headers) in section_headers {
+
+        for (section_name, headers) in SECTION_HEADERS {
             for (i, line) in lines.iter().enumerate() {
                 let line_lower = line.to_lowercase();
                 if headers.iter().any(|header| line_lower.contains(header) && line.trim().len() < 50) {
@@ -75,24 +193,54 @@ impl ResumeParser {
             }
         }
 
+        // Each section ends where the next one starts
+        let starts: Vec<usize> = sections.values().map(|(start, _)| *start).collect();
+        for (start, end) in sections.values_mut() {
+            if let Some(next) = starts.iter().filter(|s| **s > *start).min() {
+                *end = *next;
+            }
+        }
+
         sections
     }
 
-    fn extract_profile(&self, text: &str, _sections: &HashMap<String, (usize, usize)>) -> Result<Profile> {
-        let email = self.email_regex.find(text)
-            .map(|m| m.as_str().to_string())
+    fn extract_profile(
+        &self,
+        text: &str,
+        _sections: &HashMap<String, (usize, usize)>,
+        confidence: &mut ParseConfidence,
+    ) -> Result<Profile> {
+        let email_match = self.email_regex.find(text)
             .ok_or_else(|| anyhow!("No email found in resume"))?;
+        confidence.set_field("email", 0.95, text, email_match.range());
+        let email = email_match.as_str().to_string();
 
-        let phone = self.phone_regex.find(text)
-            .map(|m| m.as_str().to_string());
+        let phone = self.phone_regex.find(text).map(|m| {
+            confidence.set_field("phone", 0.8, text, m.range());
+            m.as_str().to_string()
+        });
 
         let linkedin_url = self.extract_url(text, "linkedin.com");
         let github_url = self.extract_url(text, "github.com");
         let portfolio_url = self.extract_url(text, "portfolio")
             .or_else(|| self.extract_url(text, "website"));
+        for (field, url) in [("linkedin_url", &linkedin_url), ("github_url", &github_url), ("portfolio_url", &portfolio_url)] {
+            if let Some(start) = url.as_ref().and_then(|u| text.find(u.as_str())) {
+                confidence.set_field(field, 0.9, text, start..start + url.as_ref().map_or(0, |u| u.len()));
+            }
+        }
 
         let name = self.extract_name(text)?;
+        if let Some(start) = text.find(name.as_str()) {
+            // The first line of a resume is almost always the name
+            let is_first_line = text[..start].trim().is_empty();
+            confidence.set_field("name", if is_first_line { 0.8 } else { 0.6 }, text, start..start + name.len());
+        }
+
         let location = self.extract_location(text);
+        if let Some(start) = location.as_ref().and_then(|l| text.find(l.as_str())) {
+            confidence.set_field("location", 0.4, text, start..start + location.as_ref().map_or(0, |l| l.len()));
+        }
 
         Ok(Profile {
             id: Uuid::new_v4(),
@@ -146,7 +294,12 @@ impl ResumeParser {
         None
     }
 
-    fn extract_experiences(&self, text: &str, sections: &HashMap<String, (usize, usize)>) -> Result<Vec<Experience>> {
+    fn extract_experiences(
+        &self,
+        text: &str,
+        sections: &HashMap<String, (usize, usize)>,
+        confidence: &mut ParseConfidence,
+    ) -> Result<Vec<Experience>> {
         let mut experiences = Vec::new();
         
         if let Some((start, end)) = sections.get("experience") {
@@ -162,25 +315,38 @@ impl ResumeParser {
                     continue;
                 }
                 
+                let line_start = subslice_offset(text, line);
+                let line_span = line_start..line_start + line.len();
+
+                // Dates on their own line belong to the job above
+                if let Some(ref mut exp) = current_experience {
+                    if exp.start_date.is_none() {
+                        if let Some(range) = self.date_only_line(line) {
+                            exp.set_dates(range);
+                            exp.extend_source(line_span);
+                            continue;
+                        }
+                    }
+                }
+
                 // Check if this looks like a job title line (contains company or position indicators)
                 if self.looks_like_job_header(line) {
                     if let Some(exp) = current_experience.take() {
-                        if let Ok(experience) = exp.build() {
-                            experiences.push(experience);
-                        }
+                        exp.finish(text, &mut experiences, confidence);
                     }
-                    current_experience = Some(self.parse_job_header(line));
+                    let mut exp = self.parse_job_header(line);
+                    exp.extend_source(line_span);
+                    current_experience = Some(exp);
                 } else if let Some(ref mut exp) = current_experience {
                     // Add to description
                     exp.add_description_line(line);
+                    exp.extend_source(line_span);
                 }
             }
             
             // Don't forget the last experience
             if let Some(exp) = current_experience {
-                if let Ok(experience) = exp.build() {
-                    experiences.push(experience);
-                }
+                exp.finish(text, &mut experiences, confidence);
             }
         }
         
@@ -195,99 +361,570 @@ impl ResumeParser {
     }
 
     fn parse_job_header(&self, line: &str) -> ExperienceBuilder {
+        // Dates in the header ("Acme Corp, Engineer, 2019 - Present") are split off first
+        let (dates, header) = match self.find_date_range(line) {
+            Some((range, span)) => (Some(range), remove_span(line, span)),
+            None => (None, line.to_string()),
+        };
+
         // Very basic parsing - would need much more sophistication in practice
-        let parts: Vec<&str> = line.split(", ").collect();
-        let company = parts.first().unwrap_or(&"Unknown Company").to_string();
+        let parts: Vec<&str> = header.split(", ").collect();
+        let company = parts.first().filter(|c| !c.is_empty()).unwrap_or(&"Unknown Company").to_string();
         let position = parts.get(1).unwrap_or(&"Unknown Position").to_string();
         
-        ExperienceBuilder {
+        let mut builder = ExperienceBuilder {
             id: Uuid::new_v4(),
             profile_id: Uuid::new_v4(),
             company,
             position,
             location: None,
-            start_date: Utc::now(), // Would parse from text
+            start_date: None,
             end_date: None,
             current: false,
             description: String::new(),
             achievements: Vec::new(),
+            source: None,
             created_at: Utc::now(),
             updated_at: Utc::now(),
+        };
+        if let Some(range) = dates {
+            builder.set_dates(range);
         }
+        builder
     }
 
-    fn extract_education(&self, _text: &str, _sections: &HashMap<String, (usize, usize)>) -> Result<Vec<Education>> {
-        // Simplified for now
-        Ok(Vec::new())
+    /// Find the first date or date range in a line, with its byte span
+    ///
+    /// Understands "Jan 2019 – Present", "03/2018 - 11/2020", "2015–2019"
+    /// and "2019 to 2021". A single date is returned as a range that starts
+    /// and ends on that date.
+    fn find_date_range(&self, line: &str) -> Option<(DateRange, std::ops::Range<usize>)> {
+        let dates: Vec<(std::ops::Range<usize>, DateTime<Utc>)> = self
+            .date_regex
+            .captures_iter(line)
+            .filter_map(|caps| {
+                let span = caps.get(0)?.range();
+                // A month name inside a word ("summary 2019") is not a month
+                let in_word = line[..span.start].chars().next_back().is_some_and(|c| c.is_alphabetic());
+                Some((span, date_from_captures(&caps, in_word)?))
+            })
+            .collect();
+        let (first_span, start) = dates.first()?.clone();
+
+        if let Some(separator) = self.range_separator_regex.captures(&line[first_span.end..]) {
+            let after = first_span.end + separator.get(0)?.end();
+            if separator.get(1).is_some() {
+                let range = DateRange { start, end: None, current: true };
+                return Some((range, first_span.start..after));
+            }
+            if let Some((span, end)) = dates.get(1).filter(|(span, _)| span.start == after) {
+                let range = DateRange { start, end: Some(*end), current: false };
+                return Some((range, first_span.start..span.end));
+            }
+        }
+
+        Some((DateRange { start, end: Some(start), current: false }, first_span))
     }
 
-    fn extract_skills(&self, text: &str, sections: &HashMap<String, (usize, usize)>) -> Result<Vec<Skill>> {
+    /// The date range of a line that holds nothing but dates
+    fn date_only_line(&self, line: &str) -> Option<DateRange> {
+        let (range, span) = self.find_date_range(line)?;
+        let rest = remove_span(line, span);
+        (!rest.chars().any(|c| c.is_alphanumeric())).then_some(range)
+    }
+
+    /// Build experiences from the blocks of an experience section
+    ///
+    /// Each job starts with one or more plain paragraphs or sub-headings
+    /// (title, company, dates) and is followed by a bullet list of
+    /// achievements. A plain paragraph after a list starts the next job.
+    ///
+    /// `offsets` are the positions of the blocks in `source`, the text the
+    /// source spans refer to.
+    fn extract_structured_experiences(
+        &self,
+        source: &str,
+        blocks: &[DocumentBlock],
+        offsets: &[usize],
+        confidence: &mut ParseConfidence,
+    ) -> Vec<Experience> {
+        let mut experiences = Vec::new();
+        let mut current: Option<ExperienceBuilder> = None;
+        let mut in_list = false;
+
+        for (block, offset) in blocks.iter().zip(offsets) {
+            let text = block.text.trim();
+            if text.is_empty() {
+                continue;
+            }
+            let span = *offset..*offset + block.text.len();
+
+            match block.kind {
+                BlockKind::ListItem(_) => {
+                    let exp = current.get_or_insert_with(|| self.parse_job_header("Unknown Company"));
+                    exp.achievements.push(text.to_string());
+                    exp.extend_source(span);
+                    in_list = true;
+                }
+                BlockKind::Heading(_) | BlockKind::Paragraph => {
+                    let starts_job = matches!(block.kind, BlockKind::Heading(_)) || in_list || current.is_none();
+                    if starts_job {
+                        if let Some(exp) = current.take() {
+                            exp.finish(source, &mut experiences, confidence);
+                        }
+                        let mut exp = self.parse_job_header(text);
+                        exp.extend_source(span);
+                        current = Some(exp);
+                    } else if let Some(ref mut exp) = current {
+                        // Dates on their own line belong to the job above
+                        match self.date_only_line(text).filter(|_| exp.start_date.is_none()) {
+                            Some(range) => exp.set_dates(range),
+                            None => exp.add_description_line(text),
+                        }
+                        exp.extend_source(span);
+                    }
+                    in_list = false;
+                }
+            }
+        }
+
+        if let Some(exp) = current {
+            exp.finish(source, &mut experiences, confidence);
+        }
+
+        experiences
+    }
+
+    /// Read education entries from the education section
+    ///
+    /// Lines are split into comma or pipe separated parts, and each part is
+    /// recognised as an institution, a degree (with its field of study), a
+    /// GPA, honors or dates. A second institution or degree starts the next
+    /// entry.
+    fn extract_education(
+        &self,
+        text: &str,
+        sections: &HashMap<String, (usize, usize)>,
+        confidence: &mut ParseConfidence,
+    ) -> Result<Vec<Education>> {
+        let mut education = Vec::new();
+        let Some((start, end)) = sections.get("education") else {
+            return Ok(education);
+        };
+
+        let mut current: Option<EducationBuilder> = None;
+
+        // The first line is the section heading
+        for line in text[*start..*end].lines().skip(1) {
+            let line = line.trim().trim_start_matches(LIST_BULLETS).trim();
+            if line.is_empty() {
+                continue;
+            }
+            let line_start = subslice_offset(text, line);
+            let line_span = line_start..line_start + line.len();
+
+            let (dates, line) = match self.find_date_range(line) {
+                Some((range, span)) => (Some(range), remove_span(line, span)),
+                None => (None, line.to_string()),
+            };
+            let (gpa, line) = match self.gpa_regex.captures(&line) {
+                Some(caps) => {
+                    let gpa = caps.get(1).or_else(|| caps.get(2)).and_then(|m| m.as_str().parse::<f32>().ok());
+                    let span = caps.get(0).map(|m| m.range()).unwrap_or_default();
+                    (gpa, remove_span(&line, span))
+                }
+                None => (None, line),
+            };
+
+            for segment in line.split([',', '|', ';', '\u{b7}', '\u{2022}']).flat_map(|s| s.split(" - ")) {
+                let segment = segment.trim().trim_matches(|c: char| c == '(' || c == ')').trim();
+                if segment.is_empty() {
+                    continue;
+                }
+
+                if is_institution(segment) {
+                    if current.as_ref().is_some_and(|e| !e.institution.is_empty()) {
+                        if let Some(entry) = current.take() {
+                            entry.finish(text, &mut education, confidence);
+                        }
+                    }
+                    current.get_or_insert_with(EducationBuilder::new).institution = segment.to_string();
+                } else if let Some((degree, field)) = self.parse_degree(segment) {
+                    if current.as_ref().is_some_and(|e| !e.degree.is_empty()) {
+                        if let Some(entry) = current.take() {
+                            entry.finish(text, &mut education, confidence);
+                        }
+                    }
+                    let entry = current.get_or_insert_with(EducationBuilder::new);
+                    entry.degree = degree;
+                    entry.field = field;
+                } else if is_honor(segment) {
+                    let honor = segment.split_once(':').map(|(_, h)| h.trim()).unwrap_or(segment);
+                    current.get_or_insert_with(EducationBuilder::new).honors.push(honor.to_string());
+                } else if let Some(entry) = current.as_mut() {
+                    let is_words = !segment.chars().any(|c| c.is_ascii_digit());
+                    if !entry.degree.is_empty() && entry.field.is_empty() && is_words {
+                        entry.field = segment.to_string();
+                    } else if entry.institution.is_empty() && is_words && segment.starts_with(char::is_uppercase) {
+                        entry.institution = segment.to_string();
+                    }
+                } else if segment.starts_with(char::is_uppercase) && !segment.chars().any(|c| c.is_ascii_digit()) {
+                    // An institution without a telling name ("MIT")
+                    current.get_or_insert_with(EducationBuilder::new).institution = segment.to_string();
+                }
+            }
+
+            if let Some(range) = dates {
+                current.get_or_insert_with(EducationBuilder::new).set_dates(range);
+            }
+            if let Some(gpa) = gpa {
+                current.get_or_insert_with(EducationBuilder::new).gpa = Some(gpa);
+            }
+            if let Some(entry) = current.as_mut() {
+                entry.extend_source(line_span);
+            }
+        }
+
+        if let Some(entry) = current {
+            entry.finish(text, &mut education, confidence);
+        }
+        Ok(education)
+    }
+
+    /// Split "B.S. Computer Science" or "Master of Science in Physics" into degree and field
+    fn parse_degree(&self, segment: &str) -> Option<(String, String)> {
+        let token = self.degree_regex.captures(segment)?.get(1)?;
+        let rest = segment[token.end()..].trim();
+
+        if let Some((degree, field)) = segment.split_once(" in ") {
+            Some((degree.trim().to_string(), field.trim().to_string()))
+        } else if rest.starts_with("of ") || rest.eq_ignore_ascii_case("degree") {
+            Some((segment.to_string(), String::new()))
+        } else {
+            Some((token.as_str().trim().to_string(), rest.to_string()))
+        }
+    }
+
+    fn extract_skills(
+        &self,
+        text: &str,
+        sections: &HashMap<String, (usize, usize)>,
+        confidence: &mut ParseConfidence,
+    ) -> Result<Vec<Skill>> {
         let mut skills = Vec::new();
         
         if let Some((start, end)) = sections.get("skills") {
-            let skills_text = &text[*start..*end];
-            let words: Vec<&str> = skills_text.split_whitespace().collect();
-            
-            // Simple skill extraction - look for technology names
-            let common_skills = [
-                "Python", "JavaScript", "Java", "C++", "C#", "Go", "Rust", "TypeScript",
-                "React", "Angular", "Vue", "Django", "Flask", "Express", "Spring",
-                "PostgreSQL", "MySQL", "MongoDB", "Redis", "Docker", "Kubernetes",
-                "AWS", "Azure", "GCP", "Git", "Linux", "HTML", "CSS"
-            ];
-            
-            for word in words {
-                let word = word.trim_matches(|c: char| !c.is_alphanumeric());
-                if common_skills.contains(&word) {
-                    skills.push(Skill {
-                        id: Uuid::new_v4(),
-                        profile_id: Uuid::new_v4(),
-                        name: word.to_string(),
-                        category: self.categorize_skill(word),
-                        proficiency: SkillLevel::Intermediate, // Default
-                        years_experience: None,
-                        created_at: Utc::now(),
-                        updated_at: Utc::now(),
-                    });
+            let mut seen = HashSet::new();
+            for found in self.taxonomy.find_in_text(&text[*start..*end]) {
+                if !seen.insert(found.skill.name.as_str()) {
+                    continue;
                 }
+                let skill_id = Uuid::new_v4();
+                // Known names listed under a skills heading
+                confidence.set_entry(skill_id, 0.9, text, start + found.span.start..start + found.span.end);
+                skills.push(Skill {
+                    id: skill_id,
+                    profile_id: Uuid::new_v4(),
+                    name: found.skill.name.clone(),
+                    category: found.skill.category.clone(),
+                    proficiency: SkillLevel::Intermediate, // Default
+                    years_experience: None,
+                    created_at: Utc::now(),
+                    updated_at: Utc::now(),
+                });
             }
         }
         
         Ok(skills)
     }
 
-    fn categorize_skill(&self, skill: &str) -> SkillCategory {
-        match skill {
-            "Python" | "JavaScript" | "Java" | "C++" | "C#" | "Go" | "Rust" | "TypeScript" => SkillCategory::Programming,
-            "React" | "Angular" | "Vue" | "Django" | "Flask" | "Express" | "Spring" => SkillCategory::Framework,
-            "PostgreSQL" | "MySQL" | "MongoDB" | "Redis" => SkillCategory::Database,
-            "Docker" | "Kubernetes" | "Git" | "Linux" => SkillCategory::Tool,
-            _ => SkillCategory::Other,
-        }
-    }
-
     fn extract_projects(&self, _text: &str, _sections: &HashMap<String, (usize, usize)>) -> Result<Vec<Project>> {
         // Simplified for now
         Ok(Vec::new())
     }
 }
 
+/// Characters that start a bullet point in plain text
+const LIST_BULLETS: [char; 7] = ['-', '*', '+', '\u{2022}', '\u{25e6}', '\u{25aa}', '\u{2013}'];
+
+/// Start and end of a job or course of study
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct DateRange {
+    start: DateTime<Utc>,
+    end: Option<DateTime<Utc>>,
+    current: bool,
+}
+
+/// Convert a `date_regex` match to the first day of its month
+///
+/// Matches outside 1950-2100 or with an impossible month (phone numbers,
+/// figures) are rejected.
+fn date_from_captures(caps: &regex::Captures, ignore_month_name: bool) -> Option<DateTime<Utc>> {
+    let number = |i: usize| caps.get(i).and_then(|m| m.as_str().parse::<u32>().ok());
+
+    let (year, month) = if let Some(name) = caps.get(1) {
+        let month = if ignore_month_name { 1 } else { month_from_name(name.as_str())? };
+        (number(2)?, month)
+    } else if let Some(month) = number(3) {
+        (number(4)?, month)
+    } else {
+        (number(5)?, 1)
+    };
+
+    if !(1950..=2100).contains(&year) || !(1..=12).contains(&month) {
+        return None;
+    }
+    let date = chrono::NaiveDate::from_ymd_opt(year as i32, month, 1)?.and_hms_opt(0, 0, 0)?;
+    Some(DateTime::from_naive_utc_and_offset(date, Utc))
+}
+
+fn month_from_name(name: &str) -> Option<u32> {
+    const MONTHS: [&str; 12] = ["jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec"];
+    let prefix = name.get(..3)?.to_lowercase();
+    MONTHS.iter().position(|m| *m == prefix).map(|i| i as u32 + 1)
+}
+
+/// Byte offset of `part` within `text`; `part` must be a subslice of `text`
+fn subslice_offset(text: &str, part: &str) -> usize {
+    part.as_ptr() as usize - text.as_ptr() as usize
+}
+
+/// Grow a span to also cover another one
+fn extend_span(span: Option<std::ops::Range<usize>>, other: std::ops::Range<usize>) -> std::ops::Range<usize> {
+    match span {
+        Some(span) => span.start.min(other.start)..span.end.max(other.end),
+        None => other,
+    }
+}
+
+/// Remove a span from a line, tidying the separators left around it
+fn remove_span(line: &str, span: std::ops::Range<usize>) -> String {
+    let joined = format!("{} {}", &line[..span.start], &line[span.end..]);
+    let trimmed = joined.trim_matches(|c: char| c.is_whitespace() || ",|-()\u{2013}\u{2014}".contains(c));
+    trimmed.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn is_institution(segment: &str) -> bool {
+    const KEYWORDS: [&str; 9] = [
+        "universit", "college", "institute", "school", "academy", "polytechnic", "conservatory", "\u{e9}cole", "bootcamp",
+    ];
+    let segment = segment.to_lowercase();
+    KEYWORDS.iter().any(|k| segment.contains(k))
+}
+
+fn is_honor(segment: &str) -> bool {
+    const KEYWORDS: [&str; 11] = [
+        "honors", "honours", "cum laude", "dean's list", "deans list", "distinction", "scholarship",
+        "valedictorian", "salutatorian", "award", "first class",
+    ];
+    let segment = segment.to_lowercase();
+    KEYWORDS.iter().any(|k| segment.contains(k))
+}
+
+/// Section names and the headings that introduce them
+const SECTION_HEADERS: [(&str, &[&str]); 6] = [
+    ("contact", &["contact", "personal information", "details"]),
+    ("summary", &["summary", "objective", "profile", "about"]),
+    ("experience", &["experience", "work experience", "employment", "professional experience", "work history"]),
+    ("education", &["education", "academic background", "qualifications"]),
+    ("skills", &["skills", "technical skills", "competencies", "proficiencies"]),
+    ("projects", &["projects", "personal projects", "portfolio"]),
+];
+
+/// Find the section a heading introduces, if any
+fn section_for_heading(heading: &str) -> Option<&'static str> {
+    let heading = heading.to_lowercase();
+    SECTION_HEADERS
+        .iter()
+        .find(|(_, headers)| headers.iter().any(|h| heading.contains(h)))
+        .map(|(name, _)| *name)
+}
+
+/// Block ranges `(heading, end)` of the known sections in a structured document
+///
+/// A section runs from its heading to the next heading of the same or a
+/// higher level.
+fn section_ranges(blocks: &[DocumentBlock]) -> HashMap<&'static str, (usize, usize)> {
+    let mut sections = HashMap::new();
+
+    for (i, block) in blocks.iter().enumerate() {
+        let BlockKind::Heading(level) = block.kind else { continue };
+        let Some(name) = section_for_heading(&block.text) else { continue };
+        if sections.contains_key(name) {
+            continue;
+        }
+
+        let end = blocks[i + 1..]
+            .iter()
+            .position(|b| matches!(b.kind, BlockKind::Heading(l) if l <= level))
+            .map(|p| i + 1 + p)
+            .unwrap_or(blocks.len());
+        sections.insert(name, (i, end));
+    }
+
+    sections
+}
+
+/// A paragraph of a structured document such as DOCX, Markdown or HTML
+#[derive(Debug, Clone, PartialEq)]
+pub struct DocumentBlock {
+    pub kind: BlockKind,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BlockKind {
+    /// A heading; 0 is the document title, 1 the top-level headings
+    Heading(u8),
+    /// A bullet or numbered list item at the given nesting depth
+    ListItem(u8),
+    Paragraph,
+}
+
+/// Collects the text of nested block elements (HTML, Markdown) into blocks
+///
+/// Text is attributed to the innermost open block, and opening or closing a
+/// block ends the text before it, so a list item's own text is kept apart
+/// from a list nested inside it.
+#[derive(Debug, Default)]
+pub(crate) struct BlockWriter {
+    blocks: Vec<DocumentBlock>,
+    open: Vec<BlockKind>,
+    text: String,
+}
+
+impl BlockWriter {
+    pub(crate) fn open(&mut self, kind: BlockKind) {
+        self.flush();
+        self.open.push(kind);
+    }
+
+    pub(crate) fn close(&mut self) {
+        self.flush();
+        self.open.pop();
+    }
+
+    /// Kind of the innermost open block
+    pub(crate) fn current(&self) -> Option<BlockKind> {
+        self.open.last().copied()
+    }
+
+    pub(crate) fn push_text(&mut self, text: &str) {
+        self.text.push_str(text);
+    }
+
+    pub(crate) fn flush(&mut self) {
+        let text = self.text.split_whitespace().collect::<Vec<_>>().join(" ");
+        self.text.clear();
+        if !text.is_empty() {
+            let kind = self.current().unwrap_or(BlockKind::Paragraph);
+            self.blocks.push(DocumentBlock { kind, text });
+        }
+    }
+
+    pub(crate) fn finish(mut self) -> Vec<DocumentBlock> {
+        self.flush();
+        self.blocks
+    }
+}
+
+/// Split plain text into blocks
+///
+/// Short lines naming a known section become headings and lines starting
+/// with a bullet become list items, so plain text resumes get the same
+/// structured parsing as documents with real headings.
+fn text_blocks(text: &str) -> Vec<DocumentBlock> {
+    text.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let trimmed = line.trim();
+            let indent = line.len() - line.trim_start().len();
+
+            let bullet_text = trimmed
+                .strip_prefix(LIST_BULLETS)
+                .filter(|rest| rest.starts_with(char::is_whitespace))
+                .map(str::trim);
+            if let Some(item) = bullet_text {
+                let depth = if indent >= 4 { 1 } else { 0 };
+                return DocumentBlock { kind: BlockKind::ListItem(depth), text: item.to_string() };
+            }
+
+            let heading = trimmed.trim_end_matches(':');
+            if heading.split_whitespace().count() <= 4 && section_for_heading(heading).is_some() {
+                return DocumentBlock { kind: BlockKind::Heading(1), text: heading.to_string() };
+            }
+
+            DocumentBlock { kind: BlockKind::Paragraph, text: trimmed.to_string() }
+        })
+        .collect()
+}
+
+/// Decode an uploaded text file, dropping a UTF-8 byte order mark
+fn decode_text(data: &[u8]) -> String {
+    let text = String::from_utf8_lossy(data);
+    text.strip_prefix('\u{feff}').unwrap_or(&text).to_string()
+}
+
 struct ExperienceBuilder {
     id: Uuid,
     profile_id: Uuid,
     company: String,
     position: String,
     location: Option<String>,
-    start_date: DateTime<Utc>,
+    start_date: Option<DateTime<Utc>>,
     end_date: Option<DateTime<Utc>>,
     current: bool,
     description: String,
     achievements: Vec<String>,
+    source: Option<std::ops::Range<usize>>,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
 }
 
 impl ExperienceBuilder {
+    fn extend_source(&mut self, span: std::ops::Range<usize>) {
+        self.source = Some(extend_span(self.source.take(), span));
+    }
+
+    /// How complete the job looks: a named company and position, dates and
+    /// some detail each add to the score
+    fn confidence(&self) -> f32 {
+        let mut score: f32 = 0.4;
+        if self.company != "Unknown Company" {
+            score += 0.15;
+        }
+        if self.position != "Unknown Position" {
+            score += 0.15;
+        }
+        if self.start_date.is_some() {
+            score += 0.2;
+        }
+        if !self.description.is_empty() || !self.achievements.is_empty() {
+            score += 0.1;
+        }
+        score.min(1.0)
+    }
+
+    /// Build the experience and record its confidence
+    fn finish(self, text: &str, experiences: &mut Vec<Experience>, confidence: &mut ParseConfidence) {
+        let score = self.confidence();
+        let source = self.source.clone();
+        if let Ok(experience) = self.build() {
+            match source {
+                Some(span) => confidence.set_entry(experience.id, score, text, span),
+                None => confidence.set_entry_without_source(experience.id, score),
+            }
+            experiences.push(experience);
+        }
+    }
+
+    fn set_dates(&mut self, range: DateRange) {
+        self.start_date = Some(range.start);
+        // A single date on a job is when it started
+        self.end_date = range.end.filter(|end| *end != range.start);
+        self.current = range.current;
+    }
+
     fn add_description_line(&mut self, line: &str) {
         if !self.description.is_empty() {
             self.description.push('\n');
@@ -302,7 +939,7 @@ impl ExperienceBuilder {
             company: self.company,
             position: self.position,
             location: self.location,
-            start_date: self.start_date,
+            start_date: self.start_date.unwrap_or(self.created_at),
             end_date: self.end_date,
             current: self.current,
             description: self.description,
@@ -313,11 +950,404 @@ impl ExperienceBuilder {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+struct EducationBuilder {
+    institution: String,
+    degree: String,
+    field: String,
+    start_date: Option<DateTime<Utc>>,
+    end_date: Option<DateTime<Utc>>,
+    gpa: Option<f32>,
+    honors: Vec<String>,
+    source: Option<std::ops::Range<usize>>,
+}
+
+impl EducationBuilder {
+    fn new() -> Self {
+        Self {
+            institution: String::new(),
+            degree: String::new(),
+            field: String::new(),
+            start_date: None,
+            end_date: None,
+            gpa: None,
+            honors: Vec::new(),
+            source: None,
+        }
+    }
+
+    fn extend_source(&mut self, span: std::ops::Range<usize>) {
+        self.source = Some(extend_span(self.source.take(), span));
+    }
+
+    fn confidence(&self) -> f32 {
+        let mut score: f32 = 0.3;
+        if !self.institution.is_empty() {
+            score += 0.25;
+        }
+        if !self.degree.is_empty() {
+            score += 0.25;
+        }
+        if !self.field.is_empty() {
+            score += 0.1;
+        }
+        if self.start_date.is_some() {
+            score += 0.1;
+        }
+        score.min(1.0)
+    }
+
+    fn finish(self, text: &str, education: &mut Vec<Education>, confidence: &mut ParseConfidence) {
+        let score = self.confidence();
+        let source = self.source.clone();
+        if let Some(entry) = self.build() {
+            match source {
+                Some(span) => confidence.set_entry(entry.id, score, text, span),
+                None => confidence.set_entry_without_source(entry.id, score),
+            }
+            education.push(entry);
+        }
+    }
+
+    fn set_dates(&mut self, range: DateRange) {
+        // A single date on a degree is when it was completed
+        self.start_date = Some(range.start);
+        self.end_date = range.end;
+    }
+
+    /// Entries with neither an institution nor a degree are dropped
+    fn build(self) -> Option<Education> {
+        if self.institution.is_empty() && self.degree.is_empty() {
+            return None;
+        }
+        let now = Utc::now();
+        Some(Education {
+            id: Uuid::new_v4(),
+            profile_id: Uuid::new_v4(),
+            institution: self.institution,
+            degree: self.degree,
+            field: self.field,
+            location: None,
+            start_date: self.start_date.or(self.end_date).unwrap_or(now),
+            end_date: self.end_date,
+            gpa: self.gpa,
+            honors: self.honors,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ParsedResume {
     pub profile: Profile,
     pub experiences: Vec<Experience>,
     pub education: Vec<Education>,
     pub skills: Vec<Skill>,
     pub projects: Vec<Project>,
-}
\ No newline at end of file
+    /// How reliable each extracted value is, for review before saving
+    #[serde(default)]
+    pub confidence: ParseConfidence,
+}
+
+/// Entries scoring below this should be checked by the user before saving
+pub const LOW_CONFIDENCE: f32 = 0.6;
+
+/// Confidence and provenance of everything extracted from a resume
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct ParseConfidence {
+    /// Profile fields by name (`"name"`, `"email"`, `"phone"`, ...)
+    pub profile: HashMap<String, FieldConfidence>,
+    /// Experiences, education, skills and projects by id
+    pub entries: HashMap<Uuid, FieldConfidence>,
+}
+
+/// How much an extracted value can be trusted, and where it was found
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FieldConfidence {
+    /// From 0.0 (a guess) to 1.0 (read from a structured field)
+    pub confidence: f32,
+    /// The text the value was read from, if it came from free text
+    pub source: Option<SourceSpan>,
+}
+
+/// A byte range of the text a resume was parsed from
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SourceSpan {
+    pub start: usize,
+    pub end: usize,
+    pub text: String,
+}
+
+impl ParseConfidence {
+    pub fn profile_field(&self, field: &str) -> Option<&FieldConfidence> {
+        self.profile.get(field)
+    }
+
+    pub fn entry(&self, id: &Uuid) -> Option<&FieldConfidence> {
+        self.entries.get(id)
+    }
+
+    fn set_field(&mut self, field: &str, confidence: f32, text: &str, span: std::ops::Range<usize>) {
+        self.profile.insert(field.to_string(), FieldConfidence::found(confidence, text, span));
+    }
+
+    fn set_entry(&mut self, id: Uuid, confidence: f32, text: &str, span: std::ops::Range<usize>) {
+        self.entries.insert(id, FieldConfidence::found(confidence, text, span));
+    }
+
+    pub(crate) fn set_entry_without_source(&mut self, id: Uuid, confidence: f32) {
+        self.entries.insert(id, FieldConfidence { confidence, source: None });
+    }
+
+    pub(crate) fn set_field_without_source(&mut self, field: &str, confidence: f32) {
+        self.profile.insert(field.to_string(), FieldConfidence { confidence, source: None });
+    }
+}
+
+impl FieldConfidence {
+    fn found(confidence: f32, text: &str, span: std::ops::Range<usize>) -> Self {
+        let source = text.get(span.clone()).map(|snippet| SourceSpan {
+            start: span.start,
+            end: span.end,
+            text: snippet.to_string(),
+        });
+        FieldConfidence { confidence, source }
+    }
+
+    pub fn is_low(&self) -> bool {
+        self.confidence < LOW_CONFIDENCE
+    }
+}
+
+#[cfg(test)]
+impl ParsedResume {
+    /// The parsed resume as a stored profile, for export tests
+    pub(crate) fn into_full_profile(self) -> crate::models::FullProfile {
+        crate::models::FullProfile {
+            profile: self.profile,
+            skills: self.skills,
+            experiences: self.experiences,
+            education: self.education,
+            projects: self.projects,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Datelike;
+
+    fn year_month(date: DateTime<Utc>) -> (i32, u32) {
+        (date.year(), date.month())
+    }
+
+    #[test]
+    fn test_find_date_range() {
+        let parser = ResumeParser::new().unwrap();
+        let range = |line: &str| parser.find_date_range(line).map(|(range, _)| range).unwrap();
+
+        let current = range("Jan 2019 \u{2013} Present");
+        assert_eq!(year_month(current.start), (2019, 1));
+        assert_eq!(current.end, None);
+        assert!(current.current);
+
+        let numeric = range("03/2018 - 11/2020");
+        assert_eq!(year_month(numeric.start), (2018, 3));
+        assert_eq!(numeric.end.map(year_month), Some((2020, 11)));
+        assert!(!numeric.current);
+
+        let years = range("2015\u{2013}2019");
+        assert_eq!(year_month(years.start), (2015, 1));
+        assert_eq!(years.end.map(year_month), Some((2019, 1)));
+
+        let spelled = range("September 2020 to March 2022");
+        assert_eq!(year_month(spelled.start), (2020, 9));
+        assert_eq!(spelled.end.map(year_month), Some((2022, 3)));
+
+        // Phone numbers are not dates
+        assert!(parser.find_date_range("+1 (555) 222-3333").is_none());
+    }
+
+    #[test]
+    fn test_job_header_dates() {
+        let parser = ResumeParser::new().unwrap();
+
+        let job = parser.parse_job_header("Acme Corp, Engineer, Jan 2019 \u{2013} Present").build().unwrap();
+        assert_eq!(job.company, "Acme Corp");
+        assert_eq!(job.position, "Engineer");
+        assert_eq!(year_month(job.start_date), (2019, 1));
+        assert!(job.current);
+
+        let job = parser.parse_job_header("Globex, Analyst (2015 - 2017)").build().unwrap();
+        assert_eq!(job.position, "Analyst");
+        assert_eq!(job.end_date.map(year_month), Some((2017, 1)));
+        assert!(!job.current);
+    }
+
+    #[tokio::test]
+    async fn test_extract_education() {
+        let parser = ResumeParser::new().unwrap();
+        let text = "Chris Park\nchris.park@example.com\n\nEducation\n\
+            Stanford University\n\
+            Master of Science in Computer Science, 2018 - 2020\n\
+            GPA: 3.9/4.0\n\
+            Texas State University | B.A. Economics | May 2016\n\
+            Honors: Dean's List, Magna Cum Laude\n\n\
+            Skills\nPython, Rust\n";
+        let parsed = parser.parse_text(text).await.unwrap();
+
+        assert_eq!(parsed.education.len(), 2);
+        let masters = &parsed.education[0];
+        assert_eq!(masters.institution, "Stanford University");
+        assert_eq!(masters.degree, "Master of Science");
+        assert_eq!(masters.field, "Computer Science");
+        assert_eq!(masters.gpa, Some(3.9));
+        assert_eq!(year_month(masters.start_date), (2018, 1));
+        assert_eq!(masters.end_date.map(year_month), Some((2020, 1)));
+
+        let bachelors = &parsed.education[1];
+        assert_eq!(bachelors.institution, "Texas State University");
+        assert_eq!(bachelors.degree, "B.A.");
+        assert_eq!(bachelors.field, "Economics");
+        assert_eq!(bachelors.end_date.map(year_month), Some((2016, 5)));
+        assert_eq!(bachelors.honors, vec!["Dean's List".to_string(), "Magna Cum Laude".to_string()]);
+
+        // The education section ends where the skills section starts
+        assert_eq!(parsed.skills.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_parse_pdf_fixture() {
+        let parser = ResumeParser::new().unwrap();
+        let parsed = parser
+            .parse_pdf(include_bytes!("../../tests/fixtures/resume_single_column.pdf"))
+            .await
+            .unwrap();
+
+        assert_eq!(parsed.profile.name, "Jane Doe");
+        assert_eq!(parsed.profile.email, "jane.doe@example.com");
+        assert_eq!(parsed.profile.github_url.as_deref(), Some("https://github.com/janedoe"));
+        let skills: Vec<&str> = parsed.skills.iter().map(|s| s.name.as_str()).collect();
+        assert!(skills.contains(&"Rust"));
+        assert!(skills.contains(&"Kubernetes"));
+    }
+
+    #[tokio::test]
+    async fn test_parse_two_column_pdf_fixture() {
+        let parser = ResumeParser::new().unwrap();
+        let parsed = parser
+            .parse_pdf(include_bytes!("../../tests/fixtures/resume_two_column.pdf"))
+            .await
+            .unwrap();
+
+        assert_eq!(parsed.profile.name, "John Smith");
+        assert_eq!(parsed.profile.email, "john.smith@example.com");
+    }
+
+    #[tokio::test]
+    async fn test_parse_docx_fixture() {
+        let parser = ResumeParser::new().unwrap();
+        let parsed = parser
+            .parse_docx(include_bytes!("../../tests/fixtures/resume.docx"))
+            .await
+            .unwrap();
+
+        assert_eq!(parsed.profile.name, "Maria Garcia");
+        assert_eq!(parsed.profile.email, "maria.garcia@example.com");
+
+        // Bullets become achievements of the job they follow
+        assert_eq!(parsed.experiences.len(), 2);
+        assert_eq!(parsed.experiences[0].company, "Globex Corp");
+        assert_eq!(parsed.experiences[0].position, "Staff Engineer");
+        assert_eq!(parsed.experiences[0].achievements.len(), 2);
+        assert!(parsed.experiences[0].current);
+        assert_eq!(parsed.experiences[1].company, "Initech");
+        assert_eq!(parsed.experiences[1].end_date.map(|d| (d.year(), d.month())), Some((2020, 12)));
+        assert_eq!(
+            parsed.experiences[1].achievements,
+            vec!["Designed the event pipeline processing 2M messages per day".to_string()]
+        );
+
+        assert!(parsed.skills.iter().any(|s| s.name == "Rust"));
+    }
+
+    #[tokio::test]
+    async fn test_parse_file_dispatches_on_extension() {
+        let parser = ResumeParser::new().unwrap();
+
+        let txt = parser.parse_file("resume.txt", include_bytes!("../../tests/fixtures/resume.txt")).await.unwrap();
+        assert_eq!(txt.profile.name, "Jordan Lee");
+        assert_eq!(txt.experiences.len(), 2);
+        assert_eq!(txt.experiences[0].company, "Acme Corp");
+        assert_eq!(txt.experiences[0].start_date.year(), 2019);
+        assert!(txt.experiences[0].current);
+        assert_eq!(txt.experiences[0].achievements.len(), 2);
+        assert!(txt.skills.iter().any(|s| s.name == "Kubernetes"));
+
+        let md = parser.parse_file("Resume.MD", include_bytes!("../../tests/fixtures/resume.md")).await.unwrap();
+        assert_eq!(md.profile.name, "Alex Chen");
+        assert_eq!(md.profile.github_url.as_deref(), Some("https://github.com/alexchen"));
+        assert_eq!(md.experiences[0].position, "Backend Engineer");
+        assert_eq!(md.experiences[0].achievements[0], "Built the order routing service in Rust");
+        assert_eq!(md.experiences[1].company, "Contoso");
+        assert_eq!(md.education[0].institution, "University of Washington");
+        assert_eq!(md.education[0].degree, "B.S.");
+        assert_eq!(md.education[0].field, "Computer Science");
+
+        let html = parser.parse_file("resume.html", include_bytes!("../../tests/fixtures/resume.html")).await.unwrap();
+        assert_eq!(html.profile.name, "Priya Patel");
+        assert_eq!(html.profile.email, "priya.patel@example.com");
+        assert_eq!(html.experiences.len(), 2);
+        assert_eq!(html.experiences[0].achievements.len(), 2);
+        assert!(html.skills.iter().any(|s| s.name == "AWS"));
+
+        let json = parser.parse_file("resume.json", include_bytes!("../../tests/fixtures/resume.json")).await.unwrap();
+        assert_eq!(json.profile.name, "Sam Rivera");
+
+        assert!(parser.parse_file("resume.odt", b"").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_confidence_and_sources() {
+        let parser = ResumeParser::new().unwrap();
+        let parsed = parser.parse_file("resume.txt", include_bytes!("../../tests/fixtures/resume.txt")).await.unwrap();
+
+        let email = parsed.confidence.profile_field("email").unwrap();
+        assert!(email.confidence > 0.9);
+        assert_eq!(email.source.as_ref().unwrap().text, parsed.profile.email);
+
+        // A job with a company, position, dates and bullets is fully trusted
+        let job = parsed.confidence.entry(&parsed.experiences[0].id).unwrap();
+        assert!(!job.is_low());
+        assert!(job.source.as_ref().unwrap().text.contains("Acme Corp"));
+
+        for skill in &parsed.skills {
+            let source = parsed.confidence.entry(&skill.id).unwrap().source.as_ref().unwrap();
+            assert_eq!(source.text, skill.name);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_extract_multi_word_and_punctuated_skills() {
+        let parser = ResumeParser::new().unwrap();
+        let text = "Jane Doe\njane@example.com\n\nSkills\nC++, C#, Node.js, React Native, Machine Learning, k8s, Google Docs\n";
+        let parsed = parser.parse_txt(text.as_bytes()).await.unwrap();
+
+        let skills: Vec<&str> = parsed.skills.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(skills, vec!["C++", "C#", "Node.js", "React Native", "Machine Learning", "Kubernetes", "Google Docs"]);
+        let source = parsed.confidence.entry(&parsed.skills[3].id).unwrap().source.as_ref().unwrap();
+        assert_eq!(source.text, "React Native");
+    }
+
+    #[tokio::test]
+    async fn test_structured_experience_sources() {
+        let parser = ResumeParser::new().unwrap();
+        let parsed = parser.parse_file("resume.md", include_bytes!("../../tests/fixtures/resume.md")).await.unwrap();
+
+        assert_eq!(parsed.confidence.entries.len(), parsed.experiences.len() + parsed.education.len() + parsed.skills.len());
+        let source = parsed.confidence.entry(&parsed.experiences[0].id).unwrap().source.as_ref().unwrap();
+        assert!(source.text.starts_with("Northwind Traders"));
+        assert!(source.text.contains("Built the order routing service in Rust"));
+    }
+}
@@ -64,13 +64,39 @@ pub async fn analyze_resume(resume_id: String) -> Result<ResumeAnalysis, ServerF
 /// Get saved resumes for user
 #[server(GetUserResumes)]
 pub async fn get_user_resumes(user_id: String) -> Result<Vec<Resume>, ServerFnError> {
-    Ok(Vec::new())
+    use crate::db::{get_database, SqliteResumeRepository, ResumeRepository};
+    
+    let user_uuid = uuid::Uuid::parse_str(&user_id)
+        .map_err(|e| ServerFnError::new(format!("Invalid user ID: {}", e)))?;
+    
+    let db = get_database();
+    let repo = SqliteResumeRepository::new(db.clone());
+    
+    repo.get_by_user_id(&user_uuid)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
 }
 
-/// Save resume
+/// Save or update resume
 #[server(SaveResume)]
 pub async fn save_resume(resume: Resume) -> Result<Resume, ServerFnError> {
-    Ok(resume)
+    store_resume(crate::db::get_database(), &resume)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// Create the resume, or update it when it has been stored before
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn store_resume(db: &crate::db::Database, resume: &Resume) -> Result<Resume> {
+    use crate::db::{SqliteResumeRepository, ResumeRepository};
+    
+    let repo = SqliteResumeRepository::new(db.clone());
+    
+    if repo.get_by_id(&resume.id).await?.is_some() {
+        repo.update(resume).await
+    } else {
+        repo.create(resume).await
+    }
 }
 
 async fn generate_professional_template(
@@ -243,4 +269,37 @@ async fn generate_academic_template(
 ) -> Result<String> {
     // Similar implementation for academic template
     Ok("Academic template HTML".to_string())
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::{Database, ResumeRepository, SqliteResumeRepository};
+    use crate::test_fixtures::profile;
+
+    #[tokio::test]
+    async fn test_default_user_can_save_resumes_on_a_fresh_database() {
+        let db = Database::in_memory().await.unwrap();
+        let user_id = uuid::Uuid::parse_str(DEFAULT_USER_ID).unwrap();
+        let mut resume = Resume {
+            id: uuid::Uuid::new_v4(),
+            user_id,
+            name: "Backend roles".to_string(),
+            template: ResumeTemplate::Modern,
+            profile_snapshot: profile("Jordan Lee", "jordan@example.com"),
+            selected_experiences: Vec::new(),
+            selected_projects: Vec::new(),
+            selected_skills: Vec::new(),
+            custom_sections: Vec::new(),
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+        };
+
+        store_resume(&db, &resume).await.unwrap();
+        resume.name = "Research roles".to_string();
+        store_resume(&db, &resume).await.unwrap();
+
+        let stored = SqliteResumeRepository::new(db).get_by_user_id(&user_id).await.unwrap();
+        assert_eq!(stored.len(), 1);
+        assert_eq!(stored[0].name, "Research roles");
+    }
+}
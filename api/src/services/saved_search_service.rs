@@ -0,0 +1,201 @@
+use dioxus::prelude::*;
+use crate::models::*;
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::db::{Database, JobRepository, JobSearchQuery, SavedSearchRepository, SqliteJobRepository, SqliteSavedSearchRepository};
+
+/// Save the filters of a job search under `name`
+///
+/// Jobs that already match are not new; only jobs that start matching after
+/// later fetches are counted as new matches.
+#[server(SaveSearch)]
+pub async fn save_search(
+    user_id: String,
+    name: String,
+    keywords: String,
+    location: String,
+    salary_min: Option<u32>,
+    sources: Vec<JobSource>,
+    remote_only: bool,
+) -> Result<SavedSearch, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use crate::services::job_service::search_filters;
+
+        let user_uuid = uuid::Uuid::parse_str(&user_id).map_err(|e| ServerFnError::new(e.to_string()))?;
+        if name.trim().is_empty() {
+            return Err(ServerFnError::new("A saved search needs a name"));
+        }
+
+        let query = search_filters(keywords, location, salary_min, sources, remote_only);
+        create_saved_search(crate::db::get_database(), &SavedSearch::new(user_uuid, name.trim()), &query)
+            .await
+            .map_err(|e| ServerFnError::new(e.to_string()))
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Saved searches only available on server"))
+    }
+}
+
+/// Get a user's saved searches with their numbers of new matches
+#[server(GetSavedSearches)]
+pub async fn get_saved_searches(user_id: String) -> Result<Vec<SavedSearch>, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let user_uuid = uuid::Uuid::parse_str(&user_id).map_err(|e| ServerFnError::new(e.to_string()))?;
+
+        SqliteSavedSearchRepository::new(crate::db::get_database().clone())
+            .get_by_user_id(&user_uuid)
+            .await
+            .map_err(|e| ServerFnError::new(e.to_string()))
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    {
+        Ok(Vec::new())
+    }
+}
+
+/// Get the new matches of a saved search, newest first
+#[server(GetSavedSearchNewJobs)]
+pub async fn get_saved_search_new_jobs(search_id: String) -> Result<Vec<JobListing>, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let search_uuid = uuid::Uuid::parse_str(&search_id).map_err(|e| ServerFnError::new(e.to_string()))?;
+
+        SqliteSavedSearchRepository::new(crate::db::get_database().clone())
+            .get_new_matches(&search_uuid)
+            .await
+            .map_err(|e| ServerFnError::new(e.to_string()))
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    {
+        Ok(Vec::new())
+    }
+}
+
+/// Mark all matches of a saved search as seen
+#[server(MarkSavedSearchSeen)]
+pub async fn mark_saved_search_seen(search_id: String) -> Result<(), ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let search_uuid = uuid::Uuid::parse_str(&search_id).map_err(|e| ServerFnError::new(e.to_string()))?;
+
+        SqliteSavedSearchRepository::new(crate::db::get_database().clone())
+            .mark_seen(&search_uuid)
+            .await
+            .map_err(|e| ServerFnError::new(e.to_string()))
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    {
+        Ok(())
+    }
+}
+
+/// Delete a saved search
+#[server(DeleteSavedSearch)]
+pub async fn delete_saved_search(search_id: String) -> Result<(), ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let search_uuid = uuid::Uuid::parse_str(&search_id).map_err(|e| ServerFnError::new(e.to_string()))?;
+
+        SqliteSavedSearchRepository::new(crate::db::get_database().clone())
+            .delete(&search_uuid)
+            .await
+            .map_err(|e| ServerFnError::new(e.to_string()))
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    {
+        Ok(())
+    }
+}
+
+/// Store a saved search, recording the jobs it already matches as seen
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn create_saved_search(
+    db: &Database,
+    search: &SavedSearch,
+    query: &JobSearchQuery,
+) -> anyhow::Result<SavedSearch> {
+    let repo = SqliteSavedSearchRepository::new(db.clone());
+    let mut search = repo.create(search, query).await?;
+
+    let job_ids = matching_job_ids(db, query).await?;
+    repo.record_matches(&search.id, &job_ids, true).await?;
+    search.last_checked_at = Some(chrono::Utc::now());
+
+    Ok(search)
+}
+
+/// Re-run every saved search and record the jobs it newly matches
+///
+/// Returns the number of new matches over all searches.
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn check_saved_searches(db: &Database) -> anyhow::Result<u32> {
+    let repo = SqliteSavedSearchRepository::new(db.clone());
+
+    let mut total = 0;
+    for (search, query) in repo.get_all().await? {
+        let job_ids = matching_job_ids(db, &query).await?;
+        let new = repo.record_matches(&search.id, &job_ids, false).await?;
+        if new > 0 {
+            tracing::info!("{} new jobs for saved search '{}'", new, search.name);
+        }
+        total += new;
+    }
+    Ok(total)
+}
+
+/// Ids of all stored jobs matching `query`, regardless of its paging
+#[cfg(not(target_arch = "wasm32"))]
+async fn matching_job_ids(db: &Database, query: &JobSearchQuery) -> anyhow::Result<Vec<uuid::Uuid>> {
+    let query = JobSearchQuery { limit: None, offset: None, ..query.clone() };
+    let results = SqliteJobRepository::new(db.clone()).search(&query).await?;
+    Ok(results.into_iter().map(|result| result.listing.job.id).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::job;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn test_saved_search_counts_new_matches() {
+        let db = Database::in_memory().await.unwrap();
+        let jobs = SqliteJobRepository::new(db.clone());
+        let searches = SqliteSavedSearchRepository::new(db.clone());
+        let user_id = Uuid::new_v4();
+        jobs.create(&job("Rust Backend Engineer", "Acme", "Remote - Europe", JobSource::Remotive)).await.unwrap();
+
+        let query = JobSearchQuery::new().keywords("rust").remote_only(true);
+        let search = create_saved_search(&db, &SavedSearch::new(user_id, "Rust backend, Remote EU"), &query)
+            .await
+            .unwrap();
+        assert_eq!(searches.get_by_user_id(&user_id).await.unwrap()[0].new_matches, 0);
+
+        let new_job = job("Senior Rust Developer", "Globex", "Remote", JobSource::Remotive);
+        jobs.create(&new_job).await.unwrap();
+        jobs.create(&job("Rust Engineer", "Initech", "Berlin", JobSource::Remotive)).await.unwrap();
+        jobs.create(&job("Go Developer", "Umbrella", "Remote", JobSource::Remotive)).await.unwrap();
+        assert_eq!(check_saved_searches(&db).await.unwrap(), 1);
+        assert_eq!(check_saved_searches(&db).await.unwrap(), 0);
+
+        let saved = searches.get_by_user_id(&user_id).await.unwrap();
+        assert_eq!(saved[0].new_matches, 1);
+        assert!(saved[0].last_checked_at.is_some());
+        let new_jobs = searches.get_new_matches(&search.id).await.unwrap();
+        assert_eq!(new_jobs.iter().map(|l| l.job.id).collect::<Vec<_>>(), vec![new_job.id]);
+
+        searches.mark_seen(&search.id).await.unwrap();
+        assert_eq!(searches.get_by_user_id(&user_id).await.unwrap()[0].new_matches, 0);
+
+        searches.delete(&search.id).await.unwrap();
+        assert!(searches.get_by_user_id(&user_id).await.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,535 @@
+//! Skill taxonomy
+//!
+//! Maps the names a skill goes by ("k8s", "Postgres") to its canonical name
+//! and [`SkillCategory`]. The built-in taxonomy is embedded from
+//! `data/skill_taxonomy.tsv` into the server only; skills the user adds are
+//! stored in SQLite and layered on top of it with
+//! [`SkillTaxonomy::with_custom_skills`].
+//!
+//! The same taxonomy is used to recognise skills in resumes and to tag the
+//! requirements of fetched jobs, so both sides use the same names.
+
+use anyhow::{anyhow, Result};
+use std::collections::{HashMap, HashSet};
+#[cfg(not(target_arch = "wasm32"))]
+use std::sync::{Arc, OnceLock};
+
+use crate::models::{SkillCategory, SkillDefinition};
+
+/// The built-in taxonomy, see the file header for its format
+#[cfg(not(target_arch = "wasm32"))]
+const BUILTIN_TAXONOMY: &str = include_str!("../../data/skill_taxonomy.tsv");
+
+/// Terms this short are always matched with their exact capitalization,
+/// so that "C" and "R" don't match every stray letter
+const MAX_SHORT_TERM_LEN: usize = 2;
+
+/// Phrases that start like a skill but aren't one, such as the "Go" in
+/// "Go-to-market". They take part in matching so that they win over the
+/// shorter skill name, but are never reported.
+const NOT_SKILLS: &[&str] = &["C-level", "C-suite", "Go-to-market", "Go-live", "R&D", "Q&A", "B2B", "B2C"];
+
+/// A set of known skills with fast lookup by name or alias
+#[derive(Debug, Clone)]
+pub struct SkillTaxonomy {
+    skills: Vec<SkillDefinition>,
+    /// Lowercased names and aliases that match regardless of case
+    terms: HashMap<String, usize>,
+    /// Names and aliases that only match as written
+    exact_terms: HashMap<String, usize>,
+    /// Names and aliases split into tokens, by their lowercased first token
+    phrases: HashMap<String, Vec<Phrase>>,
+}
+
+/// A name or alias split into tokens, for matching in running text
+#[derive(Debug, Clone)]
+struct Phrase {
+    /// Token text, lowercased unless the phrase is exact, and whether the
+    /// token follows whitespace
+    tokens: Vec<(String, bool)>,
+    exact: bool,
+    /// Index of the skill, `None` for phrases that are not skills
+    skill: Option<usize>,
+}
+
+/// A token of running text, see [`tokenize`]
+#[derive(Debug, Clone, Copy)]
+struct Token<'a> {
+    text: &'a str,
+    start: usize,
+    /// Whether whitespace comes before the token
+    spaced: bool,
+}
+
+/// A skill found in a piece of text
+#[derive(Debug, Clone)]
+pub struct SkillMatch<'a> {
+    pub skill: &'a SkillDefinition,
+    /// Byte range of the matched name or alias in the text
+    pub span: std::ops::Range<usize>,
+}
+
+impl SkillTaxonomy {
+    /// Parse a taxonomy in the tab-separated format of `data/skill_taxonomy.tsv`
+    ///
+    /// A name or alias that is listed for more than one skill is an error.
+    pub fn parse(tsv: &str) -> Result<Self> {
+        let mut taxonomy = Self::empty();
+        let mut seen = HashSet::new();
+
+        for (number, line) in tsv.lines().enumerate() {
+            let line = line.trim_end();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let columns: Vec<&str> = line.split('\t').map(str::trim).collect();
+            let (name, category) = match columns.as_slice() {
+                [name, category, ..] if !name.is_empty() => (*name, *category),
+                _ => return Err(anyhow!("Line {}: expected a name and a category", number + 1)),
+            };
+            let category = SkillCategory::from_name(category)
+                .ok_or_else(|| anyhow!("Line {}: unknown skill category '{}'", number + 1, category))?;
+            let aliases: Vec<String> = columns
+                .get(2)
+                .map(|aliases| aliases.split(',').map(str::trim).filter(|a| !a.is_empty()).map(String::from).collect())
+                .unwrap_or_default();
+            let case_sensitive = match columns.get(3) {
+                None | Some(&"") => false,
+                Some(&"case") => true,
+                Some(flag) => return Err(anyhow!("Line {}: unknown flag '{}'", number + 1, flag)),
+            };
+
+            for term in std::iter::once(name).chain(aliases.iter().map(String::as_str)) {
+                if !seen.insert(term.to_lowercase()) {
+                    return Err(anyhow!("Line {}: '{}' is already listed", number + 1, term));
+                }
+            }
+
+            taxonomy.skills.push(SkillDefinition {
+                name: name.to_string(),
+                category,
+                aliases,
+                case_sensitive,
+            });
+            taxonomy.index(taxonomy.skills.len() - 1);
+        }
+
+        Ok(taxonomy)
+    }
+
+    /// The taxonomy shipped with the application
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn builtin() -> Arc<SkillTaxonomy> {
+        static BUILTIN: OnceLock<Arc<SkillTaxonomy>> = OnceLock::new();
+        BUILTIN
+            .get_or_init(|| Arc::new(Self::parse(BUILTIN_TAXONOMY).expect("built-in skill taxonomy is valid")))
+            .clone()
+    }
+
+    /// The built-in taxonomy extended with the user's own skills
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn with_custom_skills(custom: &[SkillDefinition]) -> Self {
+        let mut taxonomy = (*Self::builtin()).clone();
+        for skill in custom {
+            taxonomy.add(skill.clone());
+        }
+        taxonomy
+    }
+
+    /// Add a skill, taking precedence over any existing skill with the same
+    /// name or aliases
+    ///
+    /// Adding a skill that is already known updates its category and adds
+    /// any new aliases.
+    pub fn add(&mut self, skill: SkillDefinition) {
+        let existing = self
+            .skills
+            .iter()
+            .position(|s| s.name.eq_ignore_ascii_case(&skill.name));
+
+        let index = match existing {
+            Some(index) => {
+                // The terms are indexed again below, possibly with another case sensitivity
+                self.unindex(index);
+                let known = &mut self.skills[index];
+                known.category = skill.category;
+                known.case_sensitive = skill.case_sensitive;
+                for alias in skill.aliases {
+                    if !known.aliases.iter().any(|a| a.eq_ignore_ascii_case(&alias)) {
+                        known.aliases.push(alias);
+                    }
+                }
+                index
+            }
+            None => {
+                self.skills.push(skill);
+                self.skills.len() - 1
+            }
+        };
+        self.index(index);
+    }
+
+    /// Find a skill by its name or one of its aliases
+    pub fn lookup(&self, term: &str) -> Option<&SkillDefinition> {
+        let term = term.trim();
+        self.exact_terms
+            .get(term)
+            .or_else(|| self.terms.get(&term.to_lowercase()))
+            .map(|&index| &self.skills[index])
+    }
+
+    /// The canonical name for a skill, or the name as given if it is unknown
+    pub fn canonical_name<'a>(&'a self, term: &'a str) -> &'a str {
+        self.lookup(term).map(|s| s.name.as_str()).unwrap_or_else(|| term.trim())
+    }
+
+    /// The category of a skill, `Other` if it is unknown
+    pub fn category(&self, term: &str) -> SkillCategory {
+        self.lookup(term)
+            .map(|s| s.category.clone())
+            .unwrap_or(SkillCategory::Other)
+    }
+
+    /// Find every known skill mentioned in `text`, in order of appearance
+    ///
+    /// Names are matched as whole tokens, so "Go" is not found in "Google",
+    /// and may span several words or include symbols ("React Native",
+    /// "C++", "Node.js"). Where names overlap the longest one wins.
+    pub fn find_in_text(&self, text: &str) -> Vec<SkillMatch<'_>> {
+        let tokens = tokenize(text);
+        let mut matches = Vec::new();
+
+        let mut i = 0;
+        while i < tokens.len() {
+            let Some(phrase) = self.phrase_at(&tokens[i..]) else {
+                i += 1;
+                continue;
+            };
+            let last = tokens[i + phrase.tokens.len() - 1];
+            if let Some(skill) = phrase.skill {
+                matches.push(SkillMatch {
+                    skill: &self.skills[skill],
+                    span: tokens[i].start..last.start + last.text.len(),
+                });
+            }
+            i += phrase.tokens.len();
+        }
+
+        matches
+    }
+
+    /// Canonical names of the skills mentioned in `text`, without duplicates
+    pub fn skill_names(&self, text: &str) -> Vec<String> {
+        let mut seen = HashSet::new();
+        self.find_in_text(text)
+            .into_iter()
+            .filter(|m| seen.insert(m.skill.name.as_str()))
+            .map(|m| m.skill.name.clone())
+            .collect()
+    }
+
+    /// Map tags to canonical skill names, keeping unknown tags as they are
+    /// and dropping duplicates
+    pub fn normalize(&self, tags: &[String]) -> Vec<String> {
+        let mut seen = HashSet::new();
+        tags.iter()
+            .map(|tag| self.canonical_name(tag).to_string())
+            .filter(|name| !name.is_empty() && seen.insert(name.to_lowercase()))
+            .collect()
+    }
+
+    /// All known skills
+    pub fn skills(&self) -> &[SkillDefinition] {
+        &self.skills
+    }
+
+    pub fn len(&self) -> usize {
+        self.skills.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.skills.is_empty()
+    }
+
+    /// A taxonomy without skills
+    fn empty() -> Self {
+        let mut taxonomy = Self {
+            skills: Vec::new(),
+            terms: HashMap::new(),
+            exact_terms: HashMap::new(),
+            phrases: HashMap::new(),
+        };
+        for phrase in NOT_SKILLS {
+            taxonomy.add_phrase(phrase, false, None);
+        }
+        taxonomy
+    }
+
+    /// Point the name and aliases of the skill at `index` to it
+    fn index(&mut self, index: usize) {
+        let skill = &self.skills[index];
+        let terms: Vec<(String, bool)> = std::iter::once(&skill.name)
+            .chain(&skill.aliases)
+            .enumerate()
+            .map(|(position, term)| {
+                let is_name = position == 0;
+                (term.clone(), (is_name && skill.case_sensitive) || term.chars().count() <= MAX_SHORT_TERM_LEN)
+            })
+            .collect();
+
+        for (term, exact) in terms {
+            self.add_phrase(&term, exact, Some(index));
+            if exact {
+                self.exact_terms.insert(term, index);
+            } else {
+                self.terms.insert(term.to_lowercase(), index);
+            }
+        }
+    }
+
+    /// Remove the name and aliases of the skill at `index` from the lookups
+    fn unindex(&mut self, index: usize) {
+        self.terms.retain(|_, &mut i| i != index);
+        self.exact_terms.retain(|_, &mut i| i != index);
+        for phrases in self.phrases.values_mut() {
+            phrases.retain(|p| p.skill != Some(index));
+        }
+    }
+
+    /// Make `term` findable in running text, replacing an identical phrase
+    fn add_phrase(&mut self, term: &str, exact: bool, skill: Option<usize>) {
+        let tokens: Vec<(String, bool)> = tokenize(term)
+            .into_iter()
+            .map(|t| (if exact { t.text.to_string() } else { t.text.to_lowercase() }, t.spaced))
+            .collect();
+        let Some((first, _)) = tokens.first() else {
+            return;
+        };
+
+        let phrases = self.phrases.entry(first.to_lowercase()).or_default();
+        phrases.retain(|p| p.exact != exact || p.tokens != tokens);
+        phrases.push(Phrase { tokens, exact, skill });
+    }
+
+    /// The longest phrase at the start of `tokens`
+    fn phrase_at(&self, tokens: &[Token<'_>]) -> Option<&Phrase> {
+        self.phrases
+            .get(&tokens[0].text.to_lowercase())?
+            .iter()
+            .filter(|phrase| phrase.matches(tokens))
+            .max_by_key(|phrase| (phrase.tokens.len(), phrase.exact))
+    }
+}
+
+impl Phrase {
+    /// Whether `tokens` start with this phrase, with whitespace in the
+    /// same places
+    fn matches(&self, tokens: &[Token<'_>]) -> bool {
+        tokens.len() >= self.tokens.len()
+            && self.tokens.iter().zip(tokens).enumerate().all(|(i, ((expected, spaced), token))| {
+                let same_text = if self.exact {
+                    token.text == expected
+                } else {
+                    token.text.to_lowercase() == *expected
+                };
+                same_text && (i == 0 || token.spaced == *spaced)
+            })
+    }
+}
+
+/// Split text into runs of letters and digits and single other characters
+///
+/// "Node.js, C++" becomes "Node", ".", "js", ",", "C", "+", "+", so that
+/// names are only ever matched as whole words.
+fn tokenize(text: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut spaced = false;
+    let mut chars = text.char_indices().peekable();
+
+    while let Some((start, c)) = chars.next() {
+        if c.is_whitespace() {
+            spaced = true;
+            continue;
+        }
+        let mut end = start + c.len_utf8();
+        if c.is_alphanumeric() {
+            while let Some(&(i, next)) = chars.peek() {
+                if !next.is_alphanumeric() {
+                    break;
+                }
+                end = i + next.len_utf8();
+                chars.next();
+            }
+        }
+        tokens.push(Token { text: &text[start..end], start, spaced });
+        spaced = false;
+    }
+
+    tokens
+}
+
+/// Load the built-in taxonomy together with the user's custom skills
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn load_skill_taxonomy(db: &crate::db::Database) -> Result<Arc<SkillTaxonomy>> {
+    use crate::db::{CustomSkillRepository, SqliteCustomSkillRepository};
+
+    let custom = SqliteCustomSkillRepository::new(db.clone()).get_all().await?;
+    if custom.is_empty() {
+        return Ok(SkillTaxonomy::builtin());
+    }
+    Ok(Arc::new(SkillTaxonomy::with_custom_skills(&custom)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(taxonomy: &SkillTaxonomy, text: &str) -> Vec<String> {
+        taxonomy.skill_names(text)
+    }
+
+    #[test]
+    fn test_builtin_taxonomy_parses() {
+        let taxonomy = SkillTaxonomy::builtin();
+        assert!(taxonomy.len() > 1000);
+        for category in ["Programming", "Framework", "Database", "Tool", "Language", "Soft", "Other"] {
+            let category = SkillCategory::from_name(category).unwrap();
+            assert!(taxonomy.skills().iter().any(|s| s.category == category));
+        }
+    }
+
+    #[test]
+    fn test_aliases_map_to_canonical_names() {
+        let taxonomy = SkillTaxonomy::builtin();
+        assert_eq!(taxonomy.canonical_name("k8s"), "Kubernetes");
+        assert_eq!(taxonomy.canonical_name("Postgres"), "PostgreSQL");
+        assert_eq!(taxonomy.canonical_name("golang"), "Go");
+        assert_eq!(taxonomy.canonical_name("Frobnicator"), "Frobnicator");
+        assert_eq!(taxonomy.category("k8s"), SkillCategory::Tool);
+        assert_eq!(taxonomy.category("postgres"), SkillCategory::Database);
+        assert_eq!(taxonomy.category("React"), SkillCategory::Framework);
+        assert_eq!(taxonomy.category("Spanish"), SkillCategory::Language);
+        assert_eq!(taxonomy.category("Frobnicator"), SkillCategory::Other);
+    }
+
+    #[test]
+    fn test_case_sensitive_terms() {
+        let taxonomy = SkillTaxonomy::builtin();
+        assert_eq!(taxonomy.lookup("Go").unwrap().name, "Go");
+        assert!(taxonomy.lookup("go").is_none());
+        assert!(taxonomy.lookup("excel").is_none());
+        assert_eq!(taxonomy.lookup("Excel").unwrap().name, "Excel");
+        // Aliases of flagged entries still ignore case
+        assert_eq!(taxonomy.lookup("GOLANG").unwrap().name, "Go");
+        // Short terms must match exactly even without the flag
+        assert_eq!(taxonomy.lookup("C").unwrap().name, "C");
+        assert!(taxonomy.lookup("c").is_none());
+        assert_eq!(taxonomy.lookup("python").unwrap().name, "Python");
+    }
+
+    #[test]
+    fn test_find_in_text() {
+        let taxonomy = SkillTaxonomy::builtin();
+        let text = "Python, k8s (EKS) and postgres; Rust.";
+        assert_eq!(names(&taxonomy, text), vec!["Python", "Kubernetes", "Amazon EKS", "PostgreSQL", "Rust"]);
+
+        let matches = taxonomy.find_in_text(text);
+        assert_eq!(&text[matches[1].span.clone()], "k8s");
+        assert_eq!(&text[matches[4].span.clone()], "Rust");
+        assert_eq!(names(&taxonomy, "C++, C# and Node.js"), vec!["C++", "C#", "Node.js"]);
+    }
+
+    #[test]
+    fn test_multi_word_and_punctuated_names() {
+        let taxonomy = SkillTaxonomy::builtin();
+        assert_eq!(
+            names(&taxonomy, "Skills: C++, C#, Node.js, React Native, Machine Learning"),
+            vec!["C++", "C#", "Node.js", "React Native", "Machine Learning"]
+        );
+        assert_eq!(names(&taxonomy, "C/C++ and ASP.NET Core on .NET"), vec!["C", "C++", "ASP.NET Core", ".NET"]);
+        assert_eq!(names(&taxonomy, "CI/CD with GitHub Actions"), vec!["CI/CD", "GitHub Actions"]);
+        // Line breaks count as whitespace
+        assert_eq!(names(&taxonomy, "machine\nlearning"), vec!["Machine Learning"]);
+        // The longest name wins over the names it contains
+        assert_eq!(names(&taxonomy, "Spring Boot"), vec!["Spring Boot"]);
+        assert_eq!(names(&taxonomy, "React"), vec!["React"]);
+    }
+
+    #[test]
+    fn test_names_only_match_whole_tokens() {
+        let taxonomy = SkillTaxonomy::builtin();
+        assert!(names(&taxonomy, "Google is hiring people to maintain our javascripts").is_empty());
+        assert!(names(&taxonomy, "Gopher, Rusty, Scalable, Javanese").is_empty());
+        assert!(names(&taxonomy, "Go-to-market for our C-level R&D team").is_empty());
+        assert!(names(&taxonomy, "React  Native").contains(&"React Native".to_string()));
+        assert_eq!(names(&taxonomy, "React-Native"), vec!["React"]);
+        assert_eq!(names(&taxonomy, "AI-powered tools in Go."), vec!["Artificial Intelligence", "Go"]);
+    }
+
+    #[test]
+    fn test_match_spans() {
+        let taxonomy = SkillTaxonomy::builtin();
+        let text = "Loves machine  learning and Node.js.";
+        let spans: Vec<&str> = taxonomy.find_in_text(text).iter().map(|m| &text[m.span.clone()]).collect();
+        assert_eq!(spans, vec!["machine  learning", "Node.js"]);
+    }
+
+    #[test]
+    fn test_normalize_tags() {
+        let taxonomy = SkillTaxonomy::builtin();
+        let tags = vec!["reactjs".to_string(), "React".to_string(), "Full-Time".to_string(), " ".to_string()];
+        assert_eq!(taxonomy.normalize(&tags), vec!["React", "Full-Time"]);
+    }
+
+    #[test]
+    fn test_custom_skills_extend_the_builtin_taxonomy() {
+        let taxonomy = SkillTaxonomy::with_custom_skills(&[
+            SkillDefinition {
+                name: "Frobnicator".to_string(),
+                category: SkillCategory::Tool,
+                aliases: vec!["frob".to_string()],
+                case_sensitive: false,
+            },
+            SkillDefinition {
+                name: "kubernetes".to_string(),
+                category: SkillCategory::Other,
+                aliases: vec!["kubes".to_string()],
+                case_sensitive: false,
+            },
+        ]);
+
+        assert_eq!(taxonomy.lookup("FROB").unwrap().name, "Frobnicator");
+        assert_eq!(taxonomy.category("frob"), SkillCategory::Tool);
+        // Known skills keep their name and aliases but take the new category
+        assert_eq!(taxonomy.canonical_name("kubes"), "Kubernetes");
+        assert_eq!(taxonomy.canonical_name("k8s"), "Kubernetes");
+        assert_eq!(taxonomy.category("k8s"), SkillCategory::Other);
+        assert_eq!(taxonomy.len(), SkillTaxonomy::builtin().len() + 1);
+    }
+
+    #[test]
+    fn test_readding_a_skill_changes_its_case_sensitivity() {
+        let mut taxonomy = SkillTaxonomy::parse("Frobnicator\tTool\n").unwrap();
+        taxonomy.add(SkillDefinition {
+            name: "Frobnicator".to_string(),
+            category: SkillCategory::Tool,
+            aliases: Vec::new(),
+            case_sensitive: true,
+        });
+
+        assert_eq!(taxonomy.lookup("Frobnicator").unwrap().name, "Frobnicator");
+        assert!(taxonomy.lookup("frobnicator").is_none());
+        assert!(names(&taxonomy, "a frobnicator").is_empty());
+        assert_eq!(names(&taxonomy, "a Frobnicator"), vec!["Frobnicator"]);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(SkillTaxonomy::parse("Rust\tProgramming\n").is_ok());
+        assert!(SkillTaxonomy::parse("Rust\n").is_err());
+        assert!(SkillTaxonomy::parse("Rust\tSystems\n").is_err());
+        assert!(SkillTaxonomy::parse("Rust\tProgramming\t\tloud\n").is_err());
+        assert!(SkillTaxonomy::parse("Go\tProgramming\tGolang\nGoLang\tTool\n").is_err());
+    }
+}
@@ -0,0 +1,107 @@
+//! Builders for the models used across the unit tests
+//!
+//! Tests adjust the few fields they care about, e.g.
+//! `Job { description: ..., ..job("Rust Engineer", "Acme", "Remote", JobSource::Remotive) }`,
+//! so a new model field only has to be added here.
+
+use chrono::{DateTime, TimeZone, Utc};
+use uuid::Uuid;
+
+use crate::models::{Experience, FullProfile, Job, JobSource, Profile, Skill, SkillCategory, SkillLevel, User};
+
+/// The first day of a month
+pub fn date(year: i32, month: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(year, month, 1, 0, 0, 0).unwrap()
+}
+
+/// A user created now
+pub fn user(name: &str, email: &str) -> User {
+    User {
+        id: Uuid::new_v4(),
+        email: email.to_string(),
+        name: name.to_string(),
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    }
+}
+
+/// A job posted now, with a unique URL
+pub fn job(title: &str, company: &str, location: &str, source: JobSource) -> Job {
+    Job {
+        id: Uuid::new_v4(),
+        title: title.to_string(),
+        company: company.to_string(),
+        location: location.to_string(),
+        department: None,
+        description: format!("{} at {}", title, company),
+        requirements: Vec::new(),
+        salary_range: None,
+        source,
+        source_url: format!("https://example.com/{}", Uuid::new_v4()),
+        source_job_id: None,
+        posted_date: Utc::now(),
+        scraped_at: Utc::now(),
+    }
+}
+
+/// A programming skill
+pub fn skill(name: &str, proficiency: SkillLevel) -> Skill {
+    Skill {
+        id: Uuid::new_v4(),
+        profile_id: Uuid::new_v4(),
+        name: name.to_string(),
+        category: SkillCategory::Programming,
+        proficiency,
+        years_experience: None,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    }
+}
+
+/// An engineering position, current when it has no end date
+pub fn experience(company: &str, start: DateTime<Utc>, end: Option<DateTime<Utc>>) -> Experience {
+    Experience {
+        id: Uuid::new_v4(),
+        profile_id: Uuid::new_v4(),
+        company: company.to_string(),
+        position: "Engineer".to_string(),
+        location: None,
+        start_date: start,
+        end_date: end,
+        current: end.is_none(),
+        description: String::new(),
+        achievements: Vec::new(),
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    }
+}
+
+/// A profile with only a name and an email address
+pub fn profile(name: &str, email: &str) -> Profile {
+    Profile {
+        id: Uuid::new_v4(),
+        user_id: Uuid::new_v4(),
+        name: name.to_string(),
+        headline: None,
+        summary: None,
+        phone: None,
+        email: email.to_string(),
+        location: None,
+        linkedin_url: None,
+        github_url: None,
+        portfolio_url: None,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    }
+}
+
+/// Jordan Lee's profile with the given skills and experience
+pub fn full_profile(skills: Vec<Skill>, experiences: Vec<Experience>) -> FullProfile {
+    FullProfile {
+        profile: profile("Jordan Lee", "jordan@example.com"),
+        skills,
+        experiences,
+        education: Vec::new(),
+        projects: Vec::new(),
+    }
+}
@@ -1,6 +1,27 @@
+use dioxus::prelude::*;
 use ui::SimpleApp;
 
+#[cfg(feature = "notifications")]
+mod notifications;
+
+#[cfg(feature = "notifications")]
+use notifications::SavedSearchNotifications;
+
 fn main() {
-    dioxus::launch(SimpleApp);
+    dioxus::launch(DesktopApp);
 }
 
+#[component]
+fn DesktopApp() -> Element {
+    rsx! {
+        SavedSearchNotifications {}
+        SimpleApp {}
+    }
+}
+
+/// Without the `notifications` feature, new matches are only shown in the app
+#[cfg(not(feature = "notifications"))]
+#[component]
+fn SavedSearchNotifications() -> Element {
+    rsx! {}
+}
@@ -0,0 +1,48 @@
+//! Desktop notifications for new matches of saved searches
+
+use dioxus::prelude::*;
+use std::collections::HashMap;
+use std::time::Duration;
+use api::DEFAULT_USER_ID;
+
+/// How often saved searches are checked for new matches
+const ALERT_POLL_INTERVAL: Duration = Duration::from_secs(5 * 60);
+
+/// Shows a desktop notification whenever a saved search gains new matches
+#[component]
+pub fn SavedSearchNotifications() -> Element {
+    use_future(|| async move {
+        // New-match counts already notified about, by saved search; `None`
+        // until the first poll, which only records the counts so that
+        // matches from before the app started aren't announced again
+        let mut notified: Option<HashMap<uuid::Uuid, u32>> = None;
+        loop {
+            match api::get_saved_searches(DEFAULT_USER_ID.to_string()).await {
+                Ok(searches) => {
+                    let first_poll = notified.is_none();
+                    let notified = notified.get_or_insert_with(HashMap::new);
+                    for search in searches {
+                        let previous = notified.insert(search.id, search.new_matches).unwrap_or(0);
+                        if !first_poll && search.new_matches > previous {
+                            notify(&format!("{} new jobs for '{}'", search.new_matches, search.name));
+                        }
+                    }
+                }
+                Err(e) => tracing::warn!("Failed to check saved searches: {}", e),
+            }
+            tokio::time::sleep(ALERT_POLL_INTERVAL).await;
+        }
+    });
+
+    rsx! {}
+}
+
+fn notify(body: &str) {
+    if let Err(e) = notify_rust::Notification::new()
+        .summary("Employment Barage")
+        .body(body)
+        .show()
+    {
+        tracing::warn!("Failed to show notification: {}", e);
+    }
+}
@@ -0,0 +1,134 @@
+use dioxus::prelude::*;
+use dioxus_bootstrap::*;
+use api::{CompanyBoard, JobSource, get_company_boards, add_company_board, remove_company_board};
+use crate::Label;
+
+/// Watchlist of company job boards that are fetched along with the other sources
+#[component]
+pub fn CompanyBoardWatchlist() -> Element {
+    let mut boards = use_signal(Vec::<CompanyBoard>::new);
+    let mut source = use_signal(|| CompanyBoard::SOURCES[0].clone());
+    let mut token = use_signal(String::new);
+    let mut company = use_signal(String::new);
+    let mut error = use_signal(|| None::<String>);
+
+    use_effect(move || {
+        spawn(async move {
+            match get_company_boards().await {
+                Ok(loaded) => boards.set(loaded),
+                Err(e) => tracing::error!("Failed to load company boards: {}", e),
+            }
+        });
+    });
+
+    let handle_add = move |_| {
+        let company = Some(company()).filter(|c| !c.trim().is_empty());
+        spawn(async move {
+            match add_company_board(source(), token(), company).await {
+                Ok(added) => {
+                    boards.with_mut(|all| {
+                        all.retain(|b| !(b.source == added.source && b.token == added.token));
+                        all.push(added);
+                    });
+                    token.set(String::new());
+                    error.set(None);
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    rsx! {
+        Card { class: "mt-4",
+            CardHeader {
+                h5 { class: "mb-0",
+                    i { class: "fas fa-building me-2" }
+                    "Company Boards"
+                }
+            }
+            CardBody {
+                p { class: "small text-muted",
+                    "Jobs of these companies are fetched straight from their Greenhouse, Lever, Ashby, "
+                    "Workable or SmartRecruiters boards. The board name is the last part of the board's URL, e.g. "
+                    code { "acme" }
+                    " for boards.greenhouse.io/acme, jobs.lever.co/acme, jobs.ashbyhq.com/acme, "
+                    "apply.workable.com/acme or jobs.smartrecruiters.com/acme."
+                }
+
+                for board in boards() {
+                    div { key: "{board.source.as_str()}-{board.token}",
+                        class: "d-flex justify-content-between align-items-center border-top py-2",
+                        span {
+                            strong { "{board.company_name()}" }
+                            span { class: "text-muted small", " · {board.source.display_name()} · {board.token}" }
+                        }
+                        Button {
+                            variant: ButtonVariant::Danger,
+                            size: Size::Small,
+                            onclick: move |_| {
+                                let removed = board.clone();
+                                spawn(async move {
+                                    match remove_company_board(removed.source.clone(), removed.token.clone()).await {
+                                        Ok(()) => boards.with_mut(|all| all.retain(|b| *b != removed)),
+                                        Err(e) => error.set(Some(e.to_string())),
+                                    }
+                                });
+                            },
+                            i { class: "fas fa-trash" }
+                        }
+                    }
+                }
+
+                div { class: "row g-2 mt-2",
+                    div { class: "col-md-3",
+                        Label { r#for: "board-source", class: "form-label small", "Tracking system" }
+                        Select {
+                            id: "board-source",
+                            class: "form-select",
+                            value: source().as_str().to_string(),
+                            onchange: move |evt: Event<FormData>| source.set(JobSource::from(evt.value().as_str())),
+                            for option_source in CompanyBoard::SOURCES {
+                                option { value: "{option_source.as_str()}", "{option_source.display_name()}" }
+                            }
+                        }
+                    }
+                    div { class: "col-md-4",
+                        Label { r#for: "board-token", class: "form-label small", "Board name" }
+                        Input {
+                            input_type: InputType::Text,
+                            id: "board-token",
+                            class: "form-control",
+                            placeholder: "e.g. acme",
+                            value: token(),
+                            oninput: move |evt: Event<FormData>| token.set(evt.value())
+                        }
+                    }
+                    div { class: "col-md-3",
+                        Label { r#for: "board-company", class: "form-label small", "Company (optional)" }
+                        Input {
+                            input_type: InputType::Text,
+                            id: "board-company",
+                            class: "form-control",
+                            placeholder: "e.g. Acme, Inc.",
+                            value: company(),
+                            oninput: move |evt: Event<FormData>| company.set(evt.value())
+                        }
+                    }
+                    div { class: "col-md-2 d-flex align-items-end",
+                        Button {
+                            variant: ButtonVariant::Primary,
+                            class: "w-100",
+                            disabled: token().trim().is_empty(),
+                            onclick: handle_add,
+                            "Add"
+                        }
+                    }
+                }
+
+                if let Some(e) = error() {
+                    Alert { variant: AlertVariant::Danger, class: "mt-3 mb-0", "{e}" }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,243 @@
+use dioxus::prelude::*;
+use dioxus_bootstrap::*;
+use api::{apply_resume_merge, FullProfile, ProfileMerge};
+
+/// Show how an uploaded resume would change the existing profile
+///
+/// Duplicates of stored entries are merged into them rather than added
+/// again; each change can be accepted or left out before it is applied.
+#[component]
+pub fn MergeReview(
+    user_id: String,
+    merge: ProfileMerge,
+    on_saved: EventHandler<FullProfile>,
+    on_cancel: EventHandler<()>,
+) -> Element {
+    let mut draft = use_signal(|| merge.clone());
+    let mut saving = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+
+    let apply = move |_| {
+        let user_id = user_id.clone();
+        spawn(async move {
+            saving.set(true);
+            error.set(None);
+            match apply_resume_merge(user_id, draft()).await {
+                Ok(full) => on_saved.call(full),
+                Err(e) => error.set(Some(format!("Merging failed: {}", e))),
+            }
+            saving.set(false);
+        });
+    };
+
+    let merge = draft();
+
+    rsx! {
+        Container {
+            Row {
+                Col { md: 10, offset_md: 1,
+                    Card {
+                        CardHeader {
+                            h4 { class: "mb-0",
+                                i { class: "fas fa-code-merge me-2 text-primary" }
+                                "Merge Into Your Profile"
+                            }
+                        }
+                        CardBody {
+                            p { class: "text-muted",
+                                "You already have a profile. Entries that match ones you have are merged into them instead of being added twice."
+                            }
+                            if merge.unchanged > 0 {
+                                p { class: "text-muted small",
+                                    i { class: "fas fa-equals me-1" }
+                                    "{merge.unchanged} entries are already in your profile and are left as they are."
+                                }
+                            }
+                            if merge.is_empty() {
+                                Alert { variant: AlertVariant::Info, "This resume has nothing your profile doesn't already have." }
+                            }
+
+                            if !merge.fields.is_empty() {
+                                h5 { class: "mt-4 mb-3", "Contact Details" }
+                                for (index, change) in merge.fields.iter().cloned().enumerate() {
+                                    ChangeRow {
+                                        key: "{change.field}",
+                                        title: field_label(&change.field).to_string(),
+                                        subtitle: match &change.current {
+                                            Some(current) => format!("{} → {}", current, change.proposed),
+                                            None => change.proposed.clone(),
+                                        },
+                                        is_new: change.current.is_none(),
+                                        notes: Vec::new(),
+                                        accepted: change.accepted,
+                                        on_toggle: move |_| draft.with_mut(|m| m.fields[index].accepted ^= true),
+                                    }
+                                }
+                            }
+
+                            if !merge.experiences.is_empty() {
+                                h5 { class: "mt-4 mb-3", "Experience" }
+                                for (index, change) in merge.experiences.iter().cloned().enumerate() {
+                                    ChangeRow {
+                                        key: "{change.proposed.id}",
+                                        title: change.proposed.position.clone(),
+                                        subtitle: change.proposed.company.clone(),
+                                        is_new: change.is_new(),
+                                        notes: change.notes.clone(),
+                                        accepted: change.accepted,
+                                        on_toggle: move |_| draft.with_mut(|m| m.experiences[index].accepted ^= true),
+                                    }
+                                }
+                            }
+
+                            if !merge.education.is_empty() {
+                                h5 { class: "mt-4 mb-3", "Education" }
+                                for (index, change) in merge.education.iter().cloned().enumerate() {
+                                    ChangeRow {
+                                        key: "{change.proposed.id}",
+                                        title: change.proposed.institution.clone(),
+                                        subtitle: format!("{} {}", change.proposed.degree, change.proposed.field).trim().to_string(),
+                                        is_new: change.is_new(),
+                                        notes: change.notes.clone(),
+                                        accepted: change.accepted,
+                                        on_toggle: move |_| draft.with_mut(|m| m.education[index].accepted ^= true),
+                                    }
+                                }
+                            }
+
+                            if !merge.skills.is_empty() {
+                                h5 { class: "mt-4 mb-3", "Skills" }
+                                for (index, change) in merge.skills.iter().cloned().enumerate() {
+                                    ChangeRow {
+                                        key: "{change.proposed.id}",
+                                        title: change.proposed.name.clone(),
+                                        subtitle: format!("{:?}", change.proposed.proficiency),
+                                        is_new: change.is_new(),
+                                        notes: change.notes.clone(),
+                                        accepted: change.accepted,
+                                        on_toggle: move |_| draft.with_mut(|m| m.skills[index].accepted ^= true),
+                                    }
+                                }
+                            }
+
+                            if !merge.projects.is_empty() {
+                                h5 { class: "mt-4 mb-3", "Projects" }
+                                for (index, change) in merge.projects.iter().cloned().enumerate() {
+                                    ChangeRow {
+                                        key: "{change.proposed.id}",
+                                        title: change.proposed.name.clone(),
+                                        subtitle: change.proposed.technologies.join(", "),
+                                        is_new: change.is_new(),
+                                        notes: change.notes.clone(),
+                                        accepted: change.accepted,
+                                        on_toggle: move |_| draft.with_mut(|m| m.projects[index].accepted ^= true),
+                                    }
+                                }
+                            }
+
+                            if let Some(message) = error() {
+                                div { class: "mt-3",
+                                    Alert { variant: AlertVariant::Danger, "{message}" }
+                                }
+                            }
+                        }
+                        CardFooter {
+                            div { class: "d-flex justify-content-end gap-2",
+                                Button {
+                                    variant: ButtonVariant::Secondary,
+                                    outline: true,
+                                    disabled: saving(),
+                                    onclick: move |_| on_cancel.call(()),
+                                    "Discard"
+                                }
+                                Button {
+                                    variant: ButtonVariant::Primary,
+                                    disabled: saving(),
+                                    onclick: apply,
+                                    if saving() {
+                                        span { class: "spinner-border spinner-border-sm me-2" }
+                                    } else {
+                                        i { class: "fas fa-check me-2" }
+                                    }
+                                    "Apply Accepted Changes"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// One proposed change with an accept toggle
+#[component]
+fn ChangeRow(
+    title: String,
+    subtitle: String,
+    is_new: bool,
+    notes: Vec<String>,
+    accepted: bool,
+    on_toggle: EventHandler<()>,
+) -> Element {
+    let class = if accepted {
+        "border rounded p-3 mb-2"
+    } else {
+        "border rounded p-3 mb-2 bg-light opacity-75"
+    };
+
+    rsx! {
+        div { class,
+            div { class: "d-flex justify-content-between align-items-start gap-3",
+                div { class: "flex-grow-1",
+                    div { class: "d-flex align-items-center gap-2 mb-1",
+                        strong { "{title}" }
+                        if is_new {
+                            Badge { variant: BadgeVariant::Success, "New" }
+                        } else {
+                            Badge { variant: BadgeVariant::Info, "Merge" }
+                        }
+                    }
+                    if !subtitle.is_empty() {
+                        div { class: "text-muted small", "{subtitle}" }
+                    }
+                    if !notes.is_empty() {
+                        ul { class: "small mb-0 mt-2",
+                            for note in notes {
+                                li { "{note}" }
+                            }
+                        }
+                    }
+                }
+                Button {
+                    variant: if accepted { ButtonVariant::Success } else { ButtonVariant::Secondary },
+                    outline: !accepted,
+                    size: Size::Small,
+                    onclick: move |_| on_toggle.call(()),
+                    if accepted {
+                        i { class: "fas fa-check me-1" }
+                        "Accepted"
+                    } else {
+                        i { class: "fas fa-times me-1" }
+                        "Skipped"
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn field_label(field: &str) -> &str {
+    match field {
+        "name" => "Full Name",
+        "email" => "Email Address",
+        "headline" => "Headline",
+        "summary" => "Summary",
+        "phone" => "Phone Number",
+        "location" => "Location",
+        "linkedin_url" => "LinkedIn",
+        "github_url" => "GitHub",
+        "portfolio_url" => "Portfolio",
+        _ => field,
+    }
+}
@@ -1,10 +1,14 @@
 pub mod dashboard;
 pub mod upload;
+pub mod resume_review;
+pub mod merge_review;
 pub mod navbar;
 pub mod app;
 
 // Re-export components for easy importing
 pub use dashboard::Dashboard;
 pub use upload::ResumeUpload;
+pub use resume_review::ResumeReview;
+pub use merge_review::MergeReview;
 pub use navbar::Navbar;
 pub use app::{App, SimpleApp};
\ No newline at end of file
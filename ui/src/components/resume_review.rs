@@ -0,0 +1,392 @@
+use std::collections::HashSet;
+
+use dioxus::prelude::*;
+use dioxus_bootstrap::*;
+use api::{preview_resume_merge, save_reviewed_resume, Experience, FieldConfidence, FullProfile, ParsedResume, ProfileMerge};
+use uuid::Uuid;
+
+/// Longest piece of source text shown under an entry
+const SNIPPET_LENGTH: usize = 160;
+
+/// Review what was extracted from a resume before it is saved
+///
+/// Every experience, education entry and skill can be edited, accepted or
+/// rejected. Entries the parser was unsure about start out rejected. When
+/// the user already has a profile, saving shows how the accepted entries
+/// would be merged into it.
+#[component]
+pub fn ResumeReview(
+    user_id: String,
+    parsed: ParsedResume,
+    on_saved: EventHandler<FullProfile>,
+    on_cancel: EventHandler<()>,
+) -> Element {
+    let low_confidence: HashSet<Uuid> = parsed
+        .confidence
+        .entries
+        .iter()
+        .filter(|(_, c)| c.is_low())
+        .map(|(id, _)| *id)
+        .collect();
+    let mut draft = use_signal(|| parsed.clone());
+    let rejected = use_signal(|| low_confidence);
+    let mut saving = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+    let mut merge = use_signal(|| None::<ProfileMerge>);
+
+    let merge_user_id = user_id.clone();
+    let save = move |_| {
+        let user_id = user_id.clone();
+        spawn(async move {
+            saving.set(true);
+            error.set(None);
+
+            let rejected = rejected();
+            let mut resume = draft();
+            resume.experiences.retain(|e| !rejected.contains(&e.id));
+            resume.education.retain(|e| !rejected.contains(&e.id));
+            resume.skills.retain(|s| !rejected.contains(&s.id));
+            resume.projects.retain(|p| !rejected.contains(&p.id));
+
+            match preview_resume_merge(user_id.clone(), resume.clone()).await {
+                Ok(Some(proposed)) => merge.set(Some(proposed)),
+                Ok(None) => match save_reviewed_resume(user_id, resume).await {
+                    Ok(full) => on_saved.call(full),
+                    Err(e) => error.set(Some(format!("Saving failed: {}", e))),
+                },
+                Err(e) => error.set(Some(format!("Saving failed: {}", e))),
+            }
+            saving.set(false);
+        });
+    };
+
+    if let Some(proposed) = merge() {
+        return rsx! {
+            crate::MergeReview {
+                user_id: merge_user_id,
+                merge: proposed,
+                on_saved: move |full| on_saved.call(full),
+                on_cancel: move |_| merge.set(None),
+            }
+        };
+    }
+
+    let resume = draft();
+    let confidence = resume.confidence.clone();
+    let total = resume.experiences.len() + resume.education.len() + resume.skills.len() + resume.projects.len();
+    let accepted = total.saturating_sub(rejected().len());
+
+    rsx! {
+        Container {
+            Row {
+                Col { md: 10, offset_md: 1,
+                    Card {
+                        CardHeader {
+                            div { class: "d-flex justify-content-between align-items-center",
+                                h4 { class: "mb-0",
+                                    i { class: "fas fa-clipboard-check me-2 text-primary" }
+                                    "Review Your Resume"
+                                }
+                                span { class: "text-muted small", "{accepted} of {total} entries accepted" }
+                            }
+                        }
+                        CardBody {
+                            p { class: "text-muted",
+                                "Check what we found before it is added to your profile. Entries we were unsure about are rejected until you accept them."
+                            }
+
+                            h5 { class: "mt-4 mb-3", "Contact Details" }
+                            Row {
+                                ProfileField {
+                                    title: "Full Name",
+                                    value: resume.profile.name.clone(),
+                                    confidence: confidence.profile_field("name").cloned(),
+                                    on_change: move |value| draft.with_mut(|d| d.profile.name = value),
+                                }
+                                ProfileField {
+                                    title: "Email Address",
+                                    value: resume.profile.email.clone(),
+                                    confidence: confidence.profile_field("email").cloned(),
+                                    on_change: move |value| draft.with_mut(|d| d.profile.email = value),
+                                }
+                                ProfileField {
+                                    title: "Phone Number",
+                                    value: resume.profile.phone.clone().unwrap_or_default(),
+                                    confidence: confidence.profile_field("phone").cloned(),
+                                    on_change: move |value| draft.with_mut(|d| d.profile.phone = optional(value)),
+                                }
+                                ProfileField {
+                                    title: "Location",
+                                    value: resume.profile.location.clone().unwrap_or_default(),
+                                    confidence: confidence.profile_field("location").cloned(),
+                                    on_change: move |value| draft.with_mut(|d| d.profile.location = optional(value)),
+                                }
+                            }
+
+                            if !resume.experiences.is_empty() {
+                                h5 { class: "mt-4 mb-3", "Experience" }
+                                for (index, experience) in resume.experiences.iter().cloned().enumerate() {
+                                    ReviewItem {
+                                        key: "{experience.id}",
+                                        accepted: !rejected().contains(&experience.id),
+                                        confidence: confidence.entry(&experience.id).cloned(),
+                                        on_toggle: move |_| toggle(rejected, experience.id),
+                                        Row {
+                                            Col { md: 6,
+                                                Input {
+                                                    input_type: InputType::Text,
+                                                    class: "form-control form-control-sm mb-2",
+                                                    placeholder: "Position",
+                                                    value: experience.position.clone(),
+                                                    oninput: move |evt: Event<FormData>| {
+                                                        draft.with_mut(|d| d.experiences[index].position = evt.value())
+                                                    }
+                                                }
+                                            }
+                                            Col { md: 6,
+                                                Input {
+                                                    input_type: InputType::Text,
+                                                    class: "form-control form-control-sm mb-2",
+                                                    placeholder: "Company",
+                                                    value: experience.company.clone(),
+                                                    oninput: move |evt: Event<FormData>| {
+                                                        draft.with_mut(|d| d.experiences[index].company = evt.value())
+                                                    }
+                                                }
+                                            }
+                                        }
+                                        small { class: "text-muted", {summary(&experience)} }
+                                    }
+                                }
+                            }
+
+                            if !resume.education.is_empty() {
+                                h5 { class: "mt-4 mb-3", "Education" }
+                                for (index, education) in resume.education.iter().cloned().enumerate() {
+                                    ReviewItem {
+                                        key: "{education.id}",
+                                        accepted: !rejected().contains(&education.id),
+                                        confidence: confidence.entry(&education.id).cloned(),
+                                        on_toggle: move |_| toggle(rejected, education.id),
+                                        Row {
+                                            Col { md: 4,
+                                                Input {
+                                                    input_type: InputType::Text,
+                                                    class: "form-control form-control-sm",
+                                                    placeholder: "Institution",
+                                                    value: education.institution.clone(),
+                                                    oninput: move |evt: Event<FormData>| {
+                                                        draft.with_mut(|d| d.education[index].institution = evt.value())
+                                                    }
+                                                }
+                                            }
+                                            Col { md: 4,
+                                                Input {
+                                                    input_type: InputType::Text,
+                                                    class: "form-control form-control-sm",
+                                                    placeholder: "Degree",
+                                                    value: education.degree.clone(),
+                                                    oninput: move |evt: Event<FormData>| {
+                                                        draft.with_mut(|d| d.education[index].degree = evt.value())
+                                                    }
+                                                }
+                                            }
+                                            Col { md: 4,
+                                                Input {
+                                                    input_type: InputType::Text,
+                                                    class: "form-control form-control-sm",
+                                                    placeholder: "Field of Study",
+                                                    value: education.field.clone(),
+                                                    oninput: move |evt: Event<FormData>| {
+                                                        draft.with_mut(|d| d.education[index].field = evt.value())
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+
+                            if !resume.skills.is_empty() {
+                                h5 { class: "mt-4 mb-3", "Skills" }
+                                Row {
+                                    for (index, skill) in resume.skills.iter().cloned().enumerate() {
+                                        Col { key: "{skill.id}", md: 6,
+                                            ReviewItem {
+                                                accepted: !rejected().contains(&skill.id),
+                                                confidence: confidence.entry(&skill.id).cloned(),
+                                                on_toggle: move |_| toggle(rejected, skill.id),
+                                                Input {
+                                                    input_type: InputType::Text,
+                                                    class: "form-control form-control-sm",
+                                                    value: skill.name.clone(),
+                                                    oninput: move |evt: Event<FormData>| {
+                                                        draft.with_mut(|d| d.skills[index].name = evt.value())
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+
+                            if let Some(message) = error() {
+                                div { class: "mt-3",
+                                    Alert { variant: AlertVariant::Danger, "{message}" }
+                                }
+                            }
+                        }
+                        CardFooter {
+                            div { class: "d-flex justify-content-end gap-2",
+                                Button {
+                                    variant: ButtonVariant::Secondary,
+                                    outline: true,
+                                    disabled: saving(),
+                                    onclick: move |_| on_cancel.call(()),
+                                    "Discard"
+                                }
+                                Button {
+                                    variant: ButtonVariant::Primary,
+                                    disabled: saving() || resume.profile.name.trim().is_empty() || resume.profile.email.trim().is_empty(),
+                                    onclick: save,
+                                    if saving() {
+                                        span { class: "spinner-border spinner-border-sm me-2" }
+                                    } else {
+                                        i { class: "fas fa-save me-2" }
+                                    }
+                                    "Save to Profile"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// An editable profile field with how sure the parser was of it
+#[component]
+fn ProfileField(
+    title: &'static str,
+    value: String,
+    confidence: Option<FieldConfidence>,
+    on_change: EventHandler<String>,
+) -> Element {
+    rsx! {
+        Col { md: 6, class: "mb-3",
+            div { class: "d-flex justify-content-between align-items-center mb-1",
+                label { class: "form-label fw-semibold mb-0", "{title}" }
+                ConfidenceBadge { confidence }
+            }
+            Input {
+                input_type: InputType::Text,
+                class: "form-control",
+                value,
+                oninput: move |evt: Event<FormData>| on_change.call(evt.value())
+            }
+        }
+    }
+}
+
+/// One extracted entry with its confidence, source text and accept toggle
+#[component]
+fn ReviewItem(
+    accepted: bool,
+    confidence: Option<FieldConfidence>,
+    on_toggle: EventHandler<()>,
+    children: Element,
+) -> Element {
+    let class = if accepted {
+        "border rounded p-3 mb-2"
+    } else {
+        "border rounded p-3 mb-2 bg-light opacity-75"
+    };
+    let source = confidence.as_ref().and_then(|c| c.source.as_ref()).map(|s| snippet(&s.text));
+
+    rsx! {
+        div { class,
+            div { class: "d-flex justify-content-between align-items-start gap-3",
+                div { class: "flex-grow-1", {children} }
+                div { class: "d-flex align-items-center gap-2",
+                    ConfidenceBadge { confidence }
+                    Button {
+                        variant: if accepted { ButtonVariant::Success } else { ButtonVariant::Secondary },
+                        outline: !accepted,
+                        size: Size::Small,
+                        onclick: move |_| on_toggle.call(()),
+                        if accepted {
+                            i { class: "fas fa-check me-1" }
+                            "Accepted"
+                        } else {
+                            i { class: "fas fa-times me-1" }
+                            "Rejected"
+                        }
+                    }
+                }
+            }
+            if let Some(source) = source {
+                div { class: "mt-2 small text-muted fst-italic",
+                    i { class: "fas fa-quote-left me-1" }
+                    "{source}"
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn ConfidenceBadge(confidence: Option<FieldConfidence>) -> Element {
+    let Some(confidence) = confidence else {
+        return rsx! {
+            Badge { variant: BadgeVariant::Secondary, "Not found" }
+        };
+    };
+    let percent = (confidence.confidence * 100.0).round() as u32;
+    let variant = if confidence.confidence >= 0.8 {
+        BadgeVariant::Success
+    } else if confidence.is_low() {
+        BadgeVariant::Danger
+    } else {
+        BadgeVariant::Warning
+    };
+
+    rsx! {
+        Badge { variant, "{percent}% sure" }
+    }
+}
+
+/// "Mar 2021 - Present · 2 achievements"
+fn summary(experience: &Experience) -> String {
+    let end = match experience.end_date {
+        _ if experience.current => "Present".to_string(),
+        Some(end) => end.format("%b %Y").to_string(),
+        None => String::new(),
+    };
+    let mut summary = format!("{} - {}", experience.start_date.format("%b %Y"), end);
+    if !experience.achievements.is_empty() {
+        summary.push_str(&format!(" · {} achievements", experience.achievements.len()));
+    }
+    summary
+}
+
+fn toggle(mut rejected: Signal<HashSet<Uuid>>, id: Uuid) {
+    rejected.with_mut(|r| {
+        if !r.remove(&id) {
+            r.insert(id);
+        }
+    });
+}
+
+fn optional(value: String) -> Option<String> {
+    Some(value).filter(|v| !v.trim().is_empty())
+}
+
+/// Shorten source text to a line or two, collapsing whitespace
+fn snippet(text: &str) -> String {
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    match text.char_indices().nth(SNIPPET_LENGTH) {
+        Some((end, _)) => format!("{}…", &text[..end]),
+        None => text,
+    }
+}
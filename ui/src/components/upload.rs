@@ -14,8 +14,10 @@ pub fn ResumeUpload(on_upload: EventHandler<ParsedResume>) -> Element {
                     upload_status.set(UploadStatus::Uploading);
                     
                     let file_name = file.clone();
-                    // Convert file to bytes - simplified for now
-                    let file_data = vec![]; // TODO: implement file reading
+                    let Some(file_data) = file_engine.read_file(&file_name).await else {
+                        upload_status.set(UploadStatus::Error("Could not read the file".to_string()));
+                        return;
+                    };
                     
                     match upload_resume(file_data, file_name, "dummy_user_id".to_string()).await {
                         Ok(parsed_resume) => {
@@ -39,7 +41,7 @@ pub fn ResumeUpload(on_upload: EventHandler<ParsedResume>) -> Element {
                         CardBody {
                             h3 { class: "mb-4", "Upload Your Resume" }
                             p { class: "text-muted mb-4", 
-                                "Upload your resume in PDF or DOCX format. Our system will automatically extract and organize your information."
+                                "Upload your resume as PDF, Word, plain text, Markdown, HTML or JSON Resume. Our system will automatically extract and organize your information."
                             }
                             
                             match upload_status() {
@@ -58,7 +60,7 @@ pub fn ResumeUpload(on_upload: EventHandler<ParsedResume>) -> Element {
                                                 i { class: "fas fa-cloud-upload-alt fa-3x text-primary" }
                                             }
                                             h5 { "Click to upload or drag and drop" }
-                                            p { class: "text-muted", "Supported formats: PDF, DOCX" }
+                                            p { class: "text-muted", "Supported formats: PDF, DOCX, TXT, MD, HTML, JSON Resume" }
                                         }
                                     }
                                 },
@@ -75,7 +77,7 @@ pub fn ResumeUpload(on_upload: EventHandler<ParsedResume>) -> Element {
                                             i { class: "fas fa-check-circle me-2" }
                                             div {
                                                 strong { "Success! " }
-                                                "Your resume has been processed and your profile has been updated."
+                                                "Your resume has been processed. Review what we found below."
                                             }
                                         }
                                     }
@@ -0,0 +1,128 @@
+use dioxus::prelude::*;
+use dioxus_bootstrap::*;
+use api::{FetchRun, FetchSchedule, get_fetch_schedule};
+use chrono::{DateTime, Utc};
+
+/// Background job fetching: when each source runs next and how recent runs went
+#[component]
+pub fn FetchSchedulePanel() -> Element {
+    let mut schedule = use_signal(|| None::<FetchSchedule>);
+    let mut error = use_signal(|| None::<String>);
+    let mut refresh = use_signal(|| 0u32);
+
+    use_effect(move || {
+        refresh();
+        spawn(async move {
+            match get_fetch_schedule().await {
+                Ok(loaded) => {
+                    schedule.set(Some(loaded));
+                    error.set(None);
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    });
+
+    rsx! {
+        Card { class: "border-0 shadow-sm mb-4",
+            CardBody {
+                div { class: "d-flex justify-content-between align-items-center mb-3",
+                    h5 { class: "mb-0",
+                        i { class: "fas fa-clock me-2 text-primary" }
+                        "Background Job Fetching"
+                    }
+                    Button {
+                        variant: ButtonVariant::Secondary,
+                        size: Size::Small,
+                        onclick: move |_| refresh += 1,
+                        i { class: "fas fa-sync me-2" }
+                        "Refresh"
+                    }
+                }
+
+                if let Some(e) = error() {
+                    Alert { variant: AlertVariant::Danger, "Failed to load the fetch schedule: {e}" }
+                }
+
+                if let Some(loaded) = schedule() {
+                    table { class: "table table-sm align-middle",
+                        thead {
+                            tr {
+                                th { "Source" }
+                                th { "Every" }
+                                th { "Last run" }
+                                th { "Next run" }
+                            }
+                        }
+                        tbody {
+                            for source in loaded.sources.iter() {
+                                tr { key: "{source.source}",
+                                    td { "{source.source}" }
+                                    td { {format_interval(source.interval_secs)} }
+                                    td {
+                                        if let Some(run) = &source.last_run {
+                                            RunStatus { run: run.clone() }
+                                        } else {
+                                            span { class: "text-muted", "Never" }
+                                        }
+                                    }
+                                    td { {format_time(source.next_run_at)} }
+                                }
+                            }
+                        }
+                    }
+
+                    h6 { class: "mt-4", "Recent runs" }
+                    if loaded.recent_runs.is_empty() {
+                        p { class: "text-muted small mb-0", "No jobs have been fetched in the background yet." }
+                    } else {
+                        ul { class: "list-unstyled small mb-0",
+                            for run in loaded.recent_runs.iter() {
+                                li { key: "{run.id}",
+                                    {format!("{} · {} · ", format_time(run.started_at), run.source)}
+                                    RunStatus { run: run.clone() }
+                                }
+                            }
+                        }
+                    }
+                } else if error().is_none() {
+                    div { class: "text-center text-muted py-3",
+                        span { class: "spinner-border spinner-border-sm me-2" }
+                        "Loading schedule..."
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Outcome of a run: still running, failed, or how many jobs it saved
+#[component]
+fn RunStatus(run: FetchRun) -> Element {
+    rsx! {
+        if run.finished_at.is_none() {
+            Badge { variant: BadgeVariant::Info, "Running" }
+        } else if let Some(error) = &run.error {
+            span { title: "{error}",
+                Badge { variant: BadgeVariant::Danger, "Failed" }
+            }
+        } else {
+            span {
+                i { class: "fas fa-check text-success me-1" }
+                {format!("{} fetched, {} saved", run.fetched, run.saved)}
+            }
+        }
+    }
+}
+
+fn format_time(time: DateTime<Utc>) -> String {
+    time.format("%b %-d, %H:%M UTC").to_string()
+}
+
+fn format_interval(secs: u64) -> String {
+    match secs {
+        s if s >= 3600 && s % 3600 == 0 => format!("{}h", s / 3600),
+        s if s >= 60 => format!("{}min", s / 60),
+        s => format!("{}s", s),
+    }
+}
@@ -1,19 +1,40 @@
 use dioxus::prelude::*;
 use dioxus_bootstrap::*;
-use api::{Job, JobSource, search_jobs, generate_cover_letter, apply_to_job};
+use api::{Job, JobListing, JobMatch, get_job_match, JobSource, JobSortOrder, JobSearchResult, HighlightSpan, FetchJobsResult, search_jobs, fetch_external_jobs, save_job, unsave_job, save_search, generate_cover_letter, apply_to_job, DEFAULT_USER_ID};
 use api::job_service::CoverLetterTone;
 use crate::Label;
 
+/// Sources offered as search filters
+const SEARCH_SOURCES: [JobSource; 11] = [
+    JobSource::Remotive,
+    JobSource::HNWhoIsHiring,
+    JobSource::Arbeitnow,
+    JobSource::Greenhouse,
+    JobSource::Lever,
+    JobSource::Ashby,
+    JobSource::Workable,
+    JobSource::SmartRecruiters,
+    JobSource::LinkedIn,
+    JobSource::Indeed,
+    JobSource::Glassdoor,
+];
+
 #[component]
 pub fn JobSearch() -> Element {
     let mut search_query = use_signal(|| "".to_string());
     let mut location = use_signal(|| "".to_string());
     let mut min_salary = use_signal(|| None::<u32>);
-    let mut selected_sources = use_signal(|| vec![JobSource::LinkedIn, JobSource::Indeed]);
-    let mut jobs = use_signal(|| Vec::<Job>::new());
+    let mut selected_sources = use_signal(Vec::<JobSource>::new);
+    let mut remote_only = use_signal(|| false);
+    let mut sort_order = use_signal(JobSortOrder::default);
+    let mut jobs = use_signal(|| Vec::<JobSearchResult>::new());
     let mut is_searching = use_signal(|| false);
     let mut selected_job = use_signal(|| None::<Job>);
     let mut show_application_modal = use_signal(|| false);
+    let mut is_fetching = use_signal(|| false);
+    let mut fetch_result = use_signal(|| None::<FetchJobsResult>);
+    let mut search_name = use_signal(String::new);
+    let mut save_message = use_signal(|| None::<String>);
 
     let handle_search = move |_| {
         spawn(async move {
@@ -22,7 +43,9 @@ pub fn JobSearch() -> Element {
                 search_query(),
                 location(),
                 min_salary(),
-                selected_sources()
+                selected_sources(),
+                remote_only(),
+                sort_order()
             ).await {
                 Ok(results) => {
                     jobs.set(results);
@@ -35,6 +58,39 @@ pub fn JobSearch() -> Element {
         });
     };
 
+    let handle_save_search = move |_| {
+        spawn(async move {
+            match save_search(
+                DEFAULT_USER_ID.to_string(),
+                search_name(),
+                search_query(),
+                location(),
+                min_salary(),
+                selected_sources(),
+                remote_only(),
+            ).await {
+                Ok(saved) => {
+                    save_message.set(Some(format!("Saved '{}'; new matches will show on the dashboard", saved.name)));
+                    search_name.set(String::new());
+                }
+                Err(e) => save_message.set(Some(format!("Could not save search: {}", e))),
+            }
+        });
+    };
+
+    let handle_fetch = move |_| {
+        spawn(async move {
+            is_fetching.set(true);
+            let keywords = Some(search_query()).filter(|k| !k.trim().is_empty());
+            let place = Some(location()).filter(|l| !l.trim().is_empty());
+            match fetch_external_jobs(keywords, place, None).await {
+                Ok(result) => fetch_result.set(Some(result)),
+                Err(e) => tracing::error!("Fetching jobs failed: {}", e),
+            }
+            is_fetching.set(false);
+        });
+    };
+
     rsx! {
         Container {
             Row {
@@ -51,7 +107,7 @@ pub fn JobSearch() -> Element {
                                         input_type: InputType::Text,
                                         id: "search-query",
                                         class: "form-control",
-                                        placeholder: "e.g. Software Engineer, Data Scientist",
+                                        placeholder: "e.g. rust AND (tokio OR async) -\"crypto\"",
                                         value: search_query(),
                                         oninput: move |evt: Event<FormData>| search_query.set(evt.value())
                                     }
@@ -85,54 +141,57 @@ pub fn JobSearch() -> Element {
                                 }
                                 
                                 div { class: "mb-3",
-                                    Label { class: "form-label", "Job Sources" }
                                     Checkbox {
-                                        id: "linkedin",
-                                        label: Some("LinkedIn".to_string()),
-                                        checked: selected_sources().contains(&JobSource::LinkedIn),
-                                        onchange: move |evt: Event<FormData>| {
-                                            let mut sources = selected_sources();
-                                            if evt.checked() {
-                                                if !sources.contains(&JobSource::LinkedIn) {
-                                                    sources.push(JobSource::LinkedIn);
-                                                }
-                                            } else {
-                                                sources.retain(|s| s != &JobSource::LinkedIn);
-                                            }
-                                            selected_sources.set(sources);
-                                        }
+                                        id: "remote-only",
+                                        label: Some("Remote only".to_string()),
+                                        checked: remote_only(),
+                                        onchange: move |evt: Event<FormData>| remote_only.set(evt.checked())
                                     }
-                                    Checkbox {
-                                        id: "indeed",
-                                        label: Some("Indeed".to_string()),
-                                        checked: selected_sources().contains(&JobSource::Indeed),
-                                        onchange: move |evt: Event<FormData>| {
-                                            let mut sources = selected_sources();
-                                            if evt.checked() {
-                                                if !sources.contains(&JobSource::Indeed) {
-                                                    sources.push(JobSource::Indeed);
+                                }
+                                
+                                div { class: "mb-3",
+                                    Label { class: "form-label", "Job Sources" }
+                                    small { class: "form-text d-block mb-1", "Leave all unchecked to search every source" }
+                                    for source in SEARCH_SOURCES.iter().cloned() {
+                                        Checkbox {
+                                            id: format!("source-{}", source.as_str()),
+                                            label: Some(source.display_name().to_string()),
+                                            checked: selected_sources().contains(&source),
+                                            onchange: move |evt: Event<FormData>| {
+                                                let mut sources = selected_sources();
+                                                if evt.checked() {
+                                                    if !sources.contains(&source) {
+                                                        sources.push(source.clone());
+                                                    }
+                                                } else {
+                                                    sources.retain(|s| s != &source);
                                                 }
-                                            } else {
-                                                sources.retain(|s| s != &JobSource::Indeed);
+                                                selected_sources.set(sources);
                                             }
-                                            selected_sources.set(sources);
                                         }
                                     }
-                                    Checkbox {
-                                        id: "glassdoor",
-                                        label: Some("Glassdoor".to_string()),
-                                        checked: selected_sources().contains(&JobSource::Glassdoor),
+                                }
+                                
+                                div { class: "mb-3",
+                                    Label { r#for: "sort-order", class: "form-label", "Sort By" }
+                                    Select {
+                                        id: "sort-order",
+                                        class: "form-select",
+                                        value: format!("{:?}", sort_order()),
                                         onchange: move |evt: Event<FormData>| {
-                                            let mut sources = selected_sources();
-                                            if evt.checked() {
-                                                if !sources.contains(&JobSource::Glassdoor) {
-                                                    sources.push(JobSource::Glassdoor);
-                                                }
-                                            } else {
-                                                sources.retain(|s| s != &JobSource::Glassdoor);
-                                            }
-                                            selected_sources.set(sources);
-                                        }
+                                            sort_order.set(match evt.value().as_str() {
+                                                "Newest" => JobSortOrder::Newest,
+                                                "Oldest" => JobSortOrder::Oldest,
+                                                "HighestSalary" => JobSortOrder::HighestSalary,
+                                                "BestMatch" => JobSortOrder::BestMatch,
+                                                _ => JobSortOrder::Relevance,
+                                            });
+                                        },
+                                        option { value: "Relevance", "Relevance" }
+                                        option { value: "Newest", "Newest" }
+                                        option { value: "Oldest", "Oldest" }
+                                        option { value: "HighestSalary", "Highest salary" }
+                                        option { value: "BestMatch", "Best match" }
                                     }
                                 }
                                 
@@ -152,6 +211,61 @@ pub fn JobSearch() -> Element {
                                             }
                                         }
                                     }
+                                    Button {
+                                        variant: ButtonVariant::Secondary,
+                                        class: "mt-2",
+                                        disabled: is_fetching(),
+                                        onclick: handle_fetch,
+                                        if is_fetching() {
+                                            span { class: "spinner-border spinner-border-sm me-2" }
+                                            "Fetching..."
+                                        } else {
+                                            span {
+                                                i { class: "fas fa-sync me-2" }
+                                                "Fetch New Jobs"
+                                            }
+                                        }
+                                    }
+                                }
+                                
+                                div { class: "input-group mt-3",
+                                    Input {
+                                        input_type: InputType::Text,
+                                        id: "search-name",
+                                        class: "form-control",
+                                        placeholder: "Name, e.g. Rust backend, Remote EU",
+                                        value: search_name(),
+                                        oninput: move |evt: Event<FormData>| search_name.set(evt.value())
+                                    }
+                                    Button {
+                                        variant: ButtonVariant::Secondary,
+                                        disabled: search_name().trim().is_empty(),
+                                        onclick: handle_save_search,
+                                        i { class: "fas fa-bell me-2" }
+                                        "Save Search"
+                                    }
+                                }
+                                if let Some(message) = save_message() {
+                                    p { class: "small text-muted mt-1 mb-0", "{message}" }
+                                }
+                                
+                                if let Some(result) = fetch_result() {
+                                    div { class: "mt-3 small",
+                                        p { class: "mb-1", {format!("Saved {} of {} fetched jobs", result.saved, result.fetched)} }
+                                        ul { class: "list-unstyled mb-0",
+                                            for report in result.sources.iter() {
+                                                li {
+                                                    if let Some(error) = &report.error {
+                                                        i { class: "fas fa-times text-danger me-2" }
+                                                        {format!("{}: {}", report.source, error)}
+                                                    } else {
+                                                        i { class: "fas fa-check text-success me-2" }
+                                                        {format!("{}: {} jobs in {:.1}s", report.source, report.jobs, report.duration_ms as f64 / 1000.0)}
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
                                 }
                             }
                         }
@@ -180,9 +294,11 @@ pub fn JobSearch() -> Element {
                         }
                     } else {
                         div { class: "job-results",
-                            for job in jobs() {
+                            for result in jobs() {
                                 JobCard { 
-                                    job: job.clone(),
+                                    key: "{result.listing.job.id}",
+                                    listing: result.listing.clone(),
+                                    snippet: result.snippet.clone(),
                                     on_apply: move |j| {
                                         selected_job.set(Some(j));
                                         show_application_modal.set(true);
@@ -208,8 +324,53 @@ pub fn JobSearch() -> Element {
 }
 
 #[component]
-fn JobCard(job: Job, on_apply: EventHandler<Job>) -> Element {
+fn JobCard(listing: JobListing, snippet: Vec<HighlightSpan>, on_apply: EventHandler<Job>) -> Element {
+    let job = listing.job.clone();
     let job_clone = job.clone();
+    let job_id = job.id;
+    let mut is_saved = use_signal(|| listing.is_saved);
+
+    let toggle_saved = move |_| {
+        let job_id = job_id.to_string();
+        spawn(async move {
+            let saved = is_saved();
+            let result = if saved {
+                unsave_job(job_id).await
+            } else {
+                save_job(job_id).await
+            };
+            match result {
+                Ok(()) => is_saved.set(!saved),
+                Err(e) => tracing::error!("Failed to update saved job: {}", e),
+            }
+        });
+    };
+
+    // Why the job scored as it did, fetched the first time it is asked for;
+    // `Some(None)` when the user has no profile to match against
+    let mut job_match = use_signal(|| None::<Option<JobMatch>>);
+    let mut show_match = use_signal(|| false);
+    let toggle_match = move |_| {
+        show_match.set(!show_match());
+        if job_match().is_some() {
+            return;
+        }
+        spawn(async move {
+            match get_job_match(DEFAULT_USER_ID.to_string(), job_id.to_string()).await {
+                Ok(details) => job_match.set(Some(details)),
+                Err(e) => {
+                    tracing::error!("Failed to explain job match: {}", e);
+                    show_match.set(false);
+                }
+            }
+        });
+    };
+
+    let match_variant = match listing.match_score {
+        Some(score) if score >= 75.0 => BadgeVariant::Success,
+        Some(score) if score >= 50.0 => BadgeVariant::Warning,
+        _ => BadgeVariant::Secondary,
+    };
     let _source_color = match job.source {
         JobSource::LinkedIn => "primary",
         JobSource::Indeed => "success", 
@@ -218,19 +379,14 @@ fn JobCard(job: Job, on_apply: EventHandler<Job>) -> Element {
         JobSource::Remotive => "danger",
         JobSource::HNWhoIsHiring => "dark",
         JobSource::Arbeitnow => "info",
+        JobSource::Greenhouse => "success",
+        JobSource::Lever => "primary",
+        JobSource::Ashby => "warning",
+        JobSource::Workable => "info",
+        JobSource::SmartRecruiters => "dark",
         JobSource::Other(_) => "secondary",
     };
 
-    let source_name = match &job.source {
-        JobSource::LinkedIn => "LinkedIn",
-        JobSource::Indeed => "Indeed",
-        JobSource::Glassdoor => "Glassdoor", 
-        JobSource::AngelList => "AngelList",
-        JobSource::Remotive => "Remotive",
-        JobSource::HNWhoIsHiring => "HN Who's Hiring",
-        JobSource::Arbeitnow => "Arbeitnow",
-        JobSource::Other(name) => name,
-    };
 
     rsx! {
         Card { class: "mb-3 hover-shadow",
@@ -249,23 +405,100 @@ fn JobCard(job: Job, on_apply: EventHandler<Job>) -> Element {
                         p { class: "text-muted mb-2",
                             i { class: "fas fa-map-marker-alt me-1" }
                             {job.location.clone()}
+                            if listing.is_remote {
+                                Badge { variant: BadgeVariant::Info, class: "ms-2", "Remote" }
+                            }
+                            if let Some(department) = &job.department {
+                                span { class: "ms-3",
+                                    i { class: "fas fa-sitemap me-1" }
+                                    {department.clone()}
+                                }
+                            }
                         }
                     }
                     div { class: "text-end",
+                        if let Some(score) = listing.match_score {
+                            a {
+                                role: "button",
+                                title: "Why this score?",
+                                onclick: toggle_match,
+                                Badge { 
+                                    variant: match_variant,
+                                    class: "mb-2 me-1",
+                                    {format!("{:.0}% match", score)}
+                                    i { class: "fas fa-info-circle ms-1" }
+                                }
+                            }
+                        }
                         Badge { 
                             variant: BadgeVariant::Secondary,
                             class: "mb-2",
-                            {source_name}
+                            {job.source.display_name().to_string()}
                         }
                         if let Some(salary) = &job.salary_range {
                             div { class: "text-success fw-bold",
                                 "${salary.min} - ${salary.max}"
                             }
                         }
+                        if !listing.other_sources.is_empty() {
+                            small { class: "d-block text-muted",
+                                "Also on "
+                                for (i, link) in listing.other_sources.iter().enumerate() {
+                                    if i > 0 { ", " }
+                                    a {
+                                        href: link.url.clone(),
+                                        target: "_blank",
+                                        {link.source.display_name().to_string()}
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                
+                if show_match() {
+                    div { class: "alert alert-light border mb-3",
+                        if job_match().is_none() {
+                            small { class: "text-muted", "Loading..." }
+                        } else if let Some(Some(details)) = job_match() {
+                            ul { class: "mb-2",
+                                for line in details.explanation.iter() {
+                                    li { {line.clone()} }
+                                }
+                            }
+                            div { class: "d-flex flex-wrap gap-1",
+                                for skill in details.matched_skills.iter() {
+                                    Badge { variant: BadgeVariant::Success,
+                                        i { class: "fas fa-check me-1" }
+                                        {skill.clone()}
+                                    }
+                                }
+                                for skill in details.missing_skills.iter() {
+                                    Badge { variant: BadgeVariant::Light, class: "text-muted border",
+                                        i { class: "fas fa-times me-1" }
+                                        {skill.clone()}
+                                    }
+                                }
+                            }
+                        } else {
+                            small { class: "text-muted", "Complete your profile to see how this job fits you" }
+                        }
                     }
                 }
                 
-                p { class: "mb-3", {job.description.clone()} }
+                if snippet.is_empty() {
+                    p { class: "mb-3", {job.description.clone()} }
+                } else {
+                    p { class: "mb-3",
+                        for part in snippet.iter() {
+                            if part.highlighted {
+                                mark { {part.text.clone()} }
+                            } else {
+                                span { {part.text.clone()} }
+                            }
+                        }
+                    }
+                }
                 
                 if !job.requirements.is_empty() {
                     div { class: "mb-3",
@@ -291,6 +524,19 @@ fn JobCard(job: Job, on_apply: EventHandler<Job>) -> Element {
 {"Posted "}{job.posted_date.format("%B %d, %Y").to_string()}
                     }
                     div {
+                        Button {
+                            variant: if is_saved() { ButtonVariant::Success } else { ButtonVariant::Secondary },
+                            size: Size::Small,
+                            class: "me-2",
+                            onclick: toggle_saved,
+                            if is_saved() {
+                                i { class: "fas fa-bookmark me-1" }
+                                "Saved"
+                            } else {
+                                i { class: "far fa-bookmark me-1" }
+                                "Save"
+                            }
+                        }
                         Button {
                             variant: ButtonVariant::Primary,
                             size: Size::Small,
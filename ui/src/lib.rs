@@ -3,6 +3,9 @@
 pub mod components;
 pub mod profile;
 pub mod job_search;
+pub mod fetch_schedule;
+pub mod saved_searches;
+pub mod company_boards;
 pub mod resume_builder;
 pub mod views;
 pub mod shared_navigation;
@@ -11,12 +14,17 @@ pub mod routes;
 // Re-export main components for easy access
 pub use components::Dashboard;
 pub use components::ResumeUpload;
+pub use components::ResumeReview;
+pub use components::MergeReview;
 pub use components::Navbar;
 pub use components::{App, SimpleApp};
 
 // Re-export additional components
 pub use profile::ProfileManager;
 pub use job_search::JobSearch;
+pub use fetch_schedule::FetchSchedulePanel;
+pub use saved_searches::SavedSearchAlerts;
+pub use company_boards::CompanyBoardWatchlist;
 pub use resume_builder::ResumeBuilder;
 
 // Re-export views
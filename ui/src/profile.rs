@@ -6,12 +6,13 @@
 //! - Skills (with categories and proficiency levels)
 //! - Work history
 //! - Education
+//! - Projects
 
 use dioxus::prelude::*;
 use dioxus_bootstrap::*;
-use api::{Profile, Experience, Education, Skill, SkillCategory, SkillLevel};
+use api::{Profile, Experience, Education, Skill, SkillCategory, SkillLevel, Project, DEFAULT_USER_ID};
 use uuid::Uuid;
-use chrono::Utc;
+use chrono::{DateTime, NaiveDate, Utc};
 use crate::Label;
 
 /// Main profile manager component with tabbed navigation
@@ -25,7 +26,7 @@ pub fn ProfileManager(profile: Signal<Option<Profile>>) -> Element {
         spawn(async move {
             // Try to load existing profile for a default user
             // In a real app, this would come from auth
-            let user_id = "00000000-0000-0000-0000-000000000001".to_string();
+            let user_id = DEFAULT_USER_ID.to_string();
             if let Ok(Some(loaded_profile)) = api::get_profile(user_id).await {
                 profile.set(Some(loaded_profile));
             }
@@ -70,6 +71,12 @@ pub fn ProfileManager(profile: Signal<Option<Profile>>) -> Element {
                                         tab: ProfileTab::Skills,
                                         active_tab: active_tab
                                     }
+                                    ProfileNavButton {
+                                        icon: "fas fa-project-diagram",
+                                        label: "Projects",
+                                        tab: ProfileTab::Projects,
+                                        active_tab: active_tab
+                                    }
                                 }
                                 
                                 // Profile completeness indicator
@@ -87,6 +94,7 @@ pub fn ProfileManager(profile: Signal<Option<Profile>>) -> Element {
                         ProfileTab::Experience => rsx! { ExperienceManager { profile_id } },
                         ProfileTab::Education => rsx! { EducationManager { profile_id } },
                         ProfileTab::Skills => rsx! { SkillsManager { profile_id } },
+                        ProfileTab::Projects => rsx! { ProjectsManager { profile_id } },
                     }
                 }
             }
@@ -164,7 +172,7 @@ fn PersonalInfoForm(profile: Signal<Option<Profile>>) -> Element {
     let mut form_data = use_signal(|| {
         profile().unwrap_or_else(|| Profile {
             id: Uuid::new_v4(),
-            user_id: Uuid::parse_str("00000000-0000-0000-0000-000000000001").unwrap(),
+            user_id: Uuid::parse_str(DEFAULT_USER_ID).unwrap(),
             name: String::new(),
             email: String::new(),
             headline: None,
@@ -1262,12 +1270,390 @@ fn SkillTag(skill: Skill, on_delete: EventHandler<Uuid>) -> Element {
     }
 }
 
+/// Projects management section
+#[component]
+fn ProjectsManager(profile_id: Memo<Option<Uuid>>) -> Element {
+    let mut projects = use_signal(Vec::<Project>::new);
+    let mut show_form = use_signal(|| false);
+    let mut editing_id = use_signal(|| None::<Uuid>);
+    let mut is_loading = use_signal(|| true);
+
+    // Load projects
+    use_effect(move || {
+        if let Some(pid) = profile_id() {
+            spawn(async move {
+                is_loading.set(true);
+                if let Ok(loaded) = api::get_projects(pid.to_string()).await {
+                    projects.set(loaded);
+                }
+                is_loading.set(false);
+            });
+        }
+    });
+
+    let handle_save = move |project: Project| {
+        spawn(async move {
+            match api::save_project(project.clone()).await {
+                Ok(saved) => {
+                    let mut list = projects();
+                    if let Some(idx) = list.iter().position(|p| p.id == saved.id) {
+                        list[idx] = saved;
+                    } else {
+                        list.insert(0, saved);
+                    }
+                    projects.set(list);
+                    show_form.set(false);
+                    editing_id.set(None);
+                }
+                Err(e) => {
+                    tracing::error!("Failed to save project: {}", e);
+                }
+            }
+        });
+    };
+
+    let handle_delete = move |project_id: Uuid| {
+        spawn(async move {
+            if let Ok(()) = api::delete_project(project_id.to_string()).await {
+                let mut list = projects();
+                list.retain(|p| p.id != project_id);
+                projects.set(list);
+            }
+        });
+    };
+
+    rsx! {
+        Card {
+            CardHeader { class: "bg-white",
+                div { class: "d-flex justify-content-between align-items-center",
+                    h4 { class: "mb-0",
+                        i { class: "fas fa-project-diagram me-2 text-primary" }
+                        "Projects"
+                    }
+                    Button {
+                        variant: ButtonVariant::Primary,
+                        onclick: move |_| {
+                            editing_id.set(None);
+                            show_form.set(true);
+                        },
+                        i { class: "fas fa-plus me-2" }
+                        "Add Project"
+                    }
+                }
+            }
+            CardBody {
+                if is_loading() {
+                    div { class: "text-center py-5",
+                        div { class: "spinner-border text-primary" }
+                        p { class: "mt-2 text-muted", "Loading projects..." }
+                    }
+                } else if show_form() {
+                    ProjectForm { 
+                        profile_id: profile_id().unwrap_or(Uuid::nil()),
+                        project: editing_id().and_then(|id| projects().iter().find(|p| p.id == id).cloned()),
+                        on_save: handle_save,
+                        on_cancel: move |_| {
+                            show_form.set(false);
+                            editing_id.set(None);
+                        }
+                    }
+                } else if projects().is_empty() {
+                    div { class: "text-center text-muted py-5",
+                        i { class: "fas fa-project-diagram fa-4x mb-3 opacity-25" }
+                        h5 { "No projects added yet" }
+                        p { "Click 'Add Project' to showcase your personal and open source work" }
+                    }
+                } else {
+                    for project in projects() {
+                        ProjectCard { 
+                            project: project.clone(),
+                            on_edit: move |id| {
+                                editing_id.set(Some(id));
+                                show_form.set(true);
+                            },
+                            on_delete: handle_delete
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn ProjectForm(
+    profile_id: Uuid,
+    project: Option<Project>,
+    on_save: EventHandler<Project>,
+    on_cancel: EventHandler<()>
+) -> Element {
+    let is_edit = project.is_some();
+    let mut form_data = use_signal(move || {
+        project.clone().unwrap_or_else(|| Project {
+            id: Uuid::new_v4(),
+            profile_id,
+            name: String::new(),
+            description: String::new(),
+            technologies: Vec::new(),
+            url: None,
+            github_url: None,
+            start_date: Utc::now(),
+            end_date: None,
+            highlights: Vec::new(),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        })
+    });
+    // Edited as typed and only split into lists on save, so that a trailing
+    // comma or an empty line isn't dropped mid-edit
+    let mut technologies = use_signal(|| form_data.peek().technologies.join(", "));
+    let mut highlights = use_signal(|| form_data.peek().highlights.join("\n"));
+
+    rsx! {
+        Card { class: "mb-3 border-primary",
+            CardBody {
+                h5 { class: "mb-3", 
+                    if is_edit { "Edit Project" } else { "Add Project" }
+                }
+                Form {
+                    div { class: "mb-3",
+                        Label { r#for: "project-name", class: "form-label fw-semibold", 
+                            "Project Name "
+                            span { class: "text-danger", "*" }
+                        }
+                        Input {
+                            input_type: InputType::Text,
+                            id: "project-name",
+                            class: "form-control",
+                            placeholder: "My Awesome Project",
+                            value: form_data().name,
+                            oninput: move |evt: Event<FormData>| {
+                                let mut data = form_data();
+                                data.name = evt.value();
+                                form_data.set(data);
+                            }
+                        }
+                    }
+                    
+                    Row { class: "mb-3",
+                        Col { md: 6,
+                            Label { r#for: "project-url", class: "form-label fw-semibold", "Project URL" }
+                            Input {
+                                input_type: InputType::Url,
+                                id: "project-url",
+                                class: "form-control",
+                                placeholder: "myproject.com",
+                                value: form_data().url.clone().unwrap_or_default(),
+                                oninput: move |evt: Event<FormData>| {
+                                    let mut data = form_data();
+                                    data.url = if evt.value().is_empty() { None } else { Some(evt.value()) };
+                                    form_data.set(data);
+                                }
+                            }
+                        }
+                        Col { md: 6,
+                            Label { r#for: "project-github", class: "form-label fw-semibold", "GitHub" }
+                            Input {
+                                input_type: InputType::Url,
+                                id: "project-github",
+                                class: "form-control",
+                                placeholder: "github.com/username/project",
+                                value: form_data().github_url.clone().unwrap_or_default(),
+                                oninput: move |evt: Event<FormData>| {
+                                    let mut data = form_data();
+                                    data.github_url = if evt.value().is_empty() { None } else { Some(evt.value()) };
+                                    form_data.set(data);
+                                }
+                            }
+                        }
+                    }
+                    
+                    Row { class: "mb-3",
+                        Col { md: 6,
+                            Label { r#for: "project-start", class: "form-label fw-semibold", "Started" }
+                            Input {
+                                input_type: InputType::Month,
+                                id: "project-start",
+                                class: "form-control",
+                                value: form_data().start_date.format("%Y-%m").to_string(),
+                                oninput: move |evt: Event<FormData>| {
+                                    if let Some(date) = parse_month(&evt.value()) {
+                                        let mut data = form_data();
+                                        data.start_date = date;
+                                        form_data.set(data);
+                                    }
+                                }
+                            }
+                        }
+                        Col { md: 6,
+                            Label { r#for: "project-end", class: "form-label fw-semibold", "Finished" }
+                            Input {
+                                input_type: InputType::Month,
+                                id: "project-end",
+                                class: "form-control",
+                                value: form_data().end_date.map(|d| d.format("%Y-%m").to_string()).unwrap_or_default(),
+                                oninput: move |evt: Event<FormData>| {
+                                    let mut data = form_data();
+                                    data.end_date = parse_month(&evt.value());
+                                    form_data.set(data);
+                                }
+                            }
+                            small { class: "text-muted", "Leave empty if still active" }
+                        }
+                    }
+                    
+                    div { class: "mb-3",
+                        Label { r#for: "project-technologies", class: "form-label fw-semibold", "Technologies" }
+                        Input {
+                            input_type: InputType::Text,
+                            id: "project-technologies",
+                            class: "form-control",
+                            placeholder: "Rust, Dioxus, SQLite",
+                            value: technologies(),
+                            oninput: move |evt: Event<FormData>| technologies.set(evt.value())
+                        }
+                        small { class: "text-muted", "Separate technologies with commas" }
+                    }
+                    
+                    div { class: "mb-3",
+                        Label { r#for: "project-description", class: "form-label fw-semibold", "Description" }
+                        Textarea {
+                            id: "project-description",
+                            class: "form-control",
+                            rows: 4,
+                            placeholder: "What does the project do and what was your role?",
+                            value: form_data().description,
+                            oninput: move |evt: Event<FormData>| {
+                                let mut data = form_data();
+                                data.description = evt.value();
+                                form_data.set(data);
+                            }
+                        }
+                    }
+                    
+                    div { class: "mb-3",
+                        Label { r#for: "project-highlights", class: "form-label fw-semibold", "Highlights" }
+                        Textarea {
+                            id: "project-highlights",
+                            class: "form-control",
+                            rows: 3,
+                            placeholder: "1k GitHub stars\nUsed in production by 3 companies",
+                            value: highlights(),
+                            oninput: move |evt: Event<FormData>| highlights.set(evt.value())
+                        }
+                        small { class: "text-muted", "One highlight per line" }
+                    }
+                    
+                    div { class: "d-flex justify-content-end gap-2",
+                        Button {
+                            variant: ButtonVariant::Secondary,
+                            onclick: move |_| on_cancel.call(()),
+                            "Cancel"
+                        }
+                        Button {
+                            variant: ButtonVariant::Primary,
+                            disabled: form_data().name.is_empty(),
+                            onclick: move |_| {
+                                let mut data = form_data();
+                                data.technologies = technologies()
+                                    .split(',')
+                                    .map(|t| t.trim().to_string())
+                                    .filter(|t| !t.is_empty())
+                                    .collect();
+                                data.highlights = highlights()
+                                    .lines()
+                                    .map(|h| h.trim().to_string())
+                                    .filter(|h| !h.is_empty())
+                                    .collect();
+                                data.updated_at = Utc::now();
+                                on_save.call(data);
+                            },
+                            i { class: "fas fa-save me-2" }
+                            "Save Project"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Parse the `YYYY-MM` value of a month input as the first of that month
+fn parse_month(value: &str) -> Option<DateTime<Utc>> {
+    NaiveDate::parse_from_str(&format!("{}-01", value), "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|date| date.and_utc())
+}
+
+#[component]
+fn ProjectCard(
+    project: Project,
+    on_edit: EventHandler<Uuid>,
+    on_delete: EventHandler<Uuid>
+) -> Element {
+    let project_id = project.id;
+    
+    rsx! {
+        Card { class: "mb-3 border-start border-info border-3",
+            CardBody {
+                div { class: "d-flex justify-content-between align-items-start",
+                    div { class: "flex-grow-1",
+                        h5 { class: "mb-1", "{project.name}" }
+                        div { class: "d-flex gap-3 mb-2 small",
+                            if let Some(url) = &project.url {
+                                a { href: "{url}", target: "_blank",
+                                    i { class: "fas fa-globe me-1" }
+                                    "Website"
+                                }
+                            }
+                            if let Some(github) = &project.github_url {
+                                a { href: "{github}", target: "_blank",
+                                    i { class: "fab fa-github me-1" }
+                                    "Source"
+                                }
+                            }
+                        }
+                        if !project.description.is_empty() {
+                            p { class: "mb-2", "{project.description}" }
+                        }
+                        if !project.technologies.is_empty() {
+                            div { class: "d-flex flex-wrap gap-1",
+                                for tech in project.technologies.iter() {
+                                    Badge { variant: BadgeVariant::Secondary, "{tech}" }
+                                }
+                            }
+                        }
+                    }
+                    div { class: "btn-group",
+                        Button {
+                            variant: ButtonVariant::Primary,
+                            outline: true,
+                            size: Size::Small,
+                            onclick: move |_| on_edit.call(project_id),
+                            i { class: "fas fa-edit" }
+                        }
+                        Button {
+                            variant: ButtonVariant::Danger,
+                            outline: true,
+                            size: Size::Small,
+                            onclick: move |_| on_delete.call(project_id),
+                            i { class: "fas fa-trash" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
 #[derive(Clone, PartialEq)]
 enum ProfileTab {
     Personal,
     Experience,
     Education,
     Skills,
+    Projects,
 }
 
 #[derive(Clone)]
@@ -0,0 +1,145 @@
+use dioxus::prelude::*;
+use dioxus_bootstrap::*;
+use api::{JobListing, SavedSearch, get_saved_searches, get_saved_search_new_jobs, mark_saved_search_seen, delete_saved_search};
+
+/// Saved searches, with an alert for each one that has new matching jobs
+#[component]
+pub fn SavedSearchAlerts(user_id: String) -> Element {
+    let mut searches = use_signal(Vec::<SavedSearch>::new);
+
+    use_effect(move || {
+        let user_id = user_id.clone();
+        spawn(async move {
+            match get_saved_searches(user_id).await {
+                Ok(loaded) => searches.set(loaded),
+                Err(e) => tracing::error!("Failed to load saved searches: {}", e),
+            }
+        });
+    });
+
+    rsx! {
+        Card { class: "border-0 shadow-sm mb-4",
+            CardBody {
+                h5 { class: "mb-3",
+                    i { class: "fas fa-bell me-2 text-primary" }
+                    "Saved Searches"
+                }
+
+                if searches().is_empty() {
+                    p { class: "text-muted small mb-0",
+                        "Save a search on the Jobs page to be told about new matching jobs."
+                    }
+                }
+
+                for search in searches() {
+                    SavedSearchRow {
+                        key: "{search.id}",
+                        search: search.clone(),
+                        on_seen: move |id| {
+                            searches.with_mut(|all| {
+                                if let Some(s) = all.iter_mut().find(|s| s.id == id) {
+                                    s.new_matches = 0;
+                                }
+                            });
+                        },
+                        on_delete: move |id| searches.with_mut(|all| all.retain(|s| s.id != id)),
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn SavedSearchRow(
+    search: SavedSearch,
+    on_seen: EventHandler<uuid::Uuid>,
+    on_delete: EventHandler<uuid::Uuid>,
+) -> Element {
+    let mut new_jobs = use_signal(|| None::<Vec<JobListing>>);
+    let id = search.id;
+
+    let toggle_jobs = move |_| {
+        if new_jobs().is_some() {
+            new_jobs.set(None);
+            return;
+        }
+        spawn(async move {
+            match get_saved_search_new_jobs(id.to_string()).await {
+                Ok(jobs) => new_jobs.set(Some(jobs)),
+                Err(e) => tracing::error!("Failed to load new jobs: {}", e),
+            }
+        });
+    };
+
+    let mark_seen = move |_| {
+        spawn(async move {
+            match mark_saved_search_seen(id.to_string()).await {
+                Ok(()) => {
+                    new_jobs.set(None);
+                    on_seen.call(id);
+                }
+                Err(e) => tracing::error!("Failed to mark saved search as seen: {}", e),
+            }
+        });
+    };
+
+    let delete = move |_| {
+        spawn(async move {
+            match delete_saved_search(id.to_string()).await {
+                Ok(()) => on_delete.call(id),
+                Err(e) => tracing::error!("Failed to delete saved search: {}", e),
+            }
+        });
+    };
+
+    rsx! {
+        div { class: "border-top py-2",
+            div { class: "d-flex justify-content-between align-items-center",
+                if search.new_matches > 0 {
+                    strong {
+                        Badge { variant: BadgeVariant::Success, class: "me-2", "{search.new_matches} new" }
+                        {format!("{} new jobs for '{}'", search.new_matches, search.name)}
+                    }
+                } else {
+                    span { class: "text-muted", "No new jobs for '{search.name}'" }
+                }
+                div { class: "btn-group",
+                    if search.new_matches > 0 {
+                        Button {
+                            variant: ButtonVariant::Primary,
+                            size: Size::Small,
+                            onclick: toggle_jobs,
+                            if new_jobs().is_some() { "Hide" } else { "Show" }
+                        }
+                        Button {
+                            variant: ButtonVariant::Secondary,
+                            size: Size::Small,
+                            onclick: mark_seen,
+                            "Mark as seen"
+                        }
+                    }
+                    Button {
+                        variant: ButtonVariant::Danger,
+                        size: Size::Small,
+                        onclick: delete,
+                        i { class: "fas fa-trash" }
+                    }
+                }
+            }
+
+            if let Some(jobs) = new_jobs() {
+                ul { class: "list-unstyled small mt-2 mb-0 ms-3",
+                    for listing in jobs {
+                        li { key: "{listing.job.id}",
+                            a { href: "{listing.job.source_url}", target: "_blank",
+                                "{listing.job.title}"
+                            }
+                            span { class: "text-muted", " at {listing.job.company} · {listing.job.location}" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -48,7 +48,7 @@ pub fn ResumeUpload(on_upload: EventHandler<ParsedResume>) -> Element {
                         CardBody {
                             h3 { class: "mb-4", "Upload Your Resume" }
                             p { class: "text-muted mb-4", 
-                                "Upload your resume in PDF or DOCX format. Our system will automatically extract and organize your information."
+                                "Upload your resume as PDF, Word, plain text, Markdown, HTML or JSON Resume. Our system will automatically extract and organize your information."
                             }
                             
                             match upload_status() {
@@ -57,7 +57,7 @@ pub fn ResumeUpload(on_upload: EventHandler<ParsedResume>) -> Element {
                                         style: "cursor: pointer; transition: all 0.3s ease;",
                                         input {
                                             r#type: "file",
-                                            accept: ".pdf,.docx",
+                                            accept: ".pdf,.docx,.txt,.md,.html,.htm,.json",
                                             class: "form-control",
                                             style: "display: none;",
                                             id: "resume-upload",
@@ -71,7 +71,7 @@ pub fn ResumeUpload(on_upload: EventHandler<ParsedResume>) -> Element {
                                                 i { class: "fas fa-cloud-upload-alt fa-3x text-primary" }
                                             }
                                             h5 { "Click to upload or drag and drop" }
-                                            p { class: "text-muted", "Supported formats: PDF, DOCX" }
+                                            p { class: "text-muted", "Supported formats: PDF, DOCX, TXT, MD, HTML, JSON Resume" }
                                         }
                                     }
                                 }
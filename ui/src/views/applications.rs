@@ -1,13 +1,34 @@
 use dioxus::prelude::*;
-use crate::{Container, Row, Col, Button, ButtonVariant, ButtonGroup, Size, SharedNavigation};
+use api::{ApplicationStatus, ApplicationStatusChange, Job, JobApplication, DEFAULT_USER_ID};
+use crate::{Container, Row, Col, Card, CardBody, Badge, BadgeVariant, Button, ButtonVariant, ButtonGroup, Select, Size, SharedNavigation};
 
 #[component]
 pub fn Applications() -> Element {
-    rsx! { 
+    let mut applications = use_signal(Vec::<JobApplication>::new);
+    let mut is_loading = use_signal(|| true);
+    let status_filter = use_signal(|| None::<ApplicationStatus>);
+
+    use_effect(move || {
+        spawn(async move {
+            match api::get_user_applications(DEFAULT_USER_ID.to_string()).await {
+                Ok(loaded) => applications.set(loaded),
+                Err(e) => tracing::error!("Failed to load applications: {}", e),
+            }
+            is_loading.set(false);
+        });
+    });
+
+    let filter = status_filter();
+    let visible: Vec<JobApplication> = applications()
+        .into_iter()
+        .filter(|a| filter.as_ref().is_none_or(|s| &a.status == s))
+        .collect();
+
+    rsx! {
         document::Title { "My Applications - Employment Barage | Track Your Job Applications" }
         div { class: "min-vh-100",
             SharedNavigation {}
-            
+
             main { class: "py-4",
                 Container {
                     Row {
@@ -15,22 +36,227 @@ pub fn Applications() -> Element {
                             div { class: "d-flex justify-content-between align-items-center mb-4",
                                 h2 { "My Applications" }
                                 ButtonGroup {
-                                    Button { variant: ButtonVariant::Primary, size: Size::Small, "All" }
-                                    Button { variant: ButtonVariant::Secondary, size: Size::Small, "Applied" }
-                                    Button { variant: ButtonVariant::Secondary, size: Size::Small, "Interviewing" }
-                                    Button { variant: ButtonVariant::Secondary, size: Size::Small, "Offered" }
+                                    StatusFilterButton { label: "All", status: None, status_filter: status_filter }
+                                    StatusFilterButton { label: "Applied", status: Some(ApplicationStatus::Applied), status_filter: status_filter }
+                                    StatusFilterButton { label: "Interviewing", status: Some(ApplicationStatus::Interviewing), status_filter: status_filter }
+                                    StatusFilterButton { label: "Offered", status: Some(ApplicationStatus::Offered), status_filter: status_filter }
+                                }
+                            }
+
+                            if is_loading() {
+                                div { class: "text-center py-5",
+                                    span { class: "spinner-border" }
+                                }
+                            } else if visible.is_empty() && filter.is_some() {
+                                div { class: "text-center text-muted py-5",
+                                    i { class: "fas fa-filter fa-4x mb-3" }
+                                    h4 { "No applications match this filter" }
+                                    p { "Choose \"All\" to see every application." }
+                                }
+                            } else if visible.is_empty() {
+                                div { class: "text-center text-muted py-5",
+                                    i { class: "fas fa-paper-plane fa-4x mb-3" }
+                                    h4 { "No applications yet" }
+                                    p { "Your job applications will appear here once you start applying!" }
+                                }
+                            } else {
+                                for application in visible {
+                                    ApplicationCard {
+                                        key: "{application.id}",
+                                        application: application.clone(),
+                                        on_update: move |updated: JobApplication| {
+                                            let mut list = applications();
+                                            if let Some(existing) = list.iter_mut().find(|a| a.id == updated.id) {
+                                                *existing = updated;
+                                            }
+                                            applications.set(list);
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn StatusFilterButton(label: &'static str, status: Option<ApplicationStatus>, status_filter: Signal<Option<ApplicationStatus>>) -> Element {
+    let is_active = status_filter() == status;
+
+    rsx! {
+        Button {
+            variant: if is_active { ButtonVariant::Primary } else { ButtonVariant::Secondary },
+            size: Size::Small,
+            onclick: move |_| status_filter.set(status.clone()),
+            "{label}"
+        }
+    }
+}
+
+/// A single application with its status controls and timeline
+#[component]
+fn ApplicationCard(application: JobApplication, on_update: EventHandler<JobApplication>) -> Element {
+    let mut job = use_signal(|| None::<Job>);
+    let mut show_timeline = use_signal(|| false);
+    let mut is_updating = use_signal(|| false);
+    let job_id = application.job_id.to_string();
+    let application_id = application.id.to_string();
+
+    use_effect(move || {
+        let job_id = job_id.clone();
+        spawn(async move {
+            if let Ok(Some(loaded)) = api::get_job(job_id).await {
+                job.set(Some(loaded));
+            }
+        });
+    });
+
+    let (title, company) = match job() {
+        Some(job) => (job.title, job.company),
+        None => ("Job posting".to_string(), String::new()),
+    };
+
+    rsx! {
+        Card { class: "mb-3",
+            CardBody {
+                div { class: "d-flex justify-content-between align-items-start",
+                    div {
+                        h5 { class: "mb-1", "{title}" }
+                        if !company.is_empty() {
+                            h6 { class: "text-primary mb-2", "{company}" }
+                        }
+                        small { class: "text-muted",
+                            {format!("Last updated {}", application.updated_at.format("%B %d, %Y"))}
+                        }
+                    }
+                    div { class: "text-end",
+                        StatusBadge { status: application.status.clone() }
+                        div { class: "mt-2 d-flex align-items-center gap-2",
+                            if is_updating() {
+                                span { class: "spinner-border spinner-border-sm" }
+                            }
+                            Select {
+                                class: "form-select form-select-sm",
+                                value: format!("{:?}", application.status),
+                                onchange: move |evt: Event<FormData>| {
+                                    let application_id = application_id.clone();
+                                    let status = ApplicationStatus::ALL
+                                        .into_iter()
+                                        .find(|s| format!("{:?}", s) == evt.value());
+                                    if let Some(status) = status {
+                                        spawn(async move {
+                                            is_updating.set(true);
+                                            match api::update_application_status(application_id, status, None).await {
+                                                Ok(updated) => on_update.call(updated),
+                                                Err(e) => tracing::error!("Failed to update application: {}", e),
+                                            }
+                                            is_updating.set(false);
+                                        });
+                                    }
+                                },
+                                for status in ApplicationStatus::ALL {
+                                    option { value: format!("{:?}", status), {status_label(&status)} }
                                 }
                             }
-                            
-                            div { class: "text-center text-muted py-5",
-                                i { class: "fas fa-paper-plane fa-4x mb-3" }
-                                h4 { "No applications yet" }
-                                p { "Your job applications will appear here once you start applying!" }
+                        }
+                    }
+                }
+
+                if let Some(notes) = &application.notes {
+                    p { class: "mt-3 mb-0", "{notes}" }
+                }
+
+                div { class: "mt-3",
+                    Button {
+                        variant: ButtonVariant::Secondary,
+                        outline: true,
+                        size: Size::Small,
+                        onclick: move |_| show_timeline.set(!show_timeline()),
+                        i { class: "fas fa-history me-1" }
+                        if show_timeline() { "Hide timeline" } else { "Show timeline" }
+                    }
+                }
+
+                if show_timeline() {
+                    // Keyed on the last update so the timeline reloads after a status change
+                    ApplicationTimeline {
+                        key: "{application.id}-{application.updated_at}",
+                        application_id: application.id.to_string()
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Chronological list of an application's status changes
+#[component]
+fn ApplicationTimeline(application_id: String) -> Element {
+    let mut history = use_signal(Vec::<ApplicationStatusChange>::new);
+    let mut is_loading = use_signal(|| true);
+
+    use_effect(move || {
+        let application_id = application_id.clone();
+        spawn(async move {
+            match api::get_application_timeline(application_id).await {
+                Ok(loaded) => history.set(loaded),
+                Err(e) => tracing::error!("Failed to load application timeline: {}", e),
+            }
+            is_loading.set(false);
+        });
+    });
+
+    rsx! {
+        div { class: "mt-3 border-start border-2 ps-3",
+            if is_loading() {
+                span { class: "spinner-border spinner-border-sm" }
+            } else if history().is_empty() {
+                p { class: "text-muted mb-0", "No status changes recorded" }
+            } else {
+                for change in history() {
+                    div { key: "{change.id}", class: "mb-2",
+                        div { class: "d-flex align-items-center gap-2",
+                            StatusBadge { status: change.status.clone() }
+                            small { class: "text-muted",
+                                {change.created_at.format("%B %d, %Y at %H:%M").to_string()}
                             }
                         }
+                        if let Some(notes) = &change.notes {
+                            small { class: "d-block mt-1", "{notes}" }
+                        }
                     }
                 }
             }
         }
     }
-}
\ No newline at end of file
+}
+
+#[component]
+fn StatusBadge(status: ApplicationStatus) -> Element {
+    let variant = match status {
+        ApplicationStatus::Draft => BadgeVariant::Secondary,
+        ApplicationStatus::Applied => BadgeVariant::Primary,
+        ApplicationStatus::Interviewing => BadgeVariant::Info,
+        ApplicationStatus::Offered => BadgeVariant::Success,
+        ApplicationStatus::Rejected => BadgeVariant::Danger,
+        ApplicationStatus::Withdrawn => BadgeVariant::Secondary,
+    };
+
+    rsx! {
+        Badge { variant: variant, {status_label(&status)} }
+    }
+}
+
+fn status_label(status: &ApplicationStatus) -> &'static str {
+    match status {
+        ApplicationStatus::Draft => "Draft",
+        ApplicationStatus::Applied => "Applied",
+        ApplicationStatus::Interviewing => "Interviewing",
+        ApplicationStatus::Offered => "Offered",
+        ApplicationStatus::Rejected => "Rejected",
+        ApplicationStatus::Withdrawn => "Withdrawn",
+    }
+}
@@ -1,13 +1,15 @@
 use dioxus::prelude::*;
-use crate::{Container, Row, Col, Card, CardBody, Button, ButtonVariant, Size, SharedNavigation};
-use api;
+use crate::{Container, Row, Col, Card, CardBody, Button, ButtonVariant, Size, Alert, AlertVariant, SharedNavigation};
+use api::{self, DEFAULT_USER_ID};
 
 #[component]
 pub fn Dashboard() -> Element {
-    let mut user_profile = use_signal(|| None);
+    let mut parsed_resume = use_signal(|| None::<api::ParsedResume>);
+    let mut saved_profile = use_signal(|| None::<api::FullProfile>);
 
     let handle_resume_upload = move |parsed: api::ParsedResume| {
-        user_profile.set(Some(parsed.profile));
+        saved_profile.set(None);
+        parsed_resume.set(Some(parsed));
     };
 
     rsx! { 
@@ -79,7 +81,37 @@ pub fn Dashboard() -> Element {
                     }
                     
                     // Upload Section
-                    crate::ResumeUpload { on_upload: handle_resume_upload }
+                    if let Some(parsed) = parsed_resume() {
+                        crate::ResumeReview {
+                            user_id: DEFAULT_USER_ID.to_string(),
+                            parsed,
+                            on_saved: move |full: api::FullProfile| {
+                                saved_profile.set(Some(full));
+                                parsed_resume.set(None);
+                            },
+                            on_cancel: move |_| parsed_resume.set(None),
+                        }
+                    } else {
+                        if let Some(full) = saved_profile() {
+                            Row { class: "mb-4",
+                                Col { md: 8, offset_md: 2,
+                                    Alert { variant: AlertVariant::Success,
+                                        i { class: "fas fa-check-circle me-2" }
+                                        "Saved to your profile, which now has {full.experiences.len()} experiences, {full.education.len()} education entries and {full.skills.len()} skills."
+                                    }
+                                }
+                            }
+                        }
+                        crate::ResumeUpload { on_upload: handle_resume_upload }
+                    }
+                    
+                    // Job Alerts and Background Fetching Section
+                    Row { class: "mt-5",
+                        Col { lg: 8, offset_lg: 2,
+                            crate::SavedSearchAlerts { user_id: DEFAULT_USER_ID.to_string() }
+                            crate::FetchSchedulePanel {}
+                        }
+                    }
                 }
             }
         }
@@ -1,5 +1,6 @@
 use dioxus::prelude::*;
 use crate::SharedNavigation;
+use dioxus_bootstrap::Container;
 
 #[component]
 pub fn Jobs() -> Element {
@@ -11,6 +12,10 @@ pub fn Jobs() -> Element {
             main { class: "py-4",
                 // Job search component
                 crate::JobSearch {}
+                
+                Container {
+                    crate::CompanyBoardWatchlist {}
+                }
             }
         }
     }
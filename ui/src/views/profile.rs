@@ -1,19 +1,127 @@
 //! Profile view - displays the profile management UI
 
 use dioxus::prelude::*;
-use crate::SharedNavigation;
+use api::{ExportedFile, DEFAULT_USER_ID};
+use crate::{Container, Card, CardBody, Button, ButtonVariant, Size, Alert, AlertVariant, SharedNavigation};
+
+/// Saves a file received from Rust through a temporary object URL
+const DOWNLOAD_SCRIPT: &str = r#"
+    const file = await dioxus.recv();
+    const url = URL.createObjectURL(new Blob([file.content], { type: file.mime_type }));
+    const link = document.createElement("a");
+    link.href = url;
+    link.download = file.file_name;
+    document.body.appendChild(link);
+    link.click();
+    link.remove();
+    URL.revokeObjectURL(url);
+"#;
 
 /// Profile page view
 #[component]
 pub fn Profile() -> Element {
-    rsx! { 
+    rsx! {
         document::Title { "Profile Manager - Employment Barage | Build Your Professional Profile" }
         div { class: "min-vh-100 bg-light",
             SharedNavigation {}
-            
+
             main { class: "py-4",
+                Container {
+                    div { class: "mb-4",
+                        ProfileExport {}
+                    }
+                }
                 crate::ProfileManager { profile: use_signal(|| None) }
             }
         }
     }
 }
+
+#[derive(Clone, Copy, PartialEq)]
+enum ExportFormat {
+    JsonResume,
+    Europass,
+}
+
+/// Download the whole profile in a portable format
+#[component]
+fn ProfileExport() -> Element {
+    let mut exporting = use_signal(|| None::<ExportFormat>);
+    let mut error = use_signal(|| None::<String>);
+
+    let export = move |format: ExportFormat| {
+        spawn(async move {
+            exporting.set(Some(format));
+            error.set(None);
+
+            let user_id = DEFAULT_USER_ID.to_string();
+            let result = match format {
+                ExportFormat::JsonResume => api::export_json_resume(user_id).await,
+                ExportFormat::Europass => api::export_europass(user_id).await,
+            };
+            match result {
+                Ok(file) => download(file),
+                Err(e) => error.set(Some(format!("Export failed: {}", e))),
+            }
+            exporting.set(None);
+        });
+    };
+
+    rsx! {
+        Card {
+            CardBody {
+                div { class: "d-flex justify-content-between align-items-center flex-wrap gap-2",
+                    div {
+                        h5 { class: "mb-1",
+                            i { class: "fas fa-file-export me-2 text-primary" }
+                            "Export Profile"
+                        }
+                        small { class: "text-muted",
+                            "Download your profile, experience, education, skills and projects to use in other tools."
+                        }
+                    }
+                    div { class: "d-flex gap-2",
+                        Button {
+                            variant: ButtonVariant::Primary,
+                            outline: true,
+                            size: Size::Small,
+                            disabled: exporting().is_some(),
+                            onclick: move |_| export(ExportFormat::JsonResume),
+                            if exporting() == Some(ExportFormat::JsonResume) {
+                                span { class: "spinner-border spinner-border-sm me-1" }
+                            } else {
+                                i { class: "fas fa-download me-1" }
+                            }
+                            "JSON Resume"
+                        }
+                        Button {
+                            variant: ButtonVariant::Primary,
+                            outline: true,
+                            size: Size::Small,
+                            disabled: exporting().is_some(),
+                            onclick: move |_| export(ExportFormat::Europass),
+                            if exporting() == Some(ExportFormat::Europass) {
+                                span { class: "spinner-border spinner-border-sm me-1" }
+                            } else {
+                                i { class: "fas fa-download me-1" }
+                            }
+                            "Europass XML"
+                        }
+                    }
+                }
+                if let Some(message) = error() {
+                    div { class: "mt-3",
+                        Alert { variant: AlertVariant::Danger, "{message}" }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn download(file: ExportedFile) {
+    let eval = document::eval(DOWNLOAD_SCRIPT);
+    if let Err(e) = eval.send(file) {
+        tracing::error!("Failed to start download: {:?}", e);
+    }
+}
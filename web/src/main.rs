@@ -7,7 +7,7 @@ fn main() {
     // Initialize database before launching the app
     #[cfg(not(target_arch = "wasm32"))]
     {
-        use api::db::{init_database, default_db_path};
+        use api::db::{init_database, default_db_path, get_database};
         
         // Use tokio runtime to initialize async database
         let rt = tokio::runtime::Runtime::new().expect("Failed to create runtime");
@@ -19,6 +19,11 @@ fn main() {
             }
             println!("Database initialized at: {}", db_path);
         });
+        
+        // Fetch jobs from external sources in the background
+        if let Err(e) = api::services::start_job_scheduler(get_database().clone()) {
+            eprintln!("Failed to start job scheduler: {}", e);
+        }
     }
     
     dioxus::launch(|| rsx! {